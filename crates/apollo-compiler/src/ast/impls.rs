@@ -12,6 +12,7 @@ use crate::validation::WithErrors;
 use crate::ExecutableDocument;
 use crate::Parser;
 use crate::Schema;
+use indexmap::IndexMap;
 use std::fmt;
 use std::hash;
 use std::path::Path;
@@ -136,6 +137,44 @@ impl Document {
             .map(|()| (Valid(schema), Valid(executable)))
     }
 
+    /// Returns an iterator of the operation definitions in this document,
+    /// both named and anonymous, in source order.
+    ///
+    /// ```
+    /// use apollo_compiler::ast::Document;
+    ///
+    /// let input = "query A { a } fragment F on Query { b } { c }";
+    /// let document = Document::parse(input, "query.graphql").unwrap();
+    ///
+    /// let names: Vec<_> = document
+    ///     .operations()
+    ///     .map(|operation| operation.name.as_ref().map(|name| name.as_str()))
+    ///     .collect();
+    /// assert_eq!(names, [Some("A"), None]);
+    /// assert_eq!(document.fragment_definitions().count(), 1);
+    /// assert!(document.operation_by_name("A").is_some());
+    /// ```
+    pub fn operations(&self) -> impl Iterator<Item = &Node<OperationDefinition>> {
+        self.definitions
+            .iter()
+            .filter_map(|def| def.as_operation_definition())
+    }
+
+    /// Returns an iterator of the fragment definitions in this document, in source order.
+    pub fn fragment_definitions(&self) -> impl Iterator<Item = &Node<FragmentDefinition>> {
+        self.definitions
+            .iter()
+            .filter_map(|def| def.as_fragment_definition())
+    }
+
+    /// Returns the first operation definition with the given name, if any.
+    ///
+    /// Anonymous operations are never returned.
+    pub fn operation_by_name(&self, name: &str) -> Option<&Node<OperationDefinition>> {
+        self.operations()
+            .find(|operation| operation.name.as_ref().is_some_and(|n| n == name))
+    }
+
     serialize_method!();
 }
 
@@ -573,6 +612,37 @@ impl DirectiveList {
         self.get(name).is_some()
     }
 
+    /// Removes repeated applications of directives that are not `repeatable` in `schema`,
+    /// keeping the first one in place.
+    ///
+    /// Directives that are not defined in `schema` are kept as-is.
+    ///
+    /// ```
+    /// use apollo_compiler::ast::{Directive, DirectiveList};
+    /// use apollo_compiler::{Node, Schema};
+    ///
+    /// let schema = Schema::parse(
+    ///     "directive @tag(name: String) repeatable on OBJECT type Query { a: Int }",
+    ///     "schema.graphql",
+    /// )
+    /// .unwrap();
+    /// let mut directives: DirectiveList = ["deprecated", "tag", "deprecated", "tag"]
+    ///     .into_iter()
+    ///     .map(|name| Node::new(Directive { name: name.try_into().unwrap(), arguments: vec![] }))
+    ///     .collect();
+    /// directives.dedup_non_repeatable(&schema);
+    /// assert_eq!(directives.to_string(), " @deprecated @tag @tag");
+    /// ```
+    pub fn dedup_non_repeatable(&mut self, schema: &Schema) {
+        let mut seen = std::collections::HashSet::new();
+        self.0.retain(
+            |directive| match schema.directive_definitions.get(&directive.name) {
+                Some(definition) if !definition.repeatable => seen.insert(directive.name.clone()),
+                _ => true,
+            },
+        )
+    }
+
     serialize_method!();
 }
 
@@ -636,9 +706,152 @@ impl Directive {
             .find_map(|arg| (arg.name == name).then_some(&arg.value))
     }
 
+    /// Returns the values of all arguments defined for this directive in `schema`,
+    /// coerced to their types like [input coercion] does for field arguments:
+    ///
+    /// * Arguments that are not provided get the default value of their definition, if any.
+    /// * A single value provided for a list type is wrapped in a list.
+    /// * Fields that are not provided in input objects get their default value, if any.
+    /// * Variables are kept as-is, since their values are not known here.
+    ///
+    /// Returns every argument that does not match its definition as an error,
+    /// instead of ignoring it.
+    ///
+    /// ```
+    /// use apollo_compiler::ast::Value;
+    /// use apollo_compiler::Schema;
+    ///
+    /// let input = r#"
+    ///     directive @join__field(graph: Graph, requires: String, external: Boolean = false, tags: [String!]) on FIELD_DEFINITION
+    ///     enum Graph { PRODUCTS REVIEWS }
+    ///     type Query {
+    ///         product: String @join__field(graph: PRODUCTS, tags: "public")
+    ///     }
+    /// "#;
+    /// let schema = Schema::parse_and_validate(input, "schema.graphql").unwrap();
+    /// let directive = &schema.type_field("Query", "product").unwrap().directives[0];
+    /// let arguments = directive.resolved_arguments(&schema).unwrap();
+    /// assert_eq!(arguments["graph"].as_enum().unwrap(), "PRODUCTS");
+    /// assert_eq!(arguments["external"].to_bool(), Some(false));
+    /// // A single value is coerced to a list
+    /// let tags = arguments["tags"].as_list().unwrap();
+    /// assert_eq!(tags[0].as_str(), Some("public"));
+    /// // Omitted arguments without a default value are not included
+    /// assert!(!arguments.contains_key("requires"));
+    /// ```
+    ///
+    /// [input coercion]: https://spec.graphql.org/October2021/#sec-Coercing-Field-Arguments
+    pub fn resolved_arguments(
+        &self,
+        schema: &Schema,
+    ) -> Result<IndexMap<Name, Node<Value>>, Vec<DirectiveArgumentError>> {
+        let Some(definition) = schema.directive_definitions.get(&self.name) else {
+            return Err(vec![DirectiveArgumentError::UndefinedDirective {
+                directive: self.name.clone(),
+            }]);
+        };
+        let mut errors = Vec::new();
+        for argument in &self.arguments {
+            if definition.argument_by_name(&argument.name).is_none() {
+                errors.push(DirectiveArgumentError::UndefinedArgument {
+                    directive: self.name.clone(),
+                    argument: argument.name.clone(),
+                    location: argument.location(),
+                });
+            }
+        }
+        let mut resolved = IndexMap::new();
+        for argument_definition in &definition.arguments {
+            let name = &argument_definition.name;
+            let value = match self.argument_by_name(name) {
+                Some(value) => value,
+                None => match &argument_definition.default_value {
+                    Some(default) => default,
+                    None => {
+                        if argument_definition.is_required() {
+                            errors.push(DirectiveArgumentError::MissingArgument {
+                                directive: self.name.clone(),
+                                argument: name.clone(),
+                            });
+                        }
+                        continue;
+                    }
+                },
+            };
+            let mut diagnostics = Vec::new();
+            crate::validation::value::value_of_correct_type(
+                schema,
+                &argument_definition.ty,
+                value,
+                // Variables are resolved later, during execution
+                &[],
+                &mut diagnostics,
+            );
+            if diagnostics.is_empty() {
+                let value = coerce_input_literal(schema, &argument_definition.ty, value);
+                resolved.insert(name.clone(), value);
+            }
+            errors.extend(diagnostics.into_iter().map(|diagnostic| {
+                DirectiveArgumentError::InvalidValue {
+                    directive: self.name.clone(),
+                    argument: name.clone(),
+                    message: diagnostic.to_string(),
+                    location: diagnostic.location,
+                }
+            }));
+        }
+        if errors.is_empty() {
+            Ok(resolved)
+        } else {
+            Err(errors)
+        }
+    }
+
     serialize_method!();
 }
 
+/// Coerce a literal input value to `ty`, wrapping single values into lists and applying the
+/// default values of omitted input object fields.
+///
+/// The value must already have been checked with `value_of_correct_type`.
+fn coerce_input_literal(schema: &Schema, ty: &Type, value: &Node<Value>) -> Node<Value> {
+    if matches!(value.as_ref(), Value::Variable(_) | Value::Null) {
+        return value.clone();
+    }
+    match ty {
+        Type::List(item_ty) | Type::NonNullList(item_ty) => {
+            let items = match value.as_list() {
+                Some(items) => items
+                    .iter()
+                    .map(|item| coerce_input_literal(schema, item_ty, item))
+                    .collect(),
+                // A single value is coerced to a list of one item
+                None => vec![coerce_input_literal(schema, item_ty, value)],
+            };
+            value.same_location(Value::List(items))
+        }
+        Type::Named(name) | Type::NonNullNamed(name) => {
+            let (Some(crate::schema::ExtendedType::InputObject(def)), Some(fields)) =
+                (schema.types.get(name), value.as_object())
+            else {
+                return value.clone();
+            };
+            let mut coerced = Vec::with_capacity(def.fields.len());
+            for (field_name, field_def) in &def.fields {
+                let field_value = fields
+                    .iter()
+                    .find_map(|(key, value)| (key == field_name).then_some(value))
+                    .or(field_def.default_value.as_ref());
+                if let Some(field_value) = field_value {
+                    let field_value = coerce_input_literal(schema, &field_def.ty, field_value);
+                    coerced.push((field_name.clone(), field_value));
+                }
+            }
+            value.same_location(Value::Object(coerced))
+        }
+    }
+}
+
 impl OperationType {
     /// Get the name of this operation type as it would appear in GraphQL source code.
     pub fn name(self) -> &'static str {
@@ -705,6 +918,15 @@ impl From<OperationType> for DirectiveLocation {
 }
 
 impl VariableDefinition {
+    /// Returns true if a request is required to provide a value for this variable.
+    ///
+    /// That means:
+    /// - its type is non-null, and
+    /// - it does not have a default value
+    pub fn is_required(&self) -> bool {
+        self.ty.is_non_null() && self.default_value.is_none()
+    }
+
     serialize_method!();
 }
 
@@ -847,6 +1069,23 @@ impl FieldDefinition {
         self.arguments.iter().find(|argument| argument.name == name)
     }
 
+    /// Returns whether this field is marked with the `@deprecated` directive.
+    pub fn is_deprecated(&self) -> bool {
+        self.directives.has("deprecated")
+    }
+
+    /// Returns the `reason` argument of the `@deprecated` directive, if any.
+    ///
+    /// When the directive is present without an explicit reason,
+    /// this returns the default reason from its built-in definition: `No longer supported`.
+    pub fn deprecation_reason(&self) -> Option<&str> {
+        let directive = self.directives.get("deprecated")?;
+        match directive.argument_by_name("reason") {
+            Some(reason) => reason.as_str(),
+            None => Some("No longer supported"),
+        }
+    }
+
     serialize_method!();
 }
 
@@ -1527,6 +1766,12 @@ impl From<bool> for Value {
     }
 }
 
+impl<V: Into<Node<Value>>> From<Vec<V>> for Value {
+    fn from(value: Vec<V>) -> Self {
+        Value::List(value.into_iter().map(Into::into).collect())
+    }
+}
+
 impl From<()> for Node<Value> {
     fn from(value: ()) -> Self {
         Node::new(value.into())
@@ -1569,6 +1814,12 @@ impl From<bool> for Node<Value> {
     }
 }
 
+impl<V: Into<Node<Value>>> From<Vec<V>> for Node<Value> {
+    fn from(value: Vec<V>) -> Self {
+        Node::new(value.into())
+    }
+}
+
 impl<N: Into<Name>, V: Into<Node<Value>>> From<(N, V)> for Node<Argument> {
     fn from((name, value): (N, V)) -> Self {
         Node::new(Argument {
@@ -30,7 +30,23 @@
 //! [`serialize`][Document::serialize] methods return a builder
 //! that has chaining methods for setting serialization configuration,
 //! and also implements `Display` and `ToString`.
-
+//!
+//! Comments are ignored tokens, so they are not part of the AST.
+//! The [`comment`][Serialize::comment] configuration method writes `#` lines
+//! before the serialized node:
+//!
+//! ```
+//! use apollo_compiler::ast::Document;
+//!
+//! let doc = Document::parse("type Query { a: Int }", "schema.graphql").unwrap();
+//! let output = doc.definitions[0]
+//!     .serialize()
+//!     .comment("Generated code\nDo not edit")
+//!     .to_string();
+//! assert_eq!(output, "# Generated code\n# Do not edit\ntype Query {\n  a: Int\n}");
+//! ```
+
+use crate::node::NodeLocation;
 use crate::Node;
 use crate::NodeStr;
 use std::collections::HashMap;
@@ -91,6 +107,7 @@ pub enum Definition {
     InputObjectTypeExtension(Node<InputObjectTypeExtension>),
 }
 
+/// An operation definition: a query, mutation, or subscription.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub struct OperationDefinition {
     pub operation_type: OperationType,
@@ -108,6 +125,7 @@ pub struct FragmentDefinition {
     pub selection_set: Vec<Selection>,
 }
 
+/// A directive definition.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub struct DirectiveDefinition {
     pub description: Option<NodeStr>,
@@ -117,6 +135,7 @@ pub struct DirectiveDefinition {
     pub locations: Vec<DirectiveLocation>,
 }
 
+/// A `schema` definition, listing the root operation types.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub struct SchemaDefinition {
     pub description: Option<NodeStr>,
@@ -157,6 +176,9 @@ pub struct UnionTypeDefinition {
     pub members: Vec<NamedType>,
 }
 
+/// An enum type definition.
+///
+/// Each value can have its own description and directives.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub struct EnumTypeDefinition {
     pub description: Option<NodeStr>,
@@ -228,6 +250,11 @@ pub struct Argument {
     pub value: Node<Value>,
 }
 
+/// An ordered list of directives applied to a definition or selection.
+///
+/// Directives are serialized in list order,
+/// which is source order for a parsed document or insertion order otherwise.
+/// Duplicates are kept as-is: see [`DirectiveList::dedup_non_repeatable`].
 #[derive(Clone, Eq, PartialEq, Hash, Default)]
 pub struct DirectiveList(pub Vec<Node<Directive>>);
 
@@ -364,6 +391,30 @@ pub struct FloatValue(String);
 #[non_exhaustive]
 pub struct FloatOverflowError {}
 
+/// An argument of a directive application that does not match the directive definition,
+/// returned by [`Directive::resolved_arguments`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub enum DirectiveArgumentError {
+    #[error("cannot find directive `@{directive}` in the schema")]
+    UndefinedDirective { directive: Name },
+    #[error("the directive `@{directive}` has no argument `{argument}`")]
+    UndefinedArgument {
+        directive: Name,
+        argument: Name,
+        location: Option<NodeLocation>,
+    },
+    #[error("the required argument `@{directive}({argument}:)` is not provided")]
+    MissingArgument { directive: Name, argument: Name },
+    #[error("invalid value for argument `@{directive}({argument}:)`: {message}")]
+    InvalidValue {
+        directive: Name,
+        argument: Name,
+        message: String,
+        location: Option<NodeLocation>,
+    },
+}
+
 /// Trait implemented by extensible type definitions, to associate the extension type with the base
 /// definition type.
 pub(crate) trait Extensible {
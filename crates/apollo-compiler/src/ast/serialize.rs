@@ -13,6 +13,7 @@ pub struct Serialize<'a, T> {
 #[derive(Debug, Clone)]
 pub(crate) struct Config<'a> {
     indent_prefix: Option<&'a str>,
+    comment: Option<&'a str>,
 }
 
 pub(crate) struct State<'config, 'fmt, 'fmt2> {
@@ -38,12 +39,22 @@ impl<'a, T> Serialize<'a, T> {
         self.config.indent_prefix = None;
         self
     }
+
+    /// Write `text` as a comment before the node, with one `#` line per line of `text`.
+    ///
+    /// The comment is always followed by a line break, even when indentation is disabled,
+    /// so that the output parses the same as without it.
+    pub fn comment(mut self, text: &'a str) -> Self {
+        self.config.comment = Some(text);
+        self
+    }
 }
 
 impl Default for Config<'_> {
     fn default() -> Self {
         Self {
             indent_prefix: Some("  "),
+            comment: None,
         }
     }
 }
@@ -117,6 +128,23 @@ impl<'config, 'fmt, 'fmt2> State<'config, 'fmt, 'fmt2> {
         self.config.indent_prefix.is_some()
     }
 
+    /// Writes the configured comment, if any.
+    /// This does not count as output, since a comment is an ignored token.
+    fn leading_comment(&mut self) -> fmt::Result {
+        let Some(comment) = self.config.comment else {
+            return Ok(());
+        };
+        // A carriage return on its own also ends a comment
+        for line in comment.lines().flat_map(|line| line.split('\r')) {
+            if line.is_empty() {
+                self.output.write_str("#\n")?;
+            } else {
+                display!(self, "# {}\n", line)?;
+            }
+        }
+        Ok(())
+    }
+
     pub(crate) fn on_single_line<R>(&mut self, f: impl FnOnce(&mut Self) -> R) -> R {
         let indent_prefix = self.config.indent_prefix.take();
         let result = f(self);
@@ -979,12 +1007,14 @@ macro_rules! impl_display {
             /// Serialize to GraphQL syntax
             impl Display for Serialize<'_, $ty> {
                 fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                    self.node.serialize_impl(&mut State {
+                    let mut state = State {
                         config: self.config.clone(),
                         indent_level: 0,
                         output: f,
                         output_empty: true,
-                    })
+                    };
+                    state.leading_comment()?;
+                    self.node.serialize_impl(&mut state)
                 }
             }
         )+
@@ -10,6 +10,9 @@ pub(crate) trait InputDatabase {
     #[salsa::input]
     fn schema_input(&self) -> Option<Arc<crate::Schema>>;
 
+    #[salsa::input]
+    fn validation_options(&self) -> Arc<crate::validation::ValidationOptions>;
+
     /// Get the GraphQL source text for a file.
     #[salsa::invoke(source_code)]
     fn source_code(&self, file_id: FileId) -> Arc<String>;
@@ -70,6 +70,7 @@
 use crate::execution::GraphQLError;
 use crate::execution::GraphQLLocation;
 use crate::validation::FileId;
+use crate::validation::Severity;
 use crate::SourceFile;
 use crate::SourceMap;
 use ariadne::ColorGenerator;
@@ -203,11 +204,24 @@ impl<'s> CliReport<'s> {
         sources: &'s SourceMap,
         main_location: Option<NodeLocation>,
         color: Color,
+    ) -> Self {
+        Self::builder_with_severity(sources, main_location, color, Severity::Error)
+    }
+
+    pub(crate) fn builder_with_severity(
+        sources: &'s SourceMap,
+        main_location: Option<NodeLocation>,
+        color: Color,
+        severity: Severity,
     ) -> Self {
         let (file_id, range) = main_location
             .and_then(|location| map_span(sources, location))
             .unwrap_or((FileId::NONE, 0..0));
-        let report = ariadne::Report::build(ReportKind::Error, file_id, range.start);
+        let kind = match severity {
+            Severity::Error => ReportKind::Error,
+            Severity::Warning => ReportKind::Warning,
+        };
+        let report = ariadne::Report::build(kind, file_id, range.start);
         let enable_color = match color {
             Color::Never => false,
             // Rely on ariadne's `auto-color` feature, which uses `concolor` to enable colors
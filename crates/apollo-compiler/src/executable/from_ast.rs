@@ -14,7 +14,8 @@ pub(crate) fn document_from_ast(
 ) -> ExecutableDocument {
     let mut named_operations = IndexMap::new();
     let mut anonymous_operation = None::<Node<Operation>>;
-    let mut multiple_anonymous = false;
+    let mut anonymous_locations = Vec::new();
+    let mut operation_names = Vec::new();
     let mut fragments = IndexMap::new();
     let mut errors = BuildErrors {
         errors,
@@ -29,12 +30,7 @@ pub(crate) fn document_from_ast(
         match definition {
             ast::Definition::OperationDefinition(operation) => {
                 if let Some(name) = &operation.name {
-                    if let Some(anonymous) = &anonymous_operation {
-                        errors.errors.push(
-                            anonymous.location(),
-                            BuildError::AmbiguousAnonymousOperation,
-                        )
-                    }
+                    operation_names.push(name.clone());
                     if let Entry::Vacant(entry) = named_operations.entry(name.clone()) {
                         errors.path.root = ExecutableDefinitionName::NamedOperation(
                             operation.operation_type,
@@ -59,23 +55,12 @@ pub(crate) fn document_from_ast(
                             },
                         );
                     }
-                } else if let Some(previous) = &anonymous_operation {
-                    if !multiple_anonymous {
-                        multiple_anonymous = true;
-                        errors
-                            .errors
-                            .push(previous.location(), BuildError::AmbiguousAnonymousOperation)
-                    }
-                    errors.errors.push(
-                        operation.location(),
-                        BuildError::AmbiguousAnonymousOperation,
-                    )
-                } else if !named_operations.is_empty() {
-                    errors.errors.push(
-                        operation.location(),
-                        BuildError::AmbiguousAnonymousOperation,
-                    )
                 } else {
+                    anonymous_locations.push(operation.location());
+                    if anonymous_operation.is_some() || !named_operations.is_empty() {
+                        // Only the first anonymous operation is kept, if it comes first
+                        continue;
+                    }
                     errors.path.root =
                         ExecutableDefinitionName::AnonymousOperation(operation.operation_type);
                     if let Some(op) = Operation::from_ast(schema, &mut errors, operation) {
@@ -118,6 +103,23 @@ pub(crate) fn document_from_ast(
             }
         }
     }
+    // An anonymous operation must be the only operation in the document
+    if !operation_names.is_empty() {
+        for location in anonymous_locations {
+            errors.errors.push(
+                location,
+                BuildError::MixedAnonymousOperation {
+                    named_operations: operation_names.clone(),
+                },
+            )
+        }
+    } else if anonymous_locations.len() > 1 {
+        for location in anonymous_locations {
+            errors
+                .errors
+                .push(location, BuildError::AmbiguousAnonymousOperation)
+        }
+    }
     ExecutableDocument {
         sources: document.sources.clone(),
         named_operations,
@@ -22,6 +22,7 @@ pub use crate::ast::{
 use crate::validation::DiagnosticList;
 use crate::validation::NodeLocation;
 use crate::validation::Valid;
+use crate::validation::ValidationOptions;
 use crate::validation::WithErrors;
 use std::fmt;
 use std::sync::Arc;
@@ -115,6 +116,9 @@ pub(crate) enum BuildError {
     #[error("anonymous operation cannot be selected when the document contains other operations")]
     AmbiguousAnonymousOperation,
 
+    #[error("anonymous operation must be the only operation in the document")]
+    MixedAnonymousOperation { named_operations: Vec<Name> },
+
     #[error(
         "the operation `{name_at_previous_location}` is defined multiple times in the document"
     )]
@@ -163,6 +167,29 @@ pub(crate) enum BuildError {
     },
 }
 
+impl BuildError {
+    /// Stable code for [`DiagnosticData::code`][crate::validation::DiagnosticData::code]
+    pub(crate) fn code(&self) -> &'static str {
+        match self {
+            Self::TypeSystemDefinition { .. } => "TypeSystemDefinition",
+            Self::AmbiguousAnonymousOperation => "AmbiguousAnonymousOperation",
+            Self::MixedAnonymousOperation { .. } => "MixedAnonymousOperation",
+            Self::OperationNameCollision { .. } => "OperationNameCollision",
+            Self::FragmentNameCollision { .. } => "FragmentNameCollision",
+            Self::UndefinedRootOperation { .. } => "UndefinedRootOperation",
+            Self::UndefinedTypeInNamedFragmentTypeCondition { .. } => {
+                "UndefinedTypeInNamedFragmentTypeCondition"
+            }
+            Self::UndefinedTypeInInlineFragmentTypeCondition { .. } => {
+                "UndefinedTypeInInlineFragmentTypeCondition"
+            }
+            Self::SubselectionOnScalarType { .. } => "SubselectionOnScalarType",
+            Self::SubselectionOnEnumType { .. } => "SubselectionOnEnumType",
+            Self::UndefinedField { .. } => "UndefinedField",
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct SelectionPath {
     pub(crate) root: ExecutableDefinitionName,
@@ -223,6 +250,37 @@ impl ExecutableDocument {
         errors.into_valid_result(doc)
     }
 
+    /// Validate this executable document against a schema.
+    ///
+    /// The schema is expected to have been validated beforehand and is not validated again,
+    /// so only diagnostics for this document are returned.
+    /// This makes it cheap to validate many documents against the same schema,
+    /// such as an allowlist of operations:
+    ///
+    /// ```
+    /// use apollo_compiler::{ExecutableDocument, Schema};
+    ///
+    /// let schema = Schema::parse_and_validate(
+    ///     "type Query { user(id: ID!): String }",
+    ///     "schema.graphql",
+    /// )
+    /// .unwrap();
+    /// let allowlist = [
+    ///     ("a.graphql", "query A($id: ID!) { user(id: $id) }"),
+    ///     ("b.graphql", "query B { user(id: $id) }"),
+    ///     ("c.graphql", "query C { user(id: 1) }"),
+    /// ];
+    /// for (path, source) in allowlist {
+    ///     let document = ExecutableDocument::parse(&schema, source, path).unwrap();
+    ///     match document.validate(&schema) {
+    ///         Ok(_) => assert_ne!(path, "b.graphql"),
+    ///         Err(with_errors) => {
+    ///             assert_eq!(path, "b.graphql");
+    ///             assert_eq!(with_errors.errors.len(), 1);
+    ///         }
+    ///     }
+    /// }
+    /// ```
     pub fn validate(self, schema: &Valid<Schema>) -> Result<Valid<Self>, WithErrors<Self>> {
         let mut sources = IndexMap::clone(&schema.sources);
         sources.extend(self.sources.iter().map(|(k, v)| (*k, v.clone())));
@@ -231,6 +289,23 @@ impl ExecutableDocument {
         errors.into_valid_result(self)
     }
 
+    /// Like [`validate`][Self::validate], with non-default [`ValidationOptions`].
+    ///
+    /// The document is valid if only [warnings][crate::validation::Severity::Warning] remain.
+    /// They are returned alongside it.
+    #[allow(clippy::result_large_err)] // same error type as `validate`
+    pub fn validate_with(
+        self,
+        schema: &Valid<Schema>,
+        options: &ValidationOptions,
+    ) -> Result<(Valid<Self>, DiagnosticList), WithErrors<Self>> {
+        let mut sources = IndexMap::clone(&schema.sources);
+        sources.extend(self.sources.iter().map(|(k, v)| (*k, v.clone())));
+        let mut errors = DiagnosticList::new(Arc::new(sources));
+        validation::validate_executable_document_with(&mut errors, schema, &self, options);
+        errors.into_valid_result_with_options(self, options)
+    }
+
     /// Returns an iterator of operations, both anonymous and named
     pub fn all_operations(&self) -> impl Iterator<Item = &'_ Node<Operation>> {
         self.anonymous_operation
@@ -269,6 +344,36 @@ impl ExecutableDocument {
         .ok_or(GetOperationError())
     }
 
+    /// Return the variable definitions of the relevant operation for a request
+    ///
+    /// The operation is selected like in [`get_operation`][Self::get_operation].
+    /// Each definition has a name, a type, and an optional default value,
+    /// and its [`location`][Node::location] points to the source text.
+    ///
+    /// ```
+    /// use apollo_compiler::ExecutableDocument;
+    /// use apollo_compiler::Schema;
+    ///
+    /// let schema = Schema::parse_and_validate("type Query { a(b: Int): Int }", "schema.graphql").unwrap();
+    /// let query = "query Q($b: Int = 1, $c: [String!]!) { a(b: $b) }";
+    /// let document = ExecutableDocument::parse(&schema, query, "query.graphql").unwrap();
+    ///
+    /// let variables = document.operation_variables(Some("Q")).unwrap();
+    /// assert_eq!(variables.len(), 2);
+    /// assert_eq!(variables[0].name, "b");
+    /// assert_eq!(variables[0].ty.to_string(), "Int");
+    /// assert_eq!(variables[0].default_value.as_ref().unwrap().to_i32(), Some(1));
+    /// assert_eq!(variables[1].ty.to_string(), "[String!]!");
+    /// assert!(variables[1].default_value.is_none());
+    /// ```
+    pub fn operation_variables(
+        &self,
+        name_request: Option<&str>,
+    ) -> Result<&[Node<VariableDefinition>], GetOperationError> {
+        self.get_operation(name_request)
+            .map(|operation| operation.variables.as_slice())
+    }
+
     /// Similar to [`get_operation`][Self::get_operation] but returns a mutable reference.
     pub fn get_operation_mut(
         &mut self,
@@ -331,6 +436,28 @@ impl Operation {
         &self.selection_set.ty
     }
 
+    /// Returns the names of the variables that a request must provide values for,
+    /// in declaration order: variables of a non-null type without a default value.
+    ///
+    /// Whether default values match the type of their variable is checked by validation.
+    ///
+    /// ```
+    /// use apollo_compiler::{ExecutableDocument, Schema};
+    ///
+    /// let schema = Schema::parse_and_validate("type Query { a(x: Int, y: Int): Int }", "schema.graphql").unwrap();
+    /// let query = "query($x: Int!, $y: Int! = 1, $z: Int) { a(x: $x, y: $y) b: a(x: $z) }";
+    /// let document = ExecutableDocument::parse_and_validate(&schema, query, "query.graphql").unwrap();
+    /// let operation = document.get_operation(None).unwrap();
+    /// assert_eq!(operation.required_variables(), ["x"]);
+    /// ```
+    pub fn required_variables(&self) -> Vec<Name> {
+        self.variables
+            .iter()
+            .filter(|variable| variable.is_required())
+            .map(|variable| variable.name.clone())
+            .collect()
+    }
+
     /// Return whether this operation is a query that only selects introspection meta-fields:
     /// `__type`, `__schema`, and `__typename`
     pub fn is_introspection(&self, document: &ExecutableDocument) -> bool {
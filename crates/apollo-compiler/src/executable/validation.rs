@@ -4,6 +4,7 @@ use crate::validation::Details;
 use crate::validation::DiagnosticList;
 use crate::validation::FileId;
 use crate::validation::Valid;
+use crate::validation::ValidationOptions;
 use crate::ExecutableDocument;
 use crate::InputDatabase;
 use crate::Schema;
@@ -14,10 +15,19 @@ pub(crate) fn validate_executable_document(
     errors: &mut DiagnosticList,
     schema: &Schema,
     document: &ExecutableDocument,
+) {
+    validate_executable_document_with(errors, schema, document, &ValidationOptions::default())
+}
+
+pub(crate) fn validate_executable_document_with(
+    errors: &mut DiagnosticList,
+    schema: &Schema,
+    document: &ExecutableDocument,
+    options: &ValidationOptions,
 ) {
     validate_with_or_without_schema(errors, document);
     validate_with_schema(errors, schema, document);
-    compiler_validation(errors, Some(schema), document);
+    compiler_validation(errors, Some(schema), document, options);
     // TODO
 }
 
@@ -26,7 +36,7 @@ pub(crate) fn validate_standalone_executable(
     document: &ExecutableDocument,
 ) {
     validate_with_or_without_schema(errors, document);
-    compiler_validation(errors, None, document);
+    compiler_validation(errors, None, document, &ValidationOptions::default());
 }
 
 fn validate_with_schema(
@@ -49,8 +59,12 @@ fn compiler_validation(
     errors: &mut DiagnosticList,
     schema: Option<&Schema>,
     document: &ExecutableDocument,
+    options: &ValidationOptions,
 ) {
     let mut compiler = crate::ApolloCompiler::new();
+    compiler
+        .db
+        .set_validation_options(Arc::new(options.clone()));
     let mut ids = Vec::new();
     if let Some(schema) = schema {
         for (id, source) in schema.sources.iter() {
@@ -154,13 +154,7 @@ impl GraphQLLocation {
     /// Convert a `NodeLocation` to a line and column number
     pub fn from_node(sources: &SourceMap, location: Option<NodeLocation>) -> Option<Self> {
         let loc = location?;
-        let source = sources.get(&loc.file_id)?;
-        source
-            .get_line_column(loc.offset())
-            .map(|(line, column)| GraphQLLocation {
-                line: line + 1,
-                column: column + 1,
-            })
+        loc.line_column_in(sources.get(&loc.file_id)?)
     }
 }
 
@@ -37,6 +37,7 @@ impl ApolloCompiler {
         // TODO(@goto-bus-stop) can we make salsa fill in these defaults for us…?
         db.set_source_files(vec![]);
         db.set_schema_input(None);
+        db.set_validation_options(Default::default());
 
         Self { db }
     }
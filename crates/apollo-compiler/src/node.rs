@@ -2,6 +2,7 @@ use crate::execution::GraphQLLocation;
 use crate::schema::Component;
 use crate::schema::ComponentOrigin;
 use crate::validation::FileId;
+use crate::SourceFile;
 use crate::SourceMap;
 use apollo_parser::SyntaxNode;
 use rowan::TextRange;
@@ -243,6 +244,14 @@ impl NodeLocation {
         }
     }
 
+    /// An empty location at the start of the given file
+    pub(crate) fn file_start(file_id: FileId) -> Self {
+        Self {
+            file_id,
+            text_range: TextRange::empty(0.into()),
+        }
+    }
+
     /// Returns the file ID for this location
     pub fn file_id(&self) -> FileId {
         self.file_id
@@ -263,6 +272,36 @@ impl NodeLocation {
         self.text_range.len().into()
     }
 
+    /// Converts the start of this location to 1-based line and column numbers,
+    /// given the source file it points into.
+    ///
+    /// Lines and columns are counted the same way as in rendered diagnostics.
+    /// Returns `None` if the offset is not a character boundary within the source text.
+    /// [`Node::line_column`] and [`GraphQLLocation::from_node`] look up the source file
+    /// by [`file_id`][Self::file_id] instead.
+    ///
+    /// ```
+    /// use apollo_compiler::ast::Document;
+    ///
+    /// let source = "type Query {\r\n  \"caf\u{e9}\" name: Int\r\n}";
+    /// let document = Document::parse(source, "schema.graphql").unwrap();
+    /// let object = document.definitions[0].as_object_type_definition().unwrap();
+    /// let location = object.fields[0].name.location().unwrap();
+    /// let source_file = &document.sources[&location.file_id()];
+    /// let line_column = location.line_column_in(source_file).unwrap();
+    /// assert_eq!((line_column.line, line_column.column), (2, 10));
+    /// ```
+    pub fn line_column_in(&self, source: &SourceFile) -> Option<GraphQLLocation> {
+        if !source.source_text().is_char_boundary(self.offset()) {
+            return None;
+        }
+        let (line, column) = source.get_line_column(self.offset())?;
+        Some(GraphQLLocation {
+            line: line + 1,
+            column: column + 1,
+        })
+    }
+
     /// Best effort at making a location with the given start and end
     pub fn recompose(start_of: Option<Self>, end_of: Option<Self>) -> Option<Self> {
         match (start_of, end_of) {
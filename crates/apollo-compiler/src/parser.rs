@@ -34,6 +34,43 @@ pub struct SourceFile {
     pub(crate) source: OnceLock<MappedSource>,
 }
 
+/// Source files that contributed to a schema or document, keyed by [`FileId`].
+///
+/// Parsed ASTs are not retained: the parsed [`ast::Document`] is owned by the caller,
+/// and the original source text can be recovered from this map,
+/// for example to render diagnostic snippets across several files:
+///
+/// ```
+/// use apollo_compiler::ast;
+/// use apollo_compiler::validation::FileId;
+/// use apollo_compiler::Schema;
+///
+/// let doc_a = ast::Document::parse("type Query { a: Int }", "a.graphql").unwrap();
+/// let doc_b = ast::Document::parse("extend type Query { b: Int }", "b.graphql").unwrap();
+///
+/// let schema = Schema::builder()
+///     .add_ast(&doc_a)
+///     .add_ast(&doc_b)
+///     .build()
+///     .unwrap();
+///
+/// // List the file ids of all parsed inputs (excluding built-in definitions)
+/// let file_ids: Vec<_> = schema
+///     .sources
+///     .keys()
+///     .filter(|&&id| id != FileId::BUILT_IN)
+///     .copied()
+///     .collect();
+/// assert_eq!(file_ids.len(), 2);
+///
+/// // Retrieve the original path and source text by file id
+/// let file = &schema.sources[&file_ids[1]];
+/// assert_eq!(file.path(), std::path::Path::new("b.graphql"));
+/// assert_eq!(file.source_text(), "extend type Query { b: Int }");
+///
+/// // Each AST also records its own source file
+/// assert!(doc_b.sources.contains_key(&file_ids[1]));
+/// ```
 pub type SourceMap = Arc<IndexMap<FileId, Arc<SourceFile>>>;
 
 /// Translate byte offsets to ariadne's char offsets.
@@ -133,13 +170,17 @@ impl Parser {
         });
         Arc::make_mut(&mut errors.sources).insert(file_id, source_file);
         for parser_error in tree.errors() {
+            // Underline the precise part of the input that the error is about, if known
+            let range = parser_error.detail_range().unwrap_or_else(|| {
+                parser_error.index()..parser_error.index() + parser_error.data().len()
+            });
             // Silently skip parse errors at index beyond 4 GiB.
             // Rowan in apollo-parser might complain about files that large
             // before we get here anyway.
-            let Ok(index) = parser_error.index().try_into() else {
+            let Ok(index) = range.start.try_into() else {
                 continue;
             };
-            let Ok(len) = parser_error.data().len().try_into() else {
+            let Ok(len) = range.len().try_into() else {
                 continue;
             };
             let location = Some(NodeLocation {
@@ -107,6 +107,8 @@ impl SchemaBuilder {
         document: &ast::Document,
         executable_definitions_are_errors: bool,
     ) {
+        // `scalar Foo { … }` parses as a scalar definition followed by a query shorthand
+        let mut previous_scalar: Option<&Name> = None;
         for definition in &document.definitions {
             macro_rules! type_definition {
                 ($def: ident, $Type: ident, is_scalar = $is_scalar: literal) => {
@@ -241,15 +243,22 @@ impl SchemaBuilder {
                 ast::Definition::OperationDefinition(_)
                 | ast::Definition::FragmentDefinition(_) => {
                     if executable_definitions_are_errors {
-                        self.errors.push(
-                            definition.location(),
-                            BuildError::ExecutableDefinition {
+                        let error = match previous_scalar.filter(|_| is_query_shorthand(definition))
+                        {
+                            Some(name) => BuildError::ScalarWithFields { name: name.clone() },
+                            None => BuildError::ExecutableDefinition {
                                 describe: definition.describe(),
                             },
-                        )
+                        };
+                        self.errors.push(definition.location(), error)
                     }
                 }
             }
+            previous_scalar = match definition {
+                ast::Definition::ScalarTypeDefinition(def) => Some(&def.name),
+                ast::Definition::ScalarTypeExtension(ext) => Some(&ext.name),
+                _ => None,
+            };
         }
     }
 
@@ -327,6 +336,18 @@ impl SchemaBuilder {
     }
 }
 
+/// Returns whether `definition` can be written as a bare selection set `{ … }`:
+/// an anonymous query without variables or directives
+fn is_query_shorthand(definition: &ast::Definition) -> bool {
+    let ast::Definition::OperationDefinition(operation) = definition else {
+        return false;
+    };
+    operation.operation_type == OperationType::Query
+        && operation.name.is_none()
+        && operation.variables.is_empty()
+        && operation.directives.is_empty()
+}
+
 fn adopt_type_extensions(
     errors: &mut DiagnosticList,
     type_name: &Name,
@@ -10,6 +10,7 @@ use indexmap::IndexMap;
 use indexmap::IndexSet;
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::collections::VecDeque;
 use std::path::Path;
 use std::sync::OnceLock;
 
@@ -28,6 +29,7 @@ use crate::name;
 use crate::ty;
 use crate::validation::DiagnosticList;
 use crate::validation::Valid;
+use crate::validation::ValidationOptions;
 use crate::validation::WithErrors;
 
 /// High-level representation of a GraphQL schema
@@ -176,6 +178,20 @@ pub struct Implementers {
     pub interfaces: HashSet<Name>,
 }
 
+/// The type definitions of a schema grouped by kind, returned by [`Schema::type_definitions`].
+///
+/// Each definition includes the components from its type extensions.
+/// Maps are in the same order as [`Schema::types`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TypeDefinitions<'schema> {
+    pub scalars: IndexMap<NamedType, &'schema Node<ScalarType>>,
+    pub objects: IndexMap<NamedType, &'schema Node<ObjectType>>,
+    pub interfaces: IndexMap<NamedType, &'schema Node<InterfaceType>>,
+    pub unions: IndexMap<NamedType, &'schema Node<UnionType>>,
+    pub enums: IndexMap<NamedType, &'schema Node<EnumType>>,
+    pub input_objects: IndexMap<NamedType, &'schema Node<InputObjectType>>,
+}
+
 /// AST node that has been skipped during conversion to `Schema`
 #[derive(thiserror::Error, Debug, Clone)]
 pub(crate) enum BuildError {
@@ -202,6 +218,9 @@ pub(crate) enum BuildError {
     #[error("built-in scalar definitions must be omitted")]
     BuiltInScalarTypeRedefinition,
 
+    #[error("scalar type `{name}` cannot have fields")]
+    ScalarWithFields { name: Name },
+
     #[error("schema extension without a schema definition")]
     OrphanSchemaExtension,
 
@@ -286,6 +305,35 @@ pub(crate) enum BuildError {
     },
 }
 
+impl BuildError {
+    /// Stable code for [`DiagnosticData::code`][crate::validation::DiagnosticData::code]
+    pub(crate) fn code(&self) -> &'static str {
+        match self {
+            Self::ExecutableDefinition { .. } => "ExecutableDefinition",
+            Self::SchemaDefinitionCollision { .. } => "SchemaDefinitionCollision",
+            Self::DirectiveDefinitionCollision { .. } => "DirectiveDefinitionCollision",
+            Self::TypeDefinitionCollision { .. } => "TypeDefinitionCollision",
+            Self::BuiltInScalarTypeRedefinition => "BuiltInScalarTypeRedefinition",
+            Self::ScalarWithFields { .. } => "ScalarWithFields",
+            Self::OrphanSchemaExtension => "OrphanSchemaExtension",
+            Self::OrphanTypeExtension { .. } => "OrphanTypeExtension",
+            Self::TypeExtensionKindMismatch { .. } => "TypeExtensionKindMismatch",
+            Self::DuplicateRootOperation { .. } => "DuplicateRootOperation",
+            Self::DuplicateImplementsInterfaceInObject { .. } => {
+                "DuplicateImplementsInterfaceInObject"
+            }
+            Self::DuplicateImplementsInterfaceInInterface { .. } => {
+                "DuplicateImplementsInterfaceInInterface"
+            }
+            Self::ObjectFieldNameCollision { .. } => "ObjectFieldNameCollision",
+            Self::InterfaceFieldNameCollision { .. } => "InterfaceFieldNameCollision",
+            Self::EnumValueNameCollision { .. } => "EnumValueNameCollision",
+            Self::UnionMemberNameCollision { .. } => "UnionMemberNameCollision",
+            Self::InputFieldNameCollision { .. } => "InputFieldNameCollision",
+        }
+    }
+}
+
 /// Could not find the requested field definition
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum FieldLookupError<'schema> {
@@ -339,12 +387,51 @@ impl Schema {
         SchemaBuilder::new()
     }
 
+    /// Run all type system validations, returning every diagnostic at once.
+    ///
+    /// Diagnostics are sorted by source location,
+    /// so their order is stable across runs.
+    ///
+    /// ```
+    /// use apollo_compiler::Schema;
+    ///
+    /// let input = r#"
+    ///     type Query { a: Undefined }
+    ///     scalar Unused @unknown
+    ///     union Empty
+    /// "#;
+    /// let schema = Schema::parse(input, "schema.graphql").unwrap();
+    /// let errors = schema.validate().unwrap_err().errors;
+    /// let messages: Vec<_> = errors.iter().map(|error| error.error.to_string()).collect();
+    /// assert_eq!(
+    ///     messages,
+    ///     [
+    ///         "cannot find type `Undefined` in this document",
+    ///         "cannot find directive `@unknown` in this document",
+    ///         "union type `Empty` must have at least one member type",
+    ///     ]
+    /// );
+    /// ```
     pub fn validate(self) -> Result<Valid<Self>, WithErrors<Self>> {
         let mut errors = DiagnosticList::new(self.sources.clone());
         validation::validate_schema(&mut errors, &self);
         errors.into_valid_result(self)
     }
 
+    /// Like [`validate`][Self::validate], with non-default [`ValidationOptions`].
+    ///
+    /// The schema is valid if only [warnings][crate::validation::Severity::Warning] remain.
+    /// They are returned alongside it.
+    #[allow(clippy::result_large_err)] // same error type as `validate`
+    pub fn validate_with(
+        self,
+        options: &ValidationOptions,
+    ) -> Result<(Valid<Self>, DiagnosticList), WithErrors<Self>> {
+        let mut errors = DiagnosticList::new(self.sources.clone());
+        validation::validate_schema_with(&mut errors, &self, options);
+        errors.into_valid_result_with_options(self, options)
+    }
+
     /// Returns the type with the given name, if it is a scalar type
     pub fn get_scalar(&self, name: &str) -> Option<&Node<ScalarType>> {
         if let Some(ExtendedType::Scalar(ty)) = self.types.get(name) {
@@ -399,7 +486,74 @@ impl Schema {
         }
     }
 
+    /// Returns the type definitions of this schema grouped by kind
+    ///
+    /// This includes built-in scalars and introspection types,
+    /// which can be skipped with [`ExtendedType::is_built_in`].
+    ///
+    /// ```
+    /// use apollo_compiler::Schema;
+    ///
+    /// let schema = Schema::parse(
+    ///     "type Query { a: Int } extend type Query { b: Int } enum E { A }",
+    ///     "sdl",
+    /// )
+    /// .unwrap();
+    /// let definitions = schema.type_definitions();
+    /// assert_eq!(definitions.objects["Query"].fields.len(), 2);
+    /// assert!(definitions.enums.contains_key("E"));
+    /// ```
+    pub fn type_definitions(&self) -> TypeDefinitions<'_> {
+        let mut definitions = TypeDefinitions::default();
+        for (name, def) in &self.types {
+            match def {
+                ExtendedType::Scalar(def) => {
+                    definitions.scalars.insert(name.clone(), def);
+                }
+                ExtendedType::Object(def) => {
+                    definitions.objects.insert(name.clone(), def);
+                }
+                ExtendedType::Interface(def) => {
+                    definitions.interfaces.insert(name.clone(), def);
+                }
+                ExtendedType::Union(def) => {
+                    definitions.unions.insert(name.clone(), def);
+                }
+                ExtendedType::Enum(def) => {
+                    definitions.enums.insert(name.clone(), def);
+                }
+                ExtendedType::InputObject(def) => {
+                    definitions.input_objects.insert(name.clone(), def);
+                }
+            }
+        }
+        definitions
+    }
+
     /// Returns the name of the object type for the root operation with the given operation kind
+    ///
+    /// When the schema has an explicit `schema { … }` definition, only the root operations
+    /// it declares are returned. Otherwise, the object types named `Query`, `Mutation`,
+    /// and `Subscription` are the root operation types, if they exist.
+    ///
+    /// ```
+    /// use apollo_compiler::ast::OperationType;
+    /// use apollo_compiler::Schema;
+    ///
+    /// let implicit = Schema::parse("type Query { a: Int } type Mutation { b: Int }", "sdl").unwrap();
+    /// assert_eq!(implicit.root_operation(OperationType::Query).unwrap(), "Query");
+    /// assert_eq!(implicit.root_operation(OperationType::Mutation).unwrap(), "Mutation");
+    /// assert!(implicit.root_operation(OperationType::Subscription).is_none());
+    ///
+    /// let explicit = Schema::parse(
+    ///     "schema { query: Root } type Root { a: Int } type Mutation { b: Int }",
+    ///     "sdl",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(explicit.root_operation(OperationType::Query).unwrap(), "Root");
+    /// // `Mutation` is not declared in the schema definition, so it is not a root operation type
+    /// assert!(explicit.root_operation(OperationType::Mutation).is_none());
+    /// ```
     pub fn root_operation(&self, operation_type: ast::OperationType) -> Option<&NamedType> {
         match operation_type {
             ast::OperationType::Query => &self.schema_definition.query,
@@ -449,6 +603,67 @@ impl Schema {
         Err(FieldLookupError::NoSuchField(ty_def_name, ty_def))
     }
 
+    /// Returns all explicit field definitions of an object or interface type,
+    /// including fields declared in type extensions.
+    ///
+    /// Fields are in declaration order.
+    /// They are followed by fields of interfaces that the type implements, directly or
+    /// transitively, but does not declare itself (which [`validate`][Self::validate] reports).
+    /// Meta-fields like `__typename` are not included.
+    ///
+    /// Returns an empty map if the type is not defined or is not an object or interface type.
+    ///
+    /// ```
+    /// use apollo_compiler::Schema;
+    ///
+    /// let schema = Schema::parse(
+    ///     r#"
+    ///     type Query { me: User }
+    ///     interface Node { id: ID! }
+    ///     type User implements Node { name: String }
+    ///     extend type User { age: Int }
+    ///     "#,
+    ///     "schema.graphql",
+    /// )
+    /// .unwrap();
+    /// let fields = schema.type_fields("User");
+    /// let names: Vec<_> = fields.keys().map(|name| name.as_str()).collect();
+    /// assert_eq!(names, ["name", "age", "id"]);
+    /// ```
+    pub fn type_fields(&self, type_name: &str) -> IndexMap<Name, &Component<FieldDefinition>> {
+        let mut fields = IndexMap::new();
+        let mut seen = HashSet::new();
+        let mut queue = VecDeque::from([type_name]);
+        while let Some(name) = queue.pop_front() {
+            let (explicit_fields, implements_interfaces) = match self.types.get(name) {
+                Some(ExtendedType::Object(def)) => (&def.fields, &def.implements_interfaces),
+                Some(ExtendedType::Interface(def)) => (&def.fields, &def.implements_interfaces),
+                _ => continue,
+            };
+            for (field_name, field) in explicit_fields {
+                fields.entry(field_name.clone()).or_insert(field);
+            }
+            for implemented in implements_interfaces {
+                // Guard against cycles in invalid schemas
+                if implemented != type_name && seen.insert(implemented.as_str()) {
+                    queue.push_back(implemented);
+                }
+            }
+        }
+        fields
+    }
+
+    /// Returns the declared type of a type’s explicit field or meta-field,
+    /// including list and non-null wrappers.
+    ///
+    /// Returns `None` if the type or field is not defined.
+    /// See [`type_field`][Self::type_field] for the full field definition.
+    pub fn type_of_field(&self, type_name: &str, field_name: &str) -> Option<&Type> {
+        self.type_field(type_name, field_name)
+            .ok()
+            .map(|field| &field.ty)
+    }
+
     /// Returns a map of interface names to names of types that implement that interface
     ///
     /// `Schema` only stores the inverse relationship
@@ -486,23 +701,51 @@ impl Schema {
         map
     }
 
-    /// Returns whether `maybe_subtype` is a subtype of `abstract_type`, which means either:
+    /// Returns the names of types that implement the given interface,
+    /// either directly or through another interface that implements it.
     ///
-    /// * `maybe_subtype` implements the interface `abstract_type`
-    /// * `maybe_subtype` is a member of the union type `abstract_type`
-    pub fn is_subtype(&self, abstract_type: &str, maybe_subtype: &str) -> bool {
-        self.types.get(abstract_type).is_some_and(|ty| match ty {
-            ExtendedType::Interface(_) => self.types.get(maybe_subtype).is_some_and(|ty2| {
-                match ty2 {
-                    ExtendedType::Object(def) => &def.implements_interfaces,
-                    ExtendedType::Interface(def) => &def.implements_interfaces,
+    /// This includes implementations declared in type extensions.
+    /// To look up implementers of many interfaces,
+    /// [`implementers_map`][Self::implementers_map] is more efficient
+    /// but only includes direct implementations.
+    pub fn implementers(&self, interface_name: &str) -> Implementers {
+        let mut implementers = Implementers::default();
+        let mut queue = vec![interface_name];
+        while let Some(interface_name) = queue.pop() {
+            for (ty_name, ty) in &self.types {
+                match ty {
+                    ExtendedType::Object(def) => {
+                        if def.implements_interfaces.contains(interface_name) {
+                            implementers.objects.insert(ty_name.clone());
+                        }
+                    }
+                    ExtendedType::Interface(def) => {
+                        if def.implements_interfaces.contains(interface_name)
+                            && implementers.interfaces.insert(ty_name.clone())
+                        {
+                            queue.push(ty_name.as_str());
+                        }
+                    }
                     ExtendedType::Scalar(_)
                     | ExtendedType::Union(_)
                     | ExtendedType::Enum(_)
-                    | ExtendedType::InputObject(_) => return false,
+                    | ExtendedType::InputObject(_) => (),
                 }
-                .contains(abstract_type)
-            }),
+            }
+        }
+        implementers
+    }
+
+    /// Returns whether `maybe_subtype` is a subtype of `abstract_type`, which means either:
+    ///
+    /// * `maybe_subtype` implements the interface `abstract_type`,
+    ///   directly or through interfaces that implement it
+    /// * `maybe_subtype` is a member of the union type `abstract_type`
+    pub fn is_subtype(&self, abstract_type: &str, maybe_subtype: &str) -> bool {
+        self.types.get(abstract_type).is_some_and(|ty| match ty {
+            ExtendedType::Interface(_) => {
+                self.implements_transitively(maybe_subtype, abstract_type)
+            }
             ExtendedType::Union(def) => def.members.contains(maybe_subtype),
             ExtendedType::Scalar(_)
             | ExtendedType::Object(_)
@@ -511,6 +754,344 @@ impl Schema {
         })
     }
 
+    /// Returns the names of the object types that a value of the abstract type `abstract_type`
+    /// can have at runtime:
+    ///
+    /// * For a union type, its members, in declaration order
+    /// * For an interface type, the object types that implement it,
+    ///   directly or through interfaces that implement it, in [`types`][Self::types] order
+    ///
+    /// Returns an empty list for other types, and for undefined names.
+    ///
+    /// ```
+    /// use apollo_compiler::Schema;
+    ///
+    /// let input = r#"
+    ///     type Query { pet: Pet }
+    ///     interface Named { name: String }
+    ///     interface Pet implements Named { name: String }
+    ///     type Dog implements Pet & Named { name: String }
+    ///     type Cat implements Pet & Named { name: String }
+    ///     union CatOrDog = Cat | Dog
+    /// "#;
+    /// let schema = Schema::parse_and_validate(input, "schema.graphql").unwrap();
+    /// assert_eq!(schema.possible_types("Named"), ["Dog", "Cat"]);
+    /// assert_eq!(schema.possible_types("CatOrDog"), ["Cat", "Dog"]);
+    /// assert!(schema.possible_types("Dog").is_empty());
+    /// ```
+    pub fn possible_types(&self, abstract_type: &str) -> Vec<Name> {
+        match self.types.get(abstract_type) {
+            Some(ExtendedType::Interface(_)) => {
+                let implementers = self.implementers(abstract_type);
+                self.types
+                    .keys()
+                    .filter(|name| implementers.objects.contains(*name))
+                    .cloned()
+                    .collect()
+            }
+            Some(ExtendedType::Union(def)) => def
+                .members
+                .iter()
+                .map(|member| member.name.clone())
+                .collect(),
+            Some(
+                ExtendedType::Scalar(_)
+                | ExtendedType::Object(_)
+                | ExtendedType::Enum(_)
+                | ExtendedType::InputObject(_),
+            )
+            | None => Vec::new(),
+        }
+    }
+
+    fn implements_transitively(&self, type_name: &str, interface: &str) -> bool {
+        let mut seen = HashSet::new();
+        let mut stack = vec![type_name];
+        while let Some(name) = stack.pop() {
+            let implements_interfaces = match self.types.get(name) {
+                Some(ExtendedType::Object(def)) => &def.implements_interfaces,
+                Some(ExtendedType::Interface(def)) => &def.implements_interfaces,
+                _ => continue,
+            };
+            for implemented in implements_interfaces {
+                if implemented == interface {
+                    return true;
+                }
+                // Guard against cycles in invalid schemas
+                if seen.insert(implemented.as_str()) {
+                    stack.push(implemented);
+                }
+            }
+        }
+        false
+    }
+
+    /// Returns whether `name` is the name of a built-in definition in this schema:
+    /// a built-in scalar (`Int`, `Float`, `String`, `Boolean`, `ID`),
+    /// an introspection type (`__Schema`, `__Type`, …),
+    /// or a built-in directive (`@skip`, `@include`, `@deprecated`, `@specifiedBy`),
+    /// as defined by the [October 2021 specification](https://spec.graphql.org/October2021/).
+    ///
+    /// Types and directives are looked up by name, without the `@` sigil for directives.
+    /// A built-in directive that was redefined by the schema is not built-in anymore.
+    /// To check a specific definition, see [`ExtendedType::is_built_in`]
+    /// and [`Node::is_built_in`].
+    ///
+    /// ```
+    /// use apollo_compiler::Schema;
+    ///
+    /// let schema = Schema::parse("type Query { a: Int }", "sdl").unwrap();
+    /// assert!(schema.is_built_in("Int"));
+    /// assert!(schema.is_built_in("__Type"));
+    /// assert!(schema.is_built_in("specifiedBy"));
+    /// assert!(!schema.is_built_in("Query"));
+    /// ```
+    pub fn is_built_in(&self, name: &str) -> bool {
+        self.types.get(name).is_some_and(|def| def.is_built_in())
+            || self
+                .directive_definitions
+                .get(name)
+                .is_some_and(|def| def.is_built_in())
+    }
+
+    /// Returns the source locations of every reference to the named type:
+    /// the name in its definition, followed by its uses in the schema.
+    ///
+    /// Uses are root operation types, interfaces implemented by object or interface types,
+    /// union members, and the types of fields, arguments, input fields,
+    /// and directive arguments, through any list and non-null wrappers.
+    /// Names in type extensions (`extend type …`) are not included,
+    /// as `Schema` only records the location of the extension as a whole.
+    /// Locations may be in different source files,
+    /// see [`NodeLocation::file_id`].
+    ///
+    /// This is useful to implement “rename symbol” in an editor.
+    ///
+    /// ```
+    /// use apollo_compiler::Schema;
+    ///
+    /// let sdl = "type Query { pets: [Pet!]! } type Pet { parent: Pet }";
+    /// let schema = Schema::parse(sdl, "sdl").unwrap();
+    /// let references: Vec<_> = schema
+    ///     .references_to("Pet")
+    ///     .iter()
+    ///     .map(|location| &sdl[location.offset()..location.end_offset()])
+    ///     .collect();
+    /// assert_eq!(references, ["Pet", "Pet", "Pet"]);
+    /// ```
+    pub fn references_to(&self, type_name: &str) -> Vec<NodeLocation> {
+        let mut names = Vec::new();
+        if let Some(def) = self.types.get(type_name) {
+            names.push(def.name());
+        }
+        names.extend(
+            [
+                &self.schema_definition.query,
+                &self.schema_definition.mutation,
+                &self.schema_definition.subscription,
+            ]
+            .into_iter()
+            .flatten()
+            .map(|root| &root.name),
+        );
+        for def in self.types.values() {
+            match def {
+                ExtendedType::Object(def) => {
+                    names.extend(def.implements_interfaces.iter().map(|i| &i.name));
+                    for field in def.fields.values() {
+                        names.push(field.ty.inner_named_type());
+                        names.extend(field.arguments.iter().map(|arg| arg.ty.inner_named_type()));
+                    }
+                }
+                ExtendedType::Interface(def) => {
+                    names.extend(def.implements_interfaces.iter().map(|i| &i.name));
+                    for field in def.fields.values() {
+                        names.push(field.ty.inner_named_type());
+                        names.extend(field.arguments.iter().map(|arg| arg.ty.inner_named_type()));
+                    }
+                }
+                ExtendedType::Union(def) => {
+                    names.extend(def.members.iter().map(|member| &member.name));
+                }
+                ExtendedType::InputObject(def) => {
+                    names.extend(def.fields.values().map(|f| f.ty.inner_named_type()));
+                }
+                ExtendedType::Scalar(_) | ExtendedType::Enum(_) => {}
+            }
+        }
+        for def in self.directive_definitions.values() {
+            names.extend(def.arguments.iter().map(|arg| arg.ty.inner_named_type()));
+        }
+        names
+            .into_iter()
+            .filter(|name| *name == type_name)
+            .filter_map(|name| name.location())
+            .collect()
+    }
+
+    /// Returns the names of the types that can be used starting from the root operations,
+    /// or from the arguments of a directive.
+    ///
+    /// Starting from the root operation types and the argument types of every directive
+    /// definition, this follows the types of fields, arguments and input fields,
+    /// interfaces implemented by object and interface types, union members,
+    /// and the object and interface types implementing an interface.
+    /// Introspection types are reachable from the `query` root,
+    /// through the `__schema` and `__type` fields.
+    ///
+    /// Types that are defined but not in the returned set cannot be used by any operation,
+    /// and can be removed without changing what the schema accepts.
+    ///
+    /// ```
+    /// use apollo_compiler::Schema;
+    ///
+    /// let input = r#"
+    ///     directive @tag(kind: Kind) on FIELD_DEFINITION
+    ///     type Query { pet(filter: PetFilter): Pet }
+    ///     interface Pet { name: String }
+    ///     type Dog implements Pet { name: String }
+    ///     input PetFilter { name: String }
+    ///     enum Kind { A, B }
+    ///     type Unused { a: Int }
+    /// "#;
+    /// let schema = Schema::parse_and_validate(input, "schema.graphql").unwrap();
+    /// let reachable = schema.reachable_types();
+    /// for name in ["Query", "Pet", "Dog", "PetFilter", "Kind", "String", "__Type"] {
+    ///     assert!(reachable.contains(name));
+    /// }
+    /// assert!(!reachable.contains("Unused"));
+    /// assert!(!reachable.contains("Int"));
+    /// ```
+    pub fn reachable_types(&self) -> HashSet<Name> {
+        let mut queue: Vec<&Name> = [
+            &self.schema_definition.query,
+            &self.schema_definition.mutation,
+            &self.schema_definition.subscription,
+        ]
+        .into_iter()
+        .flatten()
+        .map(|root| &root.name)
+        .collect();
+        let introspection = [name!("__Schema"), name!("__Type")];
+        if self.schema_definition.query.is_some() {
+            queue.extend(&introspection);
+        }
+        for def in self.directive_definitions.values() {
+            queue.extend(def.arguments.iter().map(|arg| arg.ty.inner_named_type()));
+        }
+
+        let implementers = self.implementers_map();
+        let mut reachable = HashSet::new();
+        while let Some(name) = queue.pop() {
+            let Some(def) = self.types.get(name) else {
+                continue;
+            };
+            if !reachable.insert(name.clone()) {
+                continue;
+            }
+            match def {
+                ExtendedType::Object(def) => {
+                    queue.extend(def.implements_interfaces.iter().map(|i| &i.name));
+                    for field in def.fields.values() {
+                        queue.push(field.ty.inner_named_type());
+                        queue.extend(field.arguments.iter().map(|arg| arg.ty.inner_named_type()));
+                    }
+                }
+                ExtendedType::Interface(def) => {
+                    queue.extend(def.implements_interfaces.iter().map(|i| &i.name));
+                    for field in def.fields.values() {
+                        queue.push(field.ty.inner_named_type());
+                        queue.extend(field.arguments.iter().map(|arg| arg.ty.inner_named_type()));
+                    }
+                    if let Some(implementers) = implementers.get(name) {
+                        queue.extend(&implementers.objects);
+                        queue.extend(&implementers.interfaces);
+                    }
+                }
+                ExtendedType::Union(def) => {
+                    queue.extend(def.members.iter().map(|member| &member.name));
+                }
+                ExtendedType::InputObject(def) => {
+                    queue.extend(def.fields.values().map(|f| f.ty.inner_named_type()));
+                }
+                ExtendedType::Scalar(_) | ExtendedType::Enum(_) => {}
+            }
+        }
+        reachable
+    }
+
+    /// Returns whether a value of the named type can contain another value of the same type,
+    /// at any depth.
+    ///
+    /// This follows the types of fields of object and interface types, input fields,
+    /// union members, and the object and interface types implementing an interface.
+    /// Unlike [`reachable_types`][Self::reachable_types], it does not follow the types of
+    /// arguments, nor the interfaces implemented by a type, which do not nest values.
+    ///
+    /// Returns false for undefined names.
+    /// This is useful to decide where to stop expanding a type, for example in documentation.
+    ///
+    /// ```
+    /// use apollo_compiler::Schema;
+    ///
+    /// let input = r#"
+    ///     type Query { user: User }
+    ///     type User { name: String, friends: [Friendship!]! }
+    ///     type Friendship { since: String, user: User }
+    ///     input Filter { not: Filter, name: String }
+    /// "#;
+    /// let schema = Schema::parse_and_validate(input, "schema.graphql").unwrap();
+    /// assert!(schema.is_recursive("User"));
+    /// assert!(schema.is_recursive("Friendship"));
+    /// assert!(schema.is_recursive("Filter"));
+    /// assert!(!schema.is_recursive("Query"));
+    /// ```
+    pub fn is_recursive(&self, type_name: &str) -> bool {
+        let implementers = self.implementers_map();
+        let nested_types = |name: &str| -> Vec<&Name> {
+            match self.types.get(name) {
+                Some(ExtendedType::Object(def)) => def
+                    .fields
+                    .values()
+                    .map(|field| field.ty.inner_named_type())
+                    .collect(),
+                Some(ExtendedType::Interface(def)) => {
+                    let mut nested: Vec<&Name> = def
+                        .fields
+                        .values()
+                        .map(|field| field.ty.inner_named_type())
+                        .collect();
+                    if let Some(implementers) = implementers.get(name) {
+                        nested.extend(&implementers.objects);
+                        nested.extend(&implementers.interfaces);
+                    }
+                    nested
+                }
+                Some(ExtendedType::Union(def)) => {
+                    def.members.iter().map(|member| &member.name).collect()
+                }
+                Some(ExtendedType::InputObject(def)) => def
+                    .fields
+                    .values()
+                    .map(|field| field.ty.inner_named_type())
+                    .collect(),
+                Some(ExtendedType::Scalar(_) | ExtendedType::Enum(_)) | None => Vec::new(),
+            }
+        };
+
+        let mut seen = HashSet::new();
+        let mut queue = nested_types(type_name);
+        while let Some(name) = queue.pop() {
+            if name == type_name {
+                return true;
+            }
+            if seen.insert(name) {
+                queue.extend(nested_types(name));
+            }
+        }
+        false
+    }
+
     /// Returns whether the type `ty` is defined as is an input type
     ///
     /// <https://spec.graphql.org/October2021/#sec-Input-and-Output-Types>
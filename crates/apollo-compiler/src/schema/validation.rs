@@ -1,17 +1,26 @@
 use crate::validation::Details;
 use crate::validation::DiagnosticList;
 use crate::validation::FileId;
+use crate::validation::ValidationOptions;
 use crate::InputDatabase;
 use crate::Schema;
 use crate::ValidationDatabase;
 use std::sync::Arc;
 
 pub(crate) fn validate_schema(errors: &mut DiagnosticList, schema: &Schema) {
-    compiler_validation(errors, schema)
+    validate_schema_with(errors, schema, &ValidationOptions::default())
+}
+
+pub(crate) fn validate_schema_with(
+    errors: &mut DiagnosticList,
+    schema: &Schema,
+    options: &ValidationOptions,
+) {
+    compiler_validation(errors, schema, options)
 }
 
 /// TODO: replace this with validation based on `Schema` without a database
-fn compiler_validation(errors: &mut DiagnosticList, schema: &Schema) {
+fn compiler_validation(errors: &mut DiagnosticList, schema: &Schema, options: &ValidationOptions) {
     let mut compiler = crate::ApolloCompiler::new();
     let mut ids = Vec::new();
     for (id, source) in schema.sources.iter() {
@@ -32,6 +41,9 @@ fn compiler_validation(errors: &mut DiagnosticList, schema: &Schema) {
         },
     );
     compiler.db.set_source_files(ids);
+    compiler
+        .db
+        .set_validation_options(Arc::new(options.clone()));
     for diagnostic in compiler.db.validate_type_system() {
         errors.push(diagnostic.location, Details::CompilerDiagnostic(diagnostic))
     }
@@ -3,6 +3,7 @@ use crate::ast::DirectiveLocation;
 use crate::ast::Name;
 use crate::ast::Type;
 use crate::ast::Value;
+use crate::coordinate::DirectiveArgumentCoordinate;
 use crate::coordinate::SchemaCoordinate;
 use crate::coordinate::TypeAttributeCoordinate;
 use crate::diagnostic::CliReport;
@@ -51,6 +52,12 @@ pub(crate) enum DiagnosticData {
         original_definition: Option<NodeLocation>,
         redefined_definition: Option<NodeLocation>,
     },
+    #[error("the argument `{coordinate}` is defined multiple times")]
+    UniqueDirectiveArgumentDefinition {
+        coordinate: DirectiveArgumentCoordinate,
+        original_definition: Option<NodeLocation>,
+        redefined_definition: Option<NodeLocation>,
+    },
     #[error(
         "{} can only have one root field",
         subscription_name_or_anonymous(name)
@@ -93,6 +100,16 @@ pub(crate) enum DiagnosticData {
         definition: Name,
         definition_location: Option<NodeLocation>,
     },
+    #[error("expected a value of enum `{definition}`, found the string \"{value}\"")]
+    EnumAsString {
+        /// Content of the string value
+        value: String,
+        /// Name of the enum
+        definition: Name,
+        /// Whether the string content is a value of the enum
+        is_enum_value: bool,
+        definition_location: Option<NodeLocation>,
+    },
     #[error("field `{value}` does not exist on `{definition}`")]
     UndefinedInputValue {
         /// Value of the input object field that doesn't exist
@@ -111,6 +128,55 @@ pub(crate) enum DiagnosticData {
         /// Location of the definition of the field in the interface
         field_location: Option<NodeLocation>,
     },
+    #[error(
+        "type `{name}` does not satisfy interface `{interface}`: field `{field}` has type `{ty}`, which is not compatible with `{interface_ty}`"
+    )]
+    InvalidFieldImplementation {
+        name: Name,
+        interface: Name,
+        field: Name,
+        /// Type of the field in the implementing type
+        ty: Type,
+        /// Type of the field in the interface
+        interface_ty: Type,
+        /// Location of the definition of the field in the interface
+        interface_field_location: Option<NodeLocation>,
+    },
+    #[error(
+        "type `{name}` does not satisfy interface `{interface}`: missing argument `{field}({argument}:)`"
+    )]
+    MissingInterfaceFieldArgument {
+        name: Name,
+        interface: Name,
+        field: Name,
+        argument: Name,
+        /// Location of the definition of the argument in the interface
+        argument_location: Option<NodeLocation>,
+    },
+    #[error(
+        "type `{name}` does not satisfy interface `{interface}`: argument `{field}({argument}:)` has type `{ty}`, but the interface requires `{interface_ty}`"
+    )]
+    InvalidFieldArgumentImplementation {
+        name: Name,
+        interface: Name,
+        field: Name,
+        argument: Name,
+        /// Type of the argument in the implementing type
+        ty: Node<Type>,
+        /// Type of the argument in the interface
+        interface_ty: Node<Type>,
+    },
+    #[error(
+        "type `{name}` does not satisfy interface `{interface}`: additional argument `{field}({argument}:)` must not be required"
+    )]
+    RequiredArgumentNotInInterface {
+        name: Name,
+        interface: Name,
+        field: Name,
+        argument: Name,
+        /// Location of the definition of the field in the interface
+        field_location: Option<NodeLocation>,
+    },
     #[error("the required argument `{coordinate}` is not provided")]
     RequiredArgument {
         name: Name,
@@ -123,6 +189,20 @@ pub(crate) enum DiagnosticData {
         coordinate: TypeAttributeCoordinate,
         definition_location: Option<NodeLocation>,
     },
+    #[error("field `{field}` of `@oneOf` input object `{name}` {reason}")]
+    InvalidOneOfDefinition {
+        /// Name of the input object type
+        name: Name,
+        /// Name of the invalid field
+        field: Name,
+        reason: &'static str,
+    },
+    #[error("exactly one non-null field must be provided for `@oneOf` input object `{name}`")]
+    InvalidOneOfValue {
+        /// Name of the input object type
+        name: Name,
+        definition_location: Option<NodeLocation>,
+    },
     #[error(
         "interface `{interface}` declares that it implements `{via_interface}`, but to do so it must also implement `{missing_interface}`"
     )]
@@ -152,6 +232,13 @@ pub(crate) enum DiagnosticData {
         describe_type: &'static str,
         type_location: Option<NodeLocation>,
     },
+    #[error("the argument `{coordinate}` must be of an input type")]
+    DirectiveArgumentInputType {
+        coordinate: DirectiveArgumentCoordinate,
+        /// The kind of type that the argument is declared with.
+        describe_type: &'static str,
+        type_location: Option<NodeLocation>,
+    },
     #[error("`${name}` variable must be of an input type")]
     VariableInputType {
         /// Variable name.
@@ -162,6 +249,8 @@ pub(crate) enum DiagnosticData {
     },
     #[error("missing query root operation type in schema definition")]
     QueryRootOperationType,
+    #[error("missing query root operation type: no `schema` definition and no `Query` type")]
+    MissingQueryRoot,
     #[error("unused variable: `${name}`")]
     UnusedVariable { name: Name },
     #[error("`{name}` field must return an object type")]
@@ -178,6 +267,23 @@ pub(crate) enum DiagnosticData {
         /// Category of the type
         describe_type: &'static str,
     },
+    #[error("union type `{name}` must have at least one member type")]
+    EmptyUnion {
+        /// Name of the union type
+        name: Name,
+    },
+    #[error("{describe_type} `{name}` must define one or more fields")]
+    EmptyFieldSet {
+        /// Name of the object, interface, or input object type
+        name: Name,
+        /// Category of the type
+        describe_type: &'static str,
+    },
+    #[error("enum type `{name}` must define one or more values")]
+    EmptyEnum {
+        /// Name of the enum type
+        name: Name,
+    },
     #[error("{name} directive is not supported for {location} location")]
     UnsupportedLocation {
         /// Name of the directive
@@ -197,6 +303,24 @@ pub(crate) enum DiagnosticData {
         ty: String,
         definition_location: Option<NodeLocation>,
     },
+    #[error("the default value of argument `{name}` is not a valid value of type {ty}")]
+    InvalidArgumentDefault {
+        /// Name of the argument
+        name: Name,
+        /// Declared type of the argument
+        ty: String,
+        /// Why the default value cannot be coerced to the argument type
+        reason: String,
+        type_location: Option<NodeLocation>,
+    },
+    #[error("{reason}")]
+    InvalidDefaultValue {
+        /// Why the default value is invalid, including the declared type
+        reason: String,
+        /// Declared type of the variable or input value
+        ty: String,
+        type_location: Option<NodeLocation>,
+    },
     #[error("int cannot represent non 32-bit signed integer value")]
     IntCoercionError {
         /// The int value that cannot be coerced
@@ -301,6 +425,15 @@ pub(crate) enum DiagnosticData {
         argument_type: Type,
         argument_location: Option<NodeLocation>,
     },
+    #[error("variable `${variable}` of type `{variable_type}` cannot be used for a value of type `{expected_type}`")]
+    VariableTypeMismatch {
+        /// Name of the variable
+        variable: Name,
+        variable_type: Type,
+        variable_location: Option<NodeLocation>,
+        /// Type of the list entry or input object field where the variable is used
+        expected_type: Type,
+    },
     #[error("`{name}` directive definition cannot reference itself")]
     RecursiveDirectiveDefinition {
         name: Name,
@@ -308,6 +441,12 @@ pub(crate) enum DiagnosticData {
     },
     #[error("interface {name} cannot implement itself")]
     RecursiveInterfaceDefinition { name: Name },
+    #[error("interface {name} cannot implement itself through other interfaces")]
+    CyclicInterfaceImplementation {
+        name: Name,
+        /// Path of `implements` references from the offending declaration back to `name`.
+        trace: Vec<Name>,
+    },
     #[error("`{name}` input object cannot reference itself")]
     RecursiveInputObjectDefinition {
         name: Name,
@@ -320,6 +459,15 @@ pub(crate) enum DiagnosticData {
         name: Name,
         trace: Vec<Node<ast::FragmentSpread>>,
     },
+    #[error("type `{ty}` has {depth} nested lists, more than the limit of {limit}")]
+    ExcessiveTypeNesting {
+        /// The offending type reference
+        ty: Type,
+        /// Number of nested list wrappers in `ty`
+        depth: usize,
+        /// Configured maximum number of nested list wrappers
+        limit: usize,
+    },
     #[error("`{name}` contains too much nesting")]
     DeeplyNestedType {
         name: Name,
@@ -329,6 +477,74 @@ pub(crate) enum DiagnosticData {
     RecursionError {},
 }
 
+impl DiagnosticData {
+    /// Stable code for [`DiagnosticData::code`][crate::validation::DiagnosticData::code]
+    pub(crate) fn code(&self) -> &'static str {
+        match self {
+            Self::UniqueVariable { .. } => "UniqueVariable",
+            Self::UniqueArgument { .. } => "UniqueArgument",
+            Self::UniqueInputValue { .. } => "UniqueInputValue",
+            Self::UniqueDirectiveArgumentDefinition { .. } => "UniqueDirectiveArgumentDefinition",
+            Self::SingleRootField { .. } => "SingleRootField",
+            Self::UndefinedArgument { .. } => "UndefinedArgument",
+            Self::UndefinedDefinition { .. } => "UndefinedDefinition",
+            Self::UndefinedDirective { .. } => "UndefinedDirective",
+            Self::UndefinedVariable { .. } => "UndefinedVariable",
+            Self::UndefinedFragment { .. } => "UndefinedFragment",
+            Self::UndefinedEnumValue { .. } => "UndefinedEnumValue",
+            Self::EnumAsString { .. } => "EnumAsString",
+            Self::UndefinedInputValue { .. } => "UndefinedInputValue",
+            Self::MissingInterfaceField { .. } => "MissingInterfaceField",
+            Self::InvalidFieldImplementation { .. } => "InvalidFieldImplementation",
+            Self::MissingInterfaceFieldArgument { .. } => "MissingInterfaceFieldArgument",
+            Self::InvalidFieldArgumentImplementation { .. } => "InvalidFieldArgumentImplementation",
+            Self::RequiredArgumentNotInInterface { .. } => "RequiredArgumentNotInInterface",
+            Self::RequiredArgument { .. } => "RequiredArgument",
+            Self::RequiredField { .. } => "RequiredField",
+            Self::InvalidOneOfDefinition { .. } => "InvalidOneOfDefinition",
+            Self::InvalidOneOfValue { .. } => "InvalidOneOfValue",
+            Self::TransitiveImplementedInterfaces { .. } => "TransitiveImplementedInterfaces",
+            Self::OutputType { .. } => "OutputType",
+            Self::InputType { .. } => "InputType",
+            Self::DirectiveArgumentInputType { .. } => "DirectiveArgumentInputType",
+            Self::VariableInputType { .. } => "VariableInputType",
+            Self::QueryRootOperationType => "QueryRootOperationType",
+            Self::MissingQueryRoot => "MissingQueryRoot",
+            Self::UnusedVariable { .. } => "UnusedVariable",
+            Self::RootOperationObjectType { .. } => "RootOperationObjectType",
+            Self::UnionMemberObjectType { .. } => "UnionMemberObjectType",
+            Self::EmptyUnion { .. } => "EmptyUnion",
+            Self::EmptyFieldSet { .. } => "EmptyFieldSet",
+            Self::EmptyEnum { .. } => "EmptyEnum",
+            Self::UnsupportedLocation { .. } => "UnsupportedLocation",
+            Self::UnsupportedValueType { .. } => "UnsupportedValueType",
+            Self::InvalidArgumentDefault { .. } => "InvalidArgumentDefault",
+            Self::InvalidDefaultValue { .. } => "InvalidDefaultValue",
+            Self::IntCoercionError { .. } => "IntCoercionError",
+            Self::FloatCoercionError { .. } => "FloatCoercionError",
+            Self::UniqueDirective { .. } => "UniqueDirective",
+            Self::IntrospectionField { .. } => "IntrospectionField",
+            Self::MissingSubselection { .. } => "MissingSubselection",
+            Self::ConflictingFieldType { .. } => "ConflictingFieldType",
+            Self::ConflictingFieldArgument { .. } => "ConflictingFieldArgument",
+            Self::ConflictingFieldName { .. } => "ConflictingFieldName",
+            Self::InvalidFragmentTarget { .. } => "InvalidFragmentTarget",
+            Self::InvalidFragmentSpread { .. } => "InvalidFragmentSpread",
+            Self::UnusedFragment { .. } => "UnusedFragment",
+            Self::DisallowedVariableUsage { .. } => "DisallowedVariableUsage",
+            Self::VariableTypeMismatch { .. } => "VariableTypeMismatch",
+            Self::RecursiveDirectiveDefinition { .. } => "RecursiveDirectiveDefinition",
+            Self::RecursiveInterfaceDefinition { .. } => "RecursiveInterfaceDefinition",
+            Self::CyclicInterfaceImplementation { .. } => "CyclicInterfaceImplementation",
+            Self::RecursiveInputObjectDefinition { .. } => "RecursiveInputObjectDefinition",
+            Self::RecursiveFragmentDefinition { .. } => "RecursiveFragmentDefinition",
+            Self::ExcessiveTypeNesting { .. } => "ExcessiveTypeNesting",
+            Self::DeeplyNestedType { .. } => "DeeplyNestedType",
+            Self::RecursionError { .. } => "RecursionError",
+        }
+    }
+}
+
 impl ValidationError {
     pub(crate) fn report(&self, report: &mut CliReport) {
         match &self.data {
@@ -380,6 +596,25 @@ impl ValidationError {
                     "`{name}` must only be defined once in this argument list or input object definition."
                 ));
             }
+            DiagnosticData::UniqueDirectiveArgumentDefinition {
+                coordinate,
+                original_definition,
+                redefined_definition,
+            } => {
+                let name = &coordinate.argument;
+                report.with_label_opt(
+                    *original_definition,
+                    format_args!("previous definition of `{name}` here"),
+                );
+                report.with_label_opt(
+                    *redefined_definition,
+                    format_args!("`{name}` defined again here"),
+                );
+                report.with_help(format_args!(
+                    "`{name}` must only be defined once in the arguments of `@{}`.",
+                    coordinate.directive
+                ));
+            }
             DiagnosticData::SingleRootField { fields, .. } => {
                 report.with_label_opt(
                     self.location,
@@ -424,6 +659,16 @@ impl ValidationError {
                 );
                 report.with_label_opt(*definition_location, "field defined here");
             }
+            DiagnosticData::InvalidOneOfDefinition { reason, .. } => {
+                report.with_label_opt(self.location, format_args!("this field {reason}"));
+            }
+            DiagnosticData::InvalidOneOfValue {
+                name: _,
+                definition_location,
+            } => {
+                report.with_label_opt(self.location, "provide exactly one non-null field");
+                report.with_label_opt(*definition_location, "input object defined here");
+            }
             DiagnosticData::UndefinedDefinition { .. } => {
                 report.with_label_opt(self.location, "not found in this scope");
             }
@@ -439,6 +684,22 @@ impl ValidationError {
                     format_args!("fragment `{name}` is not defined"),
                 );
             }
+            DiagnosticData::EnumAsString {
+                value,
+                definition,
+                is_enum_value,
+                definition_location,
+            } => {
+                report.with_label_opt(self.location, "enum values are not strings");
+                report.with_label_opt(*definition_location, "enum defined here");
+                if *is_enum_value {
+                    report.with_help(format_args!("remove the quotes: `{value}`"));
+                } else {
+                    report.with_help(format_args!(
+                        "`{value}` is not a value of `{definition}` either"
+                    ));
+                }
+            }
             DiagnosticData::UndefinedEnumValue {
                 value: _,
                 definition,
@@ -471,6 +732,23 @@ impl ValidationError {
                     format_args!("interface {name} cannot implement itself"),
                 );
             }
+            DiagnosticData::CyclicInterfaceImplementation { name, trace } => {
+                report.with_label_opt(self.location, "cyclical interface implementation");
+                if let Some((cyclical_reference, path)) = trace.split_first() {
+                    let mut prev_name = name;
+                    for reference in path.iter().rev() {
+                        report.with_label_opt(
+                            reference.location(),
+                            format!("`{prev_name}` implements `{reference}` here..."),
+                        );
+                        prev_name = reference;
+                    }
+                    report.with_label_opt(
+                        cyclical_reference.location(),
+                        format!("`{prev_name}` circularly implements `{name}` here"),
+                    );
+                }
+            }
             DiagnosticData::RecursiveInputObjectDefinition { name, trace } => {
                 report.with_label_opt(self.location, "cyclical input object definition");
                 label_recursive_trace(report, trace, name, |reference| &reference.name);
@@ -486,6 +764,12 @@ impl ValidationError {
                 );
                 label_recursive_trace(report, trace, name, |reference| &reference.fragment_name);
             }
+            DiagnosticData::ExcessiveTypeNesting { depth, .. } => {
+                report.with_label_opt(
+                    self.location,
+                    format_args!("this type is nested {depth} lists deep"),
+                );
+            }
             DiagnosticData::DeeplyNestedType { describe_type, .. } => {
                 report.with_label_opt(
                     self.location,
@@ -517,6 +801,68 @@ impl ValidationError {
                     "An object or interface must declare all fields required by the interfaces it implements",
                 )
             }
+            DiagnosticData::InvalidFieldImplementation {
+                interface,
+                field,
+                ty,
+                interface_ty,
+                interface_field_location,
+                ..
+            } => {
+                report.with_label_opt(self.location, format_args!("`{field}` declared as {ty}"));
+                report.with_label_opt(
+                    *interface_field_location,
+                    format_args!("`{interface}.{field}` declared as {interface_ty}"),
+                );
+                report.with_help(
+                    "A field's type must be the same as, or a subtype of, the type of the interface field it implements",
+                )
+            }
+            DiagnosticData::MissingInterfaceFieldArgument {
+                interface,
+                field,
+                argument,
+                argument_location,
+                ..
+            } => {
+                report.with_label_opt(
+                    self.location,
+                    format_args!("add `{argument}` argument to this field"),
+                );
+                report.with_label_opt(
+                    *argument_location,
+                    format_args!("`{interface}.{field}({argument}:)` originally defined here"),
+                );
+            }
+            DiagnosticData::InvalidFieldArgumentImplementation {
+                interface,
+                field,
+                argument,
+                ty,
+                interface_ty,
+                ..
+            } => {
+                report.with_label_opt(ty.location(), format_args!("`{argument}` declared as {ty}"));
+                report.with_label_opt(
+                    interface_ty.location(),
+                    format_args!("`{interface}.{field}({argument}:)` declared as {interface_ty}"),
+                );
+                report.with_help("Arguments must have the same type as in the interface")
+            }
+            DiagnosticData::RequiredArgumentNotInInterface {
+                interface,
+                field,
+                argument,
+                field_location,
+                ..
+            } => {
+                report.with_label_opt(self.location, format_args!("`{argument}` is required here"));
+                report.with_label_opt(
+                    *field_location,
+                    format_args!("`{interface}.{field}` has no argument `{argument}`"),
+                );
+                report.with_help("Make the argument nullable, or give it a default value")
+            }
             DiagnosticData::TransitiveImplementedInterfaces {
                 interface: _,
                 via_interface,
@@ -557,6 +903,22 @@ impl ValidationError {
                 report.with_label_opt(self.location, format_args!("this is {describe_type}"));
                 report.with_help("Union members must be object types.");
             }
+            DiagnosticData::EmptyUnion { name } => {
+                report.with_label_opt(self.location, format_args!("`{name}` has no members"));
+                report.with_help(format_args!(
+                    "Add one or more object types to `{name}`, for example: `union {name} = SomeObject`"
+                ));
+            }
+            DiagnosticData::EmptyFieldSet { name, .. } => {
+                report.with_label_opt(self.location, format_args!("`{name}` has no fields"));
+                report.with_help(format_args!("Add one or more fields to `{name}`"));
+            }
+            DiagnosticData::EmptyEnum { name } => {
+                report.with_label_opt(self.location, format_args!("`{name}` has no values"));
+                report.with_help(format_args!(
+                    "Add one or more values to `{name}`, for example: `enum {name} {{ SOME_VALUE }}`"
+                ));
+            }
             DiagnosticData::OutputType {
                 name,
                 describe_type,
@@ -579,6 +941,17 @@ impl ValidationError {
                 );
                 report.with_help(format!("Scalars, Enums, and Input Objects are input types. Change `{name}` field to take one of these input types."));
             }
+            DiagnosticData::DirectiveArgumentInputType {
+                coordinate,
+                describe_type,
+                type_location,
+            } => {
+                report.with_label_opt(
+                    type_location.or(self.location),
+                    format_args!("this is {describe_type}"),
+                );
+                report.with_help(format!("Scalars, Enums, and Input Objects are input types. Change `{coordinate}` to take one of these input types."));
+            }
             DiagnosticData::VariableInputType {
                 name: _,
                 describe_type,
@@ -596,6 +969,12 @@ impl ValidationError {
                     "`query` root operation type must be defined here",
                 );
             }
+            DiagnosticData::MissingQueryRoot => {
+                report.with_label_opt(self.location, "operations cannot be rooted in this schema");
+                report.with_help(
+                    "define a `Query` object type, or a `schema` definition with a `query` root operation",
+                );
+            }
             DiagnosticData::UnsupportedLocation {
                 name: _,
                 location,
@@ -612,6 +991,26 @@ impl ValidationError {
                     CommaSeparated(valid_locations),
                 ));
             }
+            DiagnosticData::InvalidArgumentDefault {
+                name: _,
+                ty,
+                reason,
+                type_location,
+            } => {
+                report.with_label_opt(self.location, reason);
+                report.with_label_opt(
+                    *type_location,
+                    format_args!("argument type declared here as {ty}"),
+                );
+            }
+            DiagnosticData::InvalidDefaultValue {
+                reason: _,
+                ty,
+                type_location,
+            } => {
+                report.with_label_opt(self.location, "invalid default value");
+                report.with_label_opt(*type_location, format_args!("type declared here as {ty}"));
+            }
             DiagnosticData::UnsupportedValueType {
                 describe_value_type,
                 ty,
@@ -784,6 +1183,23 @@ impl ValidationError {
                     format_args!("variable `${variable}` used here"),
                 );
             }
+            DiagnosticData::VariableTypeMismatch {
+                variable,
+                variable_type,
+                variable_location,
+                expected_type,
+            } => {
+                report.with_label_opt(
+                    *variable_location,
+                    format_args!(
+                        "variable `${variable}` of type `{variable_type}` is declared here"
+                    ),
+                );
+                report.with_label_opt(
+                    self.location,
+                    format_args!("expected a value of type `{expected_type}`"),
+                );
+            }
             DiagnosticData::RecursionError {} => {}
         }
     }
@@ -142,6 +142,7 @@ pub(crate) fn validate_directive_definition(
         db,
         &def.arguments,
         ast::DirectiveLocation::ArgumentDefinition,
+        Some(&def.name),
     ));
 
     let head_location = NodeLocation::recompose(def.location(), def.name.location());
@@ -276,7 +277,7 @@ pub(crate) fn validate_directives<'dir>(
                                 directive: dir.name.clone(),
                             }
                             .into(),
-                            definition_location: loc,
+                            definition_location: directive_definition.location(),
                         },
                     ));
                 }
@@ -309,7 +310,7 @@ pub(crate) fn validate_directives<'dir>(
                     ));
                 }
             }
-        } else {
+        } else if !db.validation_options().builtin_directives.contains(name) {
             diagnostics.push(ValidationError::new(
                 loc,
                 DiagnosticData::UndefinedDirective { name: name.clone() },
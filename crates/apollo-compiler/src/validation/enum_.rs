@@ -1,4 +1,4 @@
-use crate::validation::diagnostics::ValidationError;
+use crate::validation::diagnostics::{DiagnosticData, ValidationError};
 use crate::{ast, Node, ValidationDatabase};
 
 pub(crate) fn validate_enum_definitions(db: &dyn ValidationDatabase) -> Vec<ValidationError> {
@@ -23,6 +23,18 @@ pub(crate) fn validate_enum_definition(
         Default::default(),
     );
 
+    // An Enum type must define one or more unique enum values.
+    //
+    // Uniqueness of values is checked when building the schema.
+    if enum_def.values().next().is_none() {
+        diagnostics.push(ValidationError::new(
+            enum_def.definition.name.location(),
+            DiagnosticData::EmptyEnum {
+                name: enum_def.definition.name.clone(),
+            },
+        ));
+    }
+
     for enum_val in enum_def.values() {
         diagnostics.extend(validate_enum_value(db, enum_val));
     }
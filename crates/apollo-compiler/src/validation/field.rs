@@ -104,7 +104,7 @@ pub(crate) fn validate_field(
             }
         }
 
-        match validate_leaf_field_selection(db, field.clone(), &field_definition.ty) {
+        match validate_leaf_field_selection(db, against_type, field.clone(), &field_definition.ty) {
             Err(diag) => diagnostics.push(diag),
             Ok(_) => diagnostics.extend(super::selection::validate_selection_set(
                 db,
@@ -135,6 +135,7 @@ pub(crate) fn validate_field_definition(
         db,
         &field.arguments,
         ast::DirectiveLocation::ArgumentDefinition,
+        None,
     ));
 
     diagnostics
@@ -151,6 +152,13 @@ pub(crate) fn validate_field_definitions(
     for field in &fields {
         diagnostics.extend(validate_field_definition(db, field));
 
+        // `FieldDefinition::ty` has no location of its own
+        diagnostics.extend(super::validate_type_nesting(
+            db,
+            &field.ty,
+            field.location(),
+        ));
+
         // Field types in Object Types must be of output type
         let loc = field.location();
         let type_location = field.ty.inner_named_type().location();
@@ -181,6 +189,7 @@ pub(crate) fn validate_field_definitions(
 
 pub(crate) fn validate_leaf_field_selection(
     db: &dyn ValidationDatabase,
+    against_type: &ast::NamedType,
     field: Node<ast::Field>,
     field_type: &ast::Type,
 ) -> Result<(), ValidationError> {
@@ -208,7 +217,7 @@ pub(crate) fn validate_leaf_field_selection(
             field.location(),
             DiagnosticData::MissingSubselection {
                 coordinate: TypeAttributeCoordinate {
-                    ty: tname.clone(),
+                    ty: against_type.clone(),
                     attribute: fname.clone(),
                 },
                 describe_type: type_def.describe(),
@@ -366,7 +366,8 @@ pub(crate) fn validate_fragment_type_condition(
 
     if !is_composite {
         diagnostics.push(ValidationError::new(
-            fragment_location,
+            // Point at the `on Type` clause rather than the whole fragment
+            type_cond.location().or(fragment_location),
             DiagnosticData::InvalidFragmentTarget {
                 name: fragment_name,
                 ty: type_cond.clone(),
@@ -1,4 +1,5 @@
 use crate::ast;
+use crate::coordinate::DirectiveArgumentCoordinate;
 use crate::validation::diagnostics::{DiagnosticData, ValidationError};
 use crate::validation::{CycleError, RecursionGuard, RecursionStack};
 use crate::Node;
@@ -60,6 +61,12 @@ impl FindRecursiveInputValue<'_> {
     }
 }
 
+/// `@oneOf` is not a built-in directive of the October 2021 specification,
+/// so it is only validated if the schema defines it.
+fn is_one_of_defined(db: &dyn ValidationDatabase) -> bool {
+    db.schema().directive_definitions.contains_key("oneOf")
+}
+
 pub(crate) fn validate_input_object_definitions(
     db: &dyn ValidationDatabase,
 ) -> Vec<ValidationError> {
@@ -104,6 +111,17 @@ pub(crate) fn validate_input_object_definition(
         }
     }
 
+    // An Input Object type must define one or more input fields.
+    if input_object.fields().next().is_none() {
+        diagnostics.push(ValidationError::new(
+            input_object.definition.name.location(),
+            DiagnosticData::EmptyFieldSet {
+                name: input_object.definition.name.clone(),
+                describe_type: "input object type",
+            },
+        ));
+    }
+
     // Fields in an Input Object Definition must be unique
     //
     // Returns Unique Definition error.
@@ -112,17 +130,50 @@ pub(crate) fn validate_input_object_definition(
         db,
         &fields,
         ast::DirectiveLocation::InputFieldDefinition,
+        None,
     ));
 
+    // All fields of a `@oneOf` input object must be nullable and must not have
+    // a default value.
+    if is_one_of_defined(db)
+        && input_object
+            .directives()
+            .any(|directive| directive.name == "oneOf")
+    {
+        for field in &fields {
+            let reason = if field.ty.is_non_null() {
+                "must be nullable"
+            } else if field.default_value.is_some() {
+                "must not have a default value"
+            } else {
+                continue;
+            };
+            diagnostics.push(ValidationError::new(
+                field.location(),
+                DiagnosticData::InvalidOneOfDefinition {
+                    name: input_object.definition.name.clone(),
+                    field: field.name.clone(),
+                    reason,
+                },
+            ));
+        }
+    }
+
     diagnostics
 }
 
+/// Validate the arguments of a field or directive definition.
+///
+/// `directive` is the name of the directive being defined, if any, to produce
+/// diagnostics that refer to `@directive(argument:)`.
 pub(crate) fn validate_argument_definitions(
     db: &dyn ValidationDatabase,
     input_values: &[Node<ast::InputValueDefinition>],
     directive_location: ast::DirectiveLocation,
+    directive: Option<&ast::Name>,
 ) -> Vec<ValidationError> {
-    let mut diagnostics = validate_input_value_definitions(db, input_values, directive_location);
+    let mut diagnostics =
+        validate_input_value_definitions(db, input_values, directive_location, directive);
 
     let mut seen: HashMap<ast::Name, &Node<ast::InputValueDefinition>> = HashMap::new();
     for input_value in input_values {
@@ -131,14 +182,23 @@ pub(crate) fn validate_argument_definitions(
             let (original_definition, redefined_definition) =
                 (prev_value.location(), input_value.location());
 
-            diagnostics.push(ValidationError::new(
-                original_definition,
+            let data = if let Some(directive) = directive {
+                DiagnosticData::UniqueDirectiveArgumentDefinition {
+                    coordinate: DirectiveArgumentCoordinate {
+                        directive: directive.clone(),
+                        argument: name.clone(),
+                    },
+                    original_definition,
+                    redefined_definition,
+                }
+            } else {
                 DiagnosticData::UniqueInputValue {
                     name: name.clone(),
                     original_definition,
                     redefined_definition,
-                },
-            ));
+                }
+            };
+            diagnostics.push(ValidationError::new(original_definition, data));
         } else {
             seen.insert(name.clone(), input_value);
         }
@@ -151,6 +211,7 @@ pub(crate) fn validate_input_value_definitions(
     db: &dyn ValidationDatabase,
     input_values: &[Node<ast::InputValueDefinition>],
     directive_location: ast::DirectiveLocation,
+    directive: Option<&ast::Name>,
 ) -> Vec<ValidationError> {
     let schema = db.schema();
 
@@ -163,18 +224,69 @@ pub(crate) fn validate_input_value_definitions(
             directive_location,
             Default::default(), // No variables in an input value definition
         ));
+        if let Some(default_value) = &input_value.default_value {
+            super::value::validate_default_value_variables(
+                &input_value.ty,
+                default_value,
+                &mut diagnostics,
+            );
+            if directive_location == ast::DirectiveLocation::ArgumentDefinition {
+                // The default value of an argument must be coercible to the argument type.
+                let mut value_diagnostics = Vec::new();
+                super::value::value_of_correct_type(
+                    &schema,
+                    &input_value.ty,
+                    default_value,
+                    // Default values cannot reference variables
+                    &[],
+                    &mut value_diagnostics,
+                );
+                if let Some(error) = value_diagnostics.first() {
+                    diagnostics.push(ValidationError::new(
+                        error.location,
+                        DiagnosticData::InvalidArgumentDefault {
+                            name: input_value.name.clone(),
+                            ty: input_value.ty.to_string(),
+                            reason: error.data.to_string(),
+                            type_location: input_value.ty.location(),
+                        },
+                    ));
+                }
+            } else if let Some(error) =
+                super::value::validate_non_null_default_value(&input_value.ty, default_value)
+            {
+                diagnostics.push(error);
+            }
+        }
+        diagnostics.extend(super::validate_type_nesting(
+            db,
+            &input_value.ty,
+            input_value.ty.location(),
+        ));
+
         // Input values must only contain input types.
         let loc = input_value.location();
         if let Some(field_ty) = schema.types.get(input_value.ty.inner_named_type()) {
             if !field_ty.is_input_type() {
-                diagnostics.push(ValidationError::new(
-                    loc,
+                let describe_type = field_ty.describe();
+                let type_location = input_value.ty.location();
+                let data = if let Some(directive) = directive {
+                    DiagnosticData::DirectiveArgumentInputType {
+                        coordinate: DirectiveArgumentCoordinate {
+                            directive: directive.clone(),
+                            argument: input_value.name.clone(),
+                        },
+                        describe_type,
+                        type_location,
+                    }
+                } else {
                     DiagnosticData::InputType {
                         name: input_value.name.clone(),
-                        describe_type: field_ty.describe(),
-                        type_location: input_value.ty.location(),
-                    },
-                ));
+                        describe_type,
+                        type_location,
+                    }
+                };
+                diagnostics.push(ValidationError::new(loc, data));
             }
         } else {
             let named_type = input_value.ty.inner_named_type();
@@ -1,10 +1,74 @@
+use crate::validation::{RecursionGuard, RecursionLimitError, RecursionStack};
+use crate::Schema;
 use crate::{
     ast, schema,
     validation::diagnostics::{DiagnosticData, ValidationError},
-    ValidationDatabase,
+    Node, ValidationDatabase,
 };
 use std::collections::HashSet;
 
+/// Finds cycles of interfaces implementing each other, like:
+///
+/// ```graphql
+/// interface Node implements Named { id: ID! }
+/// interface Named implements Node { id: ID! }
+/// ```
+///
+/// An interface directly implementing itself is reported separately.
+struct FindCyclicImplementation<'a> {
+    schema: &'a Schema,
+}
+
+impl FindCyclicImplementation<'_> {
+    /// Returns the path from the offending `implements` reference back to the root interface
+    /// if a cycle was found.
+    fn implements_interface(
+        &self,
+        seen: &mut RecursionGuard<'_>,
+        name: &ast::Name,
+    ) -> Result<Option<Vec<ast::Name>>, RecursionLimitError> {
+        if seen.first() == Some(name) {
+            return Ok(Some(vec![name.clone()]));
+        }
+        if seen.contains(name) {
+            return Ok(None);
+        }
+        let Some(interface) = self.schema.get_interface(name) else {
+            return Ok(None);
+        };
+        let mut seen = seen.push(name)?;
+        for super_interface in &interface.implements_interfaces {
+            if super_interface.name == *name {
+                continue;
+            }
+            if let Some(mut trace) = self.implements_interface(&mut seen, &super_interface.name)? {
+                trace.push(name.clone());
+                return Ok(Some(trace));
+            }
+        }
+        Ok(None)
+    }
+
+    fn check(
+        schema: &Schema,
+        interface: &ast::TypeWithExtensions<ast::InterfaceTypeDefinition>,
+    ) -> Result<Option<Vec<ast::Name>>, RecursionLimitError> {
+        let name = &interface.definition.name;
+        let mut recursion_stack = RecursionStack::with_root(name.clone());
+        let mut seen = recursion_stack.guard();
+        let finder = FindCyclicImplementation { schema };
+        for implements_interface in interface.implements_interfaces() {
+            if implements_interface == name {
+                continue;
+            }
+            if let Some(trace) = finder.implements_interface(&mut seen, implements_interface)? {
+                return Ok(Some(trace));
+            }
+        }
+        Ok(None)
+    }
+}
+
 pub(crate) fn validate_interface_definitions(db: &dyn ValidationDatabase) -> Vec<ValidationError> {
     let mut diagnostics = Vec::new();
 
@@ -34,18 +98,6 @@ pub(crate) fn validate_interface_definition(
     // Interface must not implement itself.
     //
     // Return Recursive Definition error.
-    //
-    // NOTE(@lrlna): we should also check for more sneaky cyclic references for interfaces like this, for example:
-    //
-    // interface Node implements Named & Node {
-    //   id: ID!
-    //   name: String
-    // }
-    //
-    // interface Named implements Node & Named {
-    //   id: ID!
-    //   name: String
-    // }
     for implements_interface in interface.implements_interfaces() {
         if *implements_interface == interface.definition.name {
             diagnostics.push(ValidationError::new(
@@ -57,6 +109,38 @@ pub(crate) fn validate_interface_definition(
         }
     }
 
+    // Interface must not implement itself through other interfaces.
+    //
+    // Returns Cyclic Interface Implementation error.
+    match FindCyclicImplementation::check(&schema, &interface) {
+        Ok(None) => {}
+        Ok(Some(trace)) => diagnostics.push(ValidationError::new(
+            interface.definition.location(),
+            DiagnosticData::CyclicInterfaceImplementation {
+                name: interface.definition.name.clone(),
+                trace,
+            },
+        )),
+        Err(RecursionLimitError {}) => diagnostics.push(ValidationError::new(
+            interface.definition.location(),
+            DiagnosticData::DeeplyNestedType {
+                name: interface.definition.name.clone(),
+                describe_type: "interface",
+            },
+        )),
+    }
+
+    // An Interface type must define one or more fields.
+    if interface.fields().next().is_none() {
+        diagnostics.push(ValidationError::new(
+            interface.definition.name.location(),
+            DiagnosticData::EmptyFieldSet {
+                name: interface.definition.name.clone(),
+                describe_type: "interface type",
+            },
+        ));
+    }
+
     // Interface Type field validation.
     let field_definitions = interface.fields().cloned().collect();
     diagnostics.extend(db.validate_field_definitions(field_definitions));
@@ -69,6 +153,14 @@ pub(crate) fn validate_interface_definition(
         &implements_interfaces,
     ));
 
+    let field_definitions: Vec<_> = interface.fields().cloned().collect();
+    diagnostics.extend(validate_field_implementations(
+        &schema,
+        &interface.definition.name,
+        &field_definitions,
+        &implements_interfaces,
+    ));
+
     // When defining an interface that implements another interface, the
     // implementing interface must define each field that is specified by
     // the implemented interface.
@@ -152,6 +244,10 @@ pub(crate) fn validate_implements_interfaces(
         if implements_interfaces.contains(transitive_interface) {
             continue;
         }
+        // An interface reachable from itself is reported as a cyclic implementation instead.
+        if implementor.name() == Some(transitive_interface) {
+            continue;
+        }
 
         let definition_loc = implementor.location();
         // let via_loc = via_interface
@@ -170,3 +266,121 @@ pub(crate) fn validate_implements_interfaces(
 
     diagnostics
 }
+
+/// Fields that an object or interface type declares to implement interface fields must be valid
+/// implementations: their type must be the same or a subtype of the interface field's type,
+/// they must accept the same arguments with the same types, and any additional arguments must be
+/// optional.
+///
+/// Missing fields are reported separately.
+///
+/// Spec: https://spec.graphql.org/October2021/#IsValidImplementation()
+pub(crate) fn validate_field_implementations(
+    schema: &schema::Schema,
+    implementor_name: &ast::Name,
+    fields: &[Node<ast::FieldDefinition>],
+    implements_interfaces: &[ast::Name],
+) -> Vec<ValidationError> {
+    let mut diagnostics = Vec::new();
+
+    for interface_name in implements_interfaces {
+        let Some(interface) = schema.get_interface(interface_name) else {
+            continue;
+        };
+        for interface_field in interface.fields.values() {
+            let Some(field) = fields
+                .iter()
+                .find(|field| field.name == interface_field.name)
+            else {
+                continue;
+            };
+
+            if !is_valid_implementation_field_type(schema, &field.ty, &interface_field.ty) {
+                diagnostics.push(ValidationError::new(
+                    field.location(),
+                    DiagnosticData::InvalidFieldImplementation {
+                        name: implementor_name.clone(),
+                        interface: interface_name.clone(),
+                        field: field.name.clone(),
+                        ty: field.ty.clone(),
+                        interface_ty: interface_field.ty.clone(),
+                        interface_field_location: interface_field.location(),
+                    },
+                ));
+            }
+
+            for interface_argument in &interface_field.arguments {
+                match field.argument_by_name(&interface_argument.name) {
+                    None => diagnostics.push(ValidationError::new(
+                        field.location(),
+                        DiagnosticData::MissingInterfaceFieldArgument {
+                            name: implementor_name.clone(),
+                            interface: interface_name.clone(),
+                            field: field.name.clone(),
+                            argument: interface_argument.name.clone(),
+                            argument_location: interface_argument.location(),
+                        },
+                    )),
+                    Some(argument) if argument.ty != interface_argument.ty => {
+                        diagnostics.push(ValidationError::new(
+                            argument.location(),
+                            DiagnosticData::InvalidFieldArgumentImplementation {
+                                name: implementor_name.clone(),
+                                interface: interface_name.clone(),
+                                field: field.name.clone(),
+                                argument: argument.name.clone(),
+                                ty: argument.ty.clone(),
+                                interface_ty: interface_argument.ty.clone(),
+                            },
+                        ))
+                    }
+                    Some(_) => {}
+                }
+            }
+
+            for argument in &field.arguments {
+                if argument.is_required()
+                    && interface_field.argument_by_name(&argument.name).is_none()
+                {
+                    diagnostics.push(ValidationError::new(
+                        argument.location(),
+                        DiagnosticData::RequiredArgumentNotInInterface {
+                            name: implementor_name.clone(),
+                            interface: interface_name.clone(),
+                            field: field.name.clone(),
+                            argument: argument.name.clone(),
+                            field_location: interface_field.location(),
+                        },
+                    ));
+                }
+            }
+        }
+    }
+
+    diagnostics
+}
+
+/// Spec: https://spec.graphql.org/October2021/#IsValidImplementationFieldType()
+fn is_valid_implementation_field_type(
+    schema: &schema::Schema,
+    ty: &ast::Type,
+    interface_ty: &ast::Type,
+) -> bool {
+    use ast::Type::*;
+    match (ty, interface_ty) {
+        // A nullable field cannot implement a non-null field
+        (Named(_) | List(_), NonNullNamed(_) | NonNullList(_)) => false,
+        // A non-null field can implement a nullable field
+        (NonNullNamed(name), NonNullNamed(interface_name) | Named(interface_name))
+        | (Named(name), Named(interface_name)) => {
+            name == interface_name || schema.is_subtype(interface_name, name)
+        }
+        (NonNullList(item), NonNullList(interface_item) | List(interface_item))
+        | (List(item), List(interface_item)) => {
+            is_valid_implementation_field_type(schema, item, interface_item)
+        }
+        // List depth must match
+        (Named(_) | NonNullNamed(_), List(_) | NonNullList(_))
+        | (List(_) | NonNullList(_), Named(_) | NonNullNamed(_)) => false,
+    }
+}
@@ -18,19 +18,20 @@ mod schema;
 pub(crate) mod selection;
 mod union_;
 mod validation_db;
-mod value;
+pub(crate) mod value;
 mod variable;
 
 pub(crate) mod diagnostics;
 
 use crate::ast::Name;
-use crate::diagnostic::{CliReport, Diagnostic, ToCliReport};
+use crate::diagnostic::{CliReport, Color, Diagnostic, ToCliReport};
 use crate::executable::BuildError as ExecutableBuildError;
 use crate::execution::{GraphQLError, Response};
 use crate::schema::BuildError as SchemaBuildError;
 use crate::Node;
 use crate::SourceMap;
 use indexmap::IndexSet;
+use std::collections::HashMap;
 use std::fmt;
 use std::sync::Arc;
 pub(crate) use validation_db::{ValidationDatabase, ValidationStorage};
@@ -45,8 +46,10 @@ pub use crate::node::NodeLocation;
 ///
 /// * [`Schema::parse_and_validate`]
 /// * [`Schema::validate`]
+/// * [`Schema::validate_with`]
 /// * [`ExecutableDocument::parse_and_validate`]
 /// * [`ExecutableDocument::validate`]
+/// * [`ExecutableDocument::validate_with`]
 /// * [`coerce_variable_values`][crate::execution::coerce_variable_values]
 ///
 /// … or by explicitly skipping it with [`Valid::assume_valid`].
@@ -144,6 +147,96 @@ impl<T> fmt::Display for WithErrors<T> {
     }
 }
 
+/// Configuration for [`Schema::validate_with`] and [`ExecutableDocument::validate_with`].
+///
+/// The default options validate the same way as [`Schema::validate`]
+/// and [`ExecutableDocument::validate`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationOptions {
+    severities: HashMap<String, Severity>,
+    pub(crate) builtin_directives: IndexSet<Name>,
+    pub(crate) max_type_nesting: Option<usize>,
+}
+
+impl ValidationOptions {
+    /// Returns the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Report diagnostics with the given [`code`][DiagnosticData::code] with `severity`.
+    ///
+    /// A document with only [`Severity::Warning`] diagnostics is valid,
+    /// and the warnings are returned alongside it:
+    ///
+    /// ```
+    /// use apollo_compiler::validation::Severity;
+    /// use apollo_compiler::validation::ValidationOptions;
+    /// use apollo_compiler::Schema;
+    ///
+    /// let input = "type Query { a: Int } scalar Unused @unknown";
+    /// let options = ValidationOptions::new().severity("UndefinedDirective", Severity::Warning);
+    /// let (schema, warnings) = Schema::parse(input, "schema.graphql")
+    ///     .unwrap()
+    ///     .validate_with(&options)
+    ///     .unwrap();
+    /// assert!(schema.types.contains_key("Unused"));
+    /// assert_eq!(warnings.warnings().count(), 1);
+    /// ```
+    pub fn severity(mut self, code: impl Into<String>, severity: Severity) -> Self {
+        self.severities.insert(code.into(), severity);
+        self
+    }
+
+    /// Treat directives with the given names as built-in, in addition to the bundled
+    /// October 2021 set.
+    ///
+    /// Applying one of these directives where no definition is in scope does not
+    /// report an undefined directive, and its arguments are not checked.
+    /// This allows tracking newer built-in directives of the specification before
+    /// they are bundled with this crate.
+    /// If the schema does define a directive with the same name,
+    /// that definition is used for validation as usual.
+    ///
+    /// ```
+    /// use apollo_compiler::name;
+    /// use apollo_compiler::validation::ValidationOptions;
+    /// use apollo_compiler::Schema;
+    ///
+    /// let sdl = "type Query { field: Int @experimental(reason: \"testing\") }";
+    /// let schema = Schema::parse(sdl, "schema.graphql").unwrap();
+    /// assert!(schema.clone().validate().is_err());
+    ///
+    /// let options = ValidationOptions::new().with_builtin_directives([name!("experimental")]);
+    /// schema.validate_with(&options).unwrap();
+    /// ```
+    pub fn with_builtin_directives(mut self, names: impl IntoIterator<Item = Name>) -> Self {
+        self.builtin_directives.extend(names);
+        self
+    }
+
+    /// Report type references with more than `limit` nested list wrappers, like `[[[Int]]]`.
+    ///
+    /// This applies to field types, argument types, and input field types when validating
+    /// a schema, and to variable types when validating an executable document.
+    /// There is no limit by default.
+    ///
+    /// ```
+    /// use apollo_compiler::validation::ValidationOptions;
+    /// use apollo_compiler::Schema;
+    ///
+    /// let schema = Schema::parse("type Query { matrix: [[[Int]]] }", "schema.graphql").unwrap();
+    /// assert!(schema.clone().validate().is_ok());
+    ///
+    /// let options = ValidationOptions::new().max_type_nesting(2);
+    /// assert!(schema.validate_with(&options).is_err());
+    /// ```
+    pub fn max_type_nesting(mut self, limit: usize) -> Self {
+        self.max_type_nesting = Some(limit);
+        self
+    }
+}
+
 /// Returned as an error for situtations that should not happen with a valid schema or document.
 ///
 /// Since the relevant APIs take [`Valid<_>`][crate::validation::Valid] parameters,
@@ -190,9 +283,85 @@ pub struct DiagnosticList {
 #[error("{details}")]
 pub struct DiagnosticData {
     location: Option<NodeLocation>,
+    severity: Severity,
     details: Details,
 }
 
+/// How serious a diagnostic is.
+///
+/// All diagnostics are reported as errors by default.
+/// [`ValidationOptions::severity`] can downgrade some of them to warnings,
+/// for example while migrating a schema.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Severity {
+    /// The document is invalid.
+    #[default]
+    Error,
+    /// Something worth reporting, that was chosen not to make the document invalid.
+    Warning,
+}
+
+/// Which stage of processing a document a diagnostic comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DiagnosticCategory {
+    /// The source text could not be parsed, or parsing hit a configured limit.
+    Syntax,
+    /// Part of a document could not be converted to a [`Schema`]
+    /// or [`ExecutableDocument`][crate::ExecutableDocument], and was skipped.
+    Build,
+    /// The document does not satisfy a validation rule of the GraphQL specification.
+    Validation,
+}
+
+impl DiagnosticData {
+    /// Returns how serious this diagnostic is.
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Returns a stable identifier for the kind of this diagnostic,
+    /// for tools that need to match or suppress specific diagnostics.
+    ///
+    /// Codes are the `PascalCase` name of the kind of diagnostic,
+    /// such as `"SyntaxError"`, `"TypeDefinitionCollision"`, or `"MissingInterfaceField"`.
+    /// Once assigned, a code is not changed or reused for a different kind of diagnostic.
+    /// Unlike the message, it does not depend on the names in the document.
+    ///
+    /// ```
+    /// use apollo_compiler::validation::DiagnosticCategory;
+    /// use apollo_compiler::Schema;
+    ///
+    /// let input = "type Query { a: Int } interface I { b: Int } type T implements I { a: Int }";
+    /// let errors = Schema::parse_and_validate(input, "schema.graphql")
+    ///     .unwrap_err()
+    ///     .errors;
+    /// let diagnostic = errors.iter().next().unwrap();
+    /// assert_eq!(diagnostic.error.code(), "MissingInterfaceField");
+    /// assert_eq!(diagnostic.error.category(), DiagnosticCategory::Validation);
+    /// ```
+    pub fn code(&self) -> &'static str {
+        match &self.details {
+            Details::ParserLimit { .. } => "ParserLimit",
+            Details::SyntaxError { .. } => "SyntaxError",
+            Details::SchemaBuildError(err) => err.code(),
+            Details::ExecutableBuildError(err) => err.code(),
+            Details::CompilerDiagnostic(err) => err.data.code(),
+        }
+    }
+
+    /// Returns which stage of processing a document this diagnostic comes from.
+    pub fn category(&self) -> DiagnosticCategory {
+        match &self.details {
+            Details::ParserLimit { .. } | Details::SyntaxError { .. } => DiagnosticCategory::Syntax,
+            Details::SchemaBuildError(_) | Details::ExecutableBuildError(_) => {
+                DiagnosticCategory::Build
+            }
+            Details::CompilerDiagnostic(_) => DiagnosticCategory::Validation,
+        }
+    }
+}
+
 #[derive(thiserror::Error, Debug, Clone)]
 pub(crate) enum Details {
     #[error("{message}")]
@@ -213,6 +382,14 @@ impl ToCliReport for DiagnosticData {
         self.location
     }
 
+    fn to_report<'s>(&self, sources: &'s SourceMap, color: Color) -> CliReport<'s> {
+        let mut report =
+            CliReport::builder_with_severity(sources, self.location(), color, self.severity);
+        report.with_message(self);
+        self.report(&mut report);
+        report
+    }
+
     fn report(&self, report: &mut CliReport) {
         if let Details::CompilerDiagnostic(diagnostic) = &self.details {
             diagnostic.report(report);
@@ -269,6 +446,12 @@ impl ToCliReport for DiagnosticData {
                 SchemaBuildError::BuiltInScalarTypeRedefinition { .. } => {
                     report.with_label_opt(self.location, "remove this scalar definition");
                 }
+                SchemaBuildError::ScalarWithFields { name } => {
+                    report.with_label_opt(self.location, "remove these fields");
+                    report.with_help(format_args!(
+                        "to give `{name}` fields, define it as an object type instead"
+                    ));
+                }
                 SchemaBuildError::OrphanSchemaExtension { .. } => {
                     report.with_label_opt(self.location, "extension here")
                 }
@@ -352,6 +535,18 @@ impl ToCliReport for DiagnosticData {
                         "GraphQL requires operations to be named if the document has more than one",
                     );
                 }
+                ExecutableBuildError::MixedAnonymousOperation { named_operations } => {
+                    report.with_label_opt(self.location, "provide a name for this operation");
+                    for name in named_operations {
+                        report.with_label_opt(
+                            name.location(),
+                            format_args!("conflicting operation `{name}` defined here"),
+                        );
+                    }
+                    report.with_help(
+                        "GraphQL requires operations to be named if the document has more than one",
+                    );
+                }
                 ExecutableBuildError::OperationNameCollision {
                     name_at_previous_location,
                     ..
@@ -440,9 +635,27 @@ impl DiagnosticList {
             .map(|data| data.to_diagnostic(&self.sources))
     }
 
+    /// Returns an iterator of diagnostics with [`Severity::Error`].
+    pub fn errors(&self) -> impl Iterator<Item = Diagnostic<'_, DiagnosticData>> {
+        self.iter()
+            .filter(|diagnostic| diagnostic.error.severity == Severity::Error)
+    }
+
+    /// Returns an iterator of diagnostics with [`Severity::Warning`].
+    pub fn warnings(&self) -> impl Iterator<Item = Diagnostic<'_, DiagnosticData>> {
+        self.iter()
+            .filter(|diagnostic| diagnostic.error.severity == Severity::Warning)
+    }
+
+    /// Returns whether any diagnostic in this list has [`Severity::Error`].
+    pub fn has_errors(&self) -> bool {
+        self.errors().next().is_some()
+    }
+
     pub(crate) fn push(&mut self, location: Option<NodeLocation>, details: impl Into<Details>) {
         self.diagnostics_data.push(DiagnosticData {
             location,
+            severity: Severity::Error,
             details: details.into(),
         })
     }
@@ -459,9 +672,16 @@ impl DiagnosticList {
         self.sort()
     }
 
+    /// Sort by location, then by message for diagnostics at the same location,
+    /// so that the order does not depend on the order validation passes ran in.
     fn sort(&mut self) {
-        self.diagnostics_data
-            .sort_by_key(|err| err.location.map(|loc| (loc.file_id(), loc.offset())));
+        self.diagnostics_data.sort_by_cached_key(|err| {
+            (
+                err.location
+                    .map(|loc| (loc.file_id(), loc.offset(), loc.end_offset())),
+                err.to_string(),
+            )
+        });
     }
 
     pub(crate) fn into_result(mut self) -> Result<(), Self> {
@@ -492,6 +712,29 @@ impl DiagnosticList {
             }),
         }
     }
+
+    /// Apply the severity policy of `options`, then only fail if an error remains.
+    /// On success, the returned list contains the remaining warnings (if any).
+    pub(crate) fn into_valid_result_with_options<T>(
+        mut self,
+        value: T,
+        options: &ValidationOptions,
+    ) -> Result<(Valid<T>, DiagnosticList), WithErrors<T>> {
+        for data in &mut self.diagnostics_data {
+            if let Some(&severity) = options.severities.get(data.code()) {
+                data.severity = severity
+            }
+        }
+        self.sort();
+        if self.has_errors() {
+            Err(WithErrors {
+                partial: value,
+                errors: self,
+            })
+        } else {
+            Ok((Valid(value), self))
+        }
+    }
 }
 
 /// Use Debug formatting to output with colors: `format!("{diagnostics:?}")`
@@ -526,6 +769,32 @@ impl From<ExecutableBuildError> for Details {
     }
 }
 
+/// Report a type reference with more nested list wrappers than allowed by
+/// [`ValidationOptions::max_type_nesting`].
+fn validate_type_nesting(
+    db: &dyn ValidationDatabase,
+    ty: &crate::ast::Type,
+    location: Option<NodeLocation>,
+) -> Option<diagnostics::ValidationError> {
+    let limit = db.validation_options().max_type_nesting?;
+    let mut depth = 0;
+    let mut inner = ty;
+    while inner.is_list() {
+        depth += 1;
+        inner = inner.item_type();
+    }
+    (depth > limit).then(|| {
+        diagnostics::ValidationError::new(
+            location,
+            diagnostics::DiagnosticData::ExcessiveTypeNesting {
+                ty: ty.clone(),
+                depth,
+                limit,
+            },
+        )
+    })
+}
+
 const DEFAULT_RECURSION_LIMIT: usize = 32;
 
 #[derive(Debug, Clone, thiserror::Error)]
@@ -36,13 +36,24 @@ pub(crate) fn validate_object_type_definition(
 
     // Collect all fields, including duplicates
     let field_definitions: Vec<_> = object.fields().cloned().collect();
+
+    // An Object type must define one or more fields.
+    if field_definitions.is_empty() {
+        diagnostics.push(ValidationError::new(
+            object.definition.name.location(),
+            DiagnosticData::EmptyFieldSet {
+                name: object.definition.name.clone(),
+                describe_type: "object type",
+            },
+        ));
+    }
     let field_names: HashSet<_> = field_definitions
         .iter()
         .map(|field| field.name.clone())
         .collect();
 
     // Object Type field validations.
-    diagnostics.extend(db.validate_field_definitions(field_definitions));
+    diagnostics.extend(db.validate_field_definitions(field_definitions.clone()));
 
     // Implements Interfaces validation.
     let implements_interfaces: Vec<_> = object.implements_interfaces().cloned().collect();
@@ -52,6 +63,13 @@ pub(crate) fn validate_object_type_definition(
         &implements_interfaces,
     ));
 
+    diagnostics.extend(super::interface::validate_field_implementations(
+        &schema,
+        &object.definition.name,
+        &field_definitions,
+        &implements_interfaces,
+    ));
+
     // When defining an interface that implements another interface, the
     // implementing interface must define each field that is specified by
     // the implemented interface.
@@ -1,7 +1,10 @@
 use crate::ast;
+use crate::database::SourceType;
+use crate::node::NodeLocation;
 use crate::schema;
 use crate::validation::diagnostics::DiagnosticData;
 use crate::validation::diagnostics::ValidationError;
+use crate::validation::FileId;
 use crate::Node;
 use crate::ValidationDatabase;
 
@@ -17,11 +20,28 @@ pub(crate) fn validate_schema_definition(
         .iter()
         .any(|op| op.0 == ast::OperationType::Query);
     if !has_query {
-        let location = schema_definition.definition.location();
-        diagnostics.push(ValidationError::new(
-            location,
-            DiagnosticData::QueryRootOperationType,
-        ));
+        if let Some(location) = schema_definition.definition.location() {
+            diagnostics.push(ValidationError::new(
+                Some(location),
+                DiagnosticData::QueryRootOperationType,
+            ));
+        } else {
+            // Without a `schema` definition, there is no `Query` type to default to.
+            // Point at the start of the first source file.
+            let location = db
+                .source_files()
+                .into_iter()
+                .find(|file_id| {
+                    *file_id != FileId::BUILT_IN
+                        && *file_id != FileId::HACK_TMP
+                        && db.source_type(*file_id) != SourceType::Executable
+                })
+                .map(NodeLocation::file_start);
+            diagnostics.push(ValidationError::new(
+                location,
+                DiagnosticData::MissingQueryRoot,
+            ));
+        }
     }
     diagnostics.extend(validate_root_operation_definitions(db, &root_operations));
 
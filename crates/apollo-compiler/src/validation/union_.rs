@@ -28,9 +28,20 @@ pub(crate) fn validate_union_definition(
 
     let schema = db.schema();
 
+    // A Union type must include one or more unique member types.
+    //
+    // Uniqueness of members is checked when building the schema.
+    if union_def.members().next().is_none() {
+        diagnostics.push(ValidationError::new(
+            union_def.definition.name.location(),
+            DiagnosticData::EmptyUnion {
+                name: union_def.definition.name.clone(),
+            },
+        ));
+    }
+
     for union_member in union_def.members() {
         let member_location = union_member.location();
-        // TODO: (?) A Union type must include one or more unique member types.
 
         match schema.types.get(union_member) {
             None => {
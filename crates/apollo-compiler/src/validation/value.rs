@@ -4,6 +4,7 @@ use crate::schema;
 use crate::validation::diagnostics::{DiagnosticData, ValidationError};
 use crate::validation::ValidationDatabase;
 use crate::Node;
+use crate::Schema;
 
 fn unsupported_type(value: &Node<ast::Value>, declared_type: &Node<ast::Type>) -> ValidationError {
     ValidationError::new(
@@ -16,6 +17,98 @@ fn unsupported_type(value: &Node<ast::Value>, declared_type: &Node<ast::Type>) -
     )
 }
 
+fn invalid_default_value(
+    value: &Node<ast::Value>,
+    declared_type: &Node<ast::Type>,
+    reason: String,
+) -> ValidationError {
+    ValidationError::new(
+        value.location(),
+        DiagnosticData::InvalidDefaultValue {
+            reason,
+            ty: declared_type.to_string(),
+            type_location: declared_type.location(),
+        },
+    )
+}
+
+/// Report variables anywhere in a default value, which must be constant.
+///
+/// Default values are validated without variable definitions,
+/// so [`value_of_correct_type`] accepts any variable in them.
+pub(crate) fn validate_default_value_variables(
+    declared_type: &Node<ast::Type>,
+    default_value: &Node<ast::Value>,
+    diagnostics: &mut Vec<ValidationError>,
+) {
+    match &**default_value {
+        ast::Value::Variable(name) => diagnostics.push(invalid_default_value(
+            default_value,
+            declared_type,
+            format!(
+                "variable `${name}` cannot be used in the default value of type {declared_type}"
+            ),
+        )),
+        ast::Value::List(items) => {
+            for item in items {
+                validate_default_value_variables(declared_type, item, diagnostics)
+            }
+        }
+        ast::Value::Object(fields) => {
+            for (_, value) in fields {
+                validate_default_value_variables(declared_type, value, diagnostics)
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Check that the default value of a non-null type is not null.
+pub(crate) fn validate_non_null_default_value(
+    declared_type: &Node<ast::Type>,
+    default_value: &Node<ast::Value>,
+) -> Option<ValidationError> {
+    (declared_type.is_non_null() && default_value.is_null()).then(|| {
+        invalid_default_value(
+            default_value,
+            declared_type,
+            format!("non-null type {declared_type} cannot have a default value of null"),
+        )
+    })
+}
+
+/// Check a variable used in a list entry or input object field of type `location_ty`.
+///
+/// Variables used directly as an argument are checked by
+/// [`validate_variable_usage`][super::variable::validate_variable_usage].
+fn nested_variable_usage(
+    value: &Node<ast::Value>,
+    location_ty: &ast::Type,
+    has_location_default_value: bool,
+    var_defs: &[Node<ast::VariableDefinition>],
+) -> Option<ValidationError> {
+    let ast::Value::Variable(var_name) = &**value else {
+        return None;
+    };
+    let var_def = var_defs.iter().find(|v| v.name == *var_name)?;
+    if super::variable::is_variable_usage_allowed_at(
+        var_def,
+        location_ty,
+        has_location_default_value,
+    ) {
+        return None;
+    }
+    Some(ValidationError::new(
+        value.location(),
+        DiagnosticData::VariableTypeMismatch {
+            variable: var_def.name.clone(),
+            variable_type: (*var_def.ty).clone(),
+            variable_location: var_def.location(),
+            expected_type: location_ty.clone(),
+        },
+    ))
+}
+
 pub(crate) fn validate_values(
     db: &dyn ValidationDatabase,
     ty: &Node<ast::Type>,
@@ -23,18 +116,27 @@ pub(crate) fn validate_values(
     var_defs: &[Node<ast::VariableDefinition>],
 ) -> Vec<ValidationError> {
     let mut diagnostics = vec![];
-    value_of_correct_type(db, ty, &argument.value, var_defs, &mut diagnostics);
+    value_of_correct_type(
+        &db.schema(),
+        ty,
+        &argument.value,
+        var_defs,
+        &mut diagnostics,
+    );
     diagnostics
 }
 
+/// Check that `arg_value` can be coerced to `ty`.
+///
+/// This is also used by [`Directive::resolved_arguments`][ast::Directive::resolved_arguments]
+/// outside of validation.
 pub(crate) fn value_of_correct_type(
-    db: &dyn ValidationDatabase,
+    schema: &Schema,
     ty: &Node<ast::Type>,
     arg_value: &Node<ast::Value>,
     var_defs: &[Node<ast::VariableDefinition>],
     diagnostics: &mut Vec<ValidationError>,
 ) {
-    let schema = db.schema();
     let Some(type_definition) = schema.types.get(ty.inner_named_type()) else {
         return;
     };
@@ -102,7 +204,17 @@ pub(crate) fn value_of_correct_type(
         // error indicating an incorrect type.
         // When expected as an input type, any string (such as "4") or
         // integer (such as 4 or -4) input value should be coerced to ID
-        ast::Value::String(_) => match &type_definition {
+        ast::Value::String(string) => match &type_definition {
+            // Enum values are names, not strings.
+            schema::ExtendedType::Enum(enum_) => diagnostics.push(ValidationError::new(
+                arg_value.location(),
+                DiagnosticData::EnumAsString {
+                    value: string.as_str().to_owned(),
+                    definition: enum_.name.clone(),
+                    is_enum_value: enum_.values.contains_key(string.as_str()),
+                    definition_location: enum_.location(),
+                },
+            )),
             schema::ExtendedType::Scalar(scalar) => {
                 // specifically return diagnostics for ints, floats, and
                 // booleans.
@@ -139,20 +251,9 @@ pub(crate) fn value_of_correct_type(
                     // we don't have the actual variable values here, so just
                     // compare if two Types are the same
                     // TODO(@goto-bus-stop) This should use the is_assignable_to check
+                    // The variable's default value is validated with the variable definition.
                     if var_def.ty.inner_named_type() != ty.inner_named_type() {
                         diagnostics.push(unsupported_type(arg_value, ty));
-                    } else if let Some(default_value) = &var_def.default_value {
-                        if var_def.ty.is_non_null() && default_value.is_null() {
-                            diagnostics.push(unsupported_type(default_value, &var_def.ty))
-                        } else {
-                            value_of_correct_type(
-                                db,
-                                &var_def.ty,
-                                default_value,
-                                var_defs,
-                                diagnostics,
-                            )
-                        }
                     }
                 }
             }
@@ -191,7 +292,11 @@ pub(crate) fn value_of_correct_type(
                 let item_type = ty.same_location(ty.item_type().clone());
                 if type_definition.is_input_type() {
                     for v in li {
-                        value_of_correct_type(db, &item_type, v, var_defs, diagnostics);
+                        if let Some(error) = nested_variable_usage(v, &item_type, false, var_defs) {
+                            diagnostics.push(error);
+                            continue;
+                        }
+                        value_of_correct_type(schema, &item_type, v, var_defs, diagnostics);
                     }
                 } else {
                     diagnostics.push(unsupported_type(arg_value, &item_type));
@@ -201,13 +306,13 @@ pub(crate) fn value_of_correct_type(
         ast::Value::Object(obj) => match &type_definition {
             schema::ExtendedType::Scalar(scalar) if !scalar.is_built_in() => (),
             schema::ExtendedType::InputObject(input_obj) => {
-                let undefined_field = obj
+                let undefined_fields = obj
                     .iter()
-                    .find(|(name, ..)| !input_obj.fields.contains_key(name));
+                    .filter(|(name, ..)| !input_obj.fields.contains_key(name));
 
-                // Add a diagnostic if a value does not exist on the input
+                // Add a diagnostic for each value that does not exist on the input
                 // object type
-                if let Some((name, value)) = undefined_field {
+                for (name, value) in undefined_fields {
                     diagnostics.push(ValidationError::new(
                         value.location(),
                         DiagnosticData::UndefinedInputValue {
@@ -246,9 +351,42 @@ pub(crate) fn value_of_correct_type(
                     let used_val = obj.iter().find(|(obj_name, ..)| obj_name == input_name);
 
                     if let Some((_, v)) = used_val {
-                        value_of_correct_type(db, ty, v, var_defs, diagnostics);
+                        if let Some(error) =
+                            nested_variable_usage(v, ty, f.default_value.is_some(), var_defs)
+                        {
+                            diagnostics.push(error);
+                        } else {
+                            value_of_correct_type(schema, ty, v, var_defs, diagnostics);
+                        }
                     }
-                })
+                });
+
+                // Exactly one field of a `@oneOf` input object must be provided,
+                // and its value must not be null.
+                // `@oneOf` is not built-in, so this only applies if the schema defines it.
+                if schema.directive_definitions.contains_key("oneOf")
+                    && input_obj.directives.has("oneOf")
+                {
+                    let is_valid = match obj.as_slice() {
+                        [(_, value)] => match &**value {
+                            ast::Value::Null => false,
+                            ast::Value::Variable(var_name) => !var_defs
+                                .iter()
+                                .any(|v| v.name == *var_name && !v.ty.is_non_null()),
+                            _ => true,
+                        },
+                        _ => false,
+                    };
+                    if !is_valid {
+                        diagnostics.push(ValidationError::new(
+                            arg_value.location(),
+                            DiagnosticData::InvalidOneOfValue {
+                                name: input_obj.name.clone(),
+                                definition_location: input_obj.location(),
+                            },
+                        ));
+                    }
+                }
             }
             _ => diagnostics.push(unsupported_type(arg_value, ty)),
         },
@@ -27,11 +27,34 @@ pub(crate) fn validate_variable_definitions(
 
         if has_schema {
             let ty = &variable.ty;
+            diagnostics.extend(super::validate_type_nesting(db, ty, ty.location()));
             let type_definition = schema.types.get(ty.inner_named_type());
 
             match type_definition {
                 Some(type_definition) if type_definition.is_input_type() => {
-                    // OK!
+                    // A default value must be a constant value of the declared type,
+                    // and so it can only be null if the type is nullable.
+                    if let Some(default_value) = &variable.default_value {
+                        super::value::validate_default_value_variables(
+                            ty,
+                            default_value,
+                            &mut diagnostics,
+                        );
+                        if let Some(error) =
+                            super::value::validate_non_null_default_value(ty, default_value)
+                        {
+                            diagnostics.push(error);
+                        } else {
+                            super::value::value_of_correct_type(
+                                &schema,
+                                ty,
+                                default_value,
+                                // Default values cannot reference variables
+                                &[],
+                                &mut diagnostics,
+                            );
+                        }
+                    }
                 }
                 Some(type_definition) => {
                     diagnostics.push(ValidationError::new(
@@ -277,13 +300,26 @@ pub(crate) fn validate_variable_usage(
 fn is_variable_usage_allowed(
     variable_def: &ast::VariableDefinition,
     variable_usage: &ast::InputValueDefinition,
+) -> bool {
+    is_variable_usage_allowed_at(
+        variable_def,
+        &variable_usage.ty,
+        variable_usage.default_value.is_some(),
+    )
+}
+
+/// Implements [IsVariableUsageAllowed](https://spec.graphql.org/October2021/#IsVariableUsageAllowed())
+/// for a variable used in an argument, object field, or list entry of type `location_ty`.
+pub(crate) fn is_variable_usage_allowed_at(
+    variable_def: &ast::VariableDefinition,
+    location_ty: &ast::Type,
+    has_location_default_value: bool,
 ) -> bool {
     // 1. Let variable_ty be the expected type of variable_def.
     let variable_ty = &variable_def.ty;
     // 2. Let location_ty be the expected type of the Argument,
     // ObjectField, or ListValue entry where variableUsage is
     // located.
-    let location_ty = &variable_usage.ty;
     // 3. if location_ty is a non-null type AND variable_ty is
     // NOT a non-null type:
     if location_ty.is_non_null() && !variable_ty.is_non_null() {
@@ -294,7 +330,6 @@ fn is_variable_usage_allowed(
         // 3.b. Let hasLocationDefaultValue be true if a default
         // value exists for the Argument or ObjectField where
         // variableUsage is located.
-        let has_location_default_value = variable_usage.default_value.is_some();
         // 3.c. If hasNonNullVariableDefaultValue is NOT true
         // AND hasLocationDefaultValue is NOT true, return
         // false.
@@ -304,7 +339,7 @@ fn is_variable_usage_allowed(
 
         // 3.d. Let nullable_location_ty be the unwrapped
         // nullable type of location_ty.
-        return variable_ty.is_assignable_to(&location_ty.as_ref().clone().nullable());
+        return variable_ty.is_assignable_to(&location_ty.clone().nullable());
     }
 
     variable_ty.is_assignable_to(location_ty)
@@ -35,7 +35,7 @@ fn get_operations() {
 #[test]
 fn is_introspection_operation() {
     let query_input = r#"
-        type Query {}
+        type Query { id: ID }
         query TypeIntrospect {
           __type(name: "User") {
             name
@@ -184,7 +184,7 @@ fn is_introspection_deep() {
 #[test]
 fn is_introspection_repeated_fragment() {
     let query_input_indirect = r#"
-      type Query {}
+      type Query { id: ID }
 
       query IntrospectRepeatedIndirectFragment {
         ...A
@@ -204,7 +204,7 @@ fn is_introspection_repeated_fragment() {
     "#;
 
     let query_input_direct = r#"
-      type Query {}
+      type Query { id: ID }
 
       query IntrospectRepeatedDirectFragment {
         ...C
@@ -233,3 +233,66 @@ fn is_introspection_repeated_fragment() {
             .is_introspection(&query_doc_direct)
     );
 }
+
+#[test]
+fn operation_variables() {
+    let schema =
+        Schema::parse_and_validate("type Query { a(b: Int, c: String): Int }", "schema.graphql")
+            .unwrap();
+    let query = r#"
+    query A($b: Int = 1, $c: String) { a(b: $b, c: $c) }
+    query B { a }
+    "#;
+    let document = ExecutableDocument::parse_and_validate(&schema, query, "query.graphql").unwrap();
+
+    let variables = document.operation_variables(Some("A")).unwrap();
+    let names: Vec<_> = variables.iter().map(|var| var.name.as_str()).collect();
+    assert_eq!(names, ["b", "c"]);
+    let location = variables[0].location().unwrap();
+    assert_eq!(
+        &query[location.offset()..location.end_offset()],
+        "$b: Int = 1"
+    );
+
+    assert!(document.operation_variables(Some("B")).unwrap().is_empty());
+    // The operation must be selected without ambiguity
+    assert!(document.operation_variables(None).is_err());
+    assert!(document.operation_variables(Some("C")).is_err());
+}
+
+#[test]
+fn validate_many_documents_against_one_schema() {
+    // Invalid: `Unused` has an undefined directive. The schema is trusted as-is,
+    // so this must not be reported for each executable document.
+    let schema = Schema::parse(
+        "type Query { a(b: Int!): Int } scalar Unused @undefined",
+        "schema.graphql",
+    )
+    .unwrap();
+    assert!(schema.clone().validate().is_err());
+    let schema = apollo_compiler::validation::Valid::assume_valid(schema);
+
+    let documents = [
+        ("ok.graphql", "query Ok { a(b: 1) }"),
+        ("missing.graphql", "query Missing { a }"),
+        ("undefined.graphql", "query Undefined { a(b: $b) }"),
+    ];
+    let results: Vec<_> = documents
+        .iter()
+        .map(|(path, source)| {
+            let document = ExecutableDocument::parse(&schema, *source, path).unwrap();
+            match document.validate(&schema) {
+                Ok(_) => Vec::new(),
+                Err(with_errors) => with_errors
+                    .errors
+                    .iter()
+                    .map(|diagnostic| diagnostic.error.code())
+                    .collect(),
+            }
+        })
+        .collect();
+    assert_eq!(
+        results,
+        [vec![], vec!["RequiredArgument"], vec!["UndefinedVariable"]]
+    );
+}
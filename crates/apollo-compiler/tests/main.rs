@@ -11,6 +11,7 @@ mod node_str;
 mod parser;
 mod schema;
 mod serde;
+mod serialize;
 mod validation;
 
 #[path = "../examples/rename.rs"]
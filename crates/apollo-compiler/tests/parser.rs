@@ -98,3 +98,35 @@ fn it_errors_with_multiple_limits() {
     );
     assert!(errors.contains("doc.graphql:6:25"), "{errors}");
 }
+
+#[test]
+fn it_iterates_operations_and_fragments_separately() {
+    let input = r#"
+    query A { a }
+    fragment F on Query { b }
+    type Query { a: Int b: Int c: Int }
+    { c }
+    mutation B { a }
+    "#;
+    let document = Parser::new().parse_ast(input, "doc.graphql").unwrap();
+
+    let names: Vec<_> = document
+        .operations()
+        .map(|operation| operation.name.as_ref().map(|name| name.as_str()))
+        .collect();
+    assert_eq!(names, [Some("A"), None, Some("B")]);
+
+    let fragments: Vec<_> = document
+        .fragment_definitions()
+        .map(|fragment| fragment.name.as_str())
+        .collect();
+    assert_eq!(fragments, ["F"]);
+
+    let b = document.operation_by_name("B").unwrap();
+    assert_eq!(
+        b.operation_type,
+        apollo_compiler::ast::OperationType::Mutation
+    );
+    assert!(document.operation_by_name("F").is_none());
+    assert!(document.operation_by_name("C").is_none());
+}
@@ -1,3 +1,5 @@
+use apollo_compiler::ast;
+use apollo_compiler::Node;
 use apollo_compiler::Schema;
 
 #[test]
@@ -186,6 +188,33 @@ fn is_subtype() {
     assert!(schema.is_subtype("Foo", "InterfaceType2"));
     assert!(schema.is_subtype("Bar", "InterfaceType2"));
     assert!(schema.is_subtype("Baz", "InterfaceType2"));
+
+    let schema = gen_schema_interfaces(
+        r#"
+        interface Level2 implements Foo { me: String }
+        interface Level3 implements Level2 { me: String }
+        type ObjectType implements Level3 { me: String }
+        "#,
+    );
+    assert!(schema.is_subtype("Foo", "Level2"));
+    assert!(schema.is_subtype("Level2", "Level3"));
+    assert!(schema.is_subtype("Foo", "Level3"));
+    assert!(schema.is_subtype("Level3", "ObjectType"));
+    assert!(schema.is_subtype("Foo", "ObjectType"));
+    assert!(!schema.is_subtype("Bar", "Level3"));
+    assert!(!schema.is_subtype("Level3", "Foo"));
+    assert!(!schema.is_subtype("Level3", "Level2"));
+
+    // Cycles are invalid, but must not loop forever
+    let schema = gen_schema_interfaces(
+        r#"
+        interface Cycle1 implements Cycle2 { me: String }
+        interface Cycle2 implements Cycle1 { me: String }
+        "#,
+    );
+    assert!(schema.is_subtype("Cycle1", "Cycle2"));
+    assert!(schema.is_subtype("Cycle1", "Cycle1"));
+    assert!(!schema.is_subtype("Foo", "Cycle1"));
 }
 
 const SUPERGRAPH_BOILERPLATE: &str = r#"
@@ -226,3 +255,753 @@ fn test_default_root_op_name_ignored_with_explicit_schema_def() {
     let schema = Schema::parse_and_validate(input, "schema.graphql").unwrap();
     assert!(schema.schema_definition.mutation.is_none())
 }
+
+#[test]
+fn type_of_field() {
+    let schema = r#"
+        type Query {
+          products: [Product!]!
+          node(id: ID!): Node
+        }
+
+        interface Node {
+          id: ID!
+        }
+
+        type Product implements Node {
+          id: ID!
+          sku: String @deprecated(reason: "Use `id`")
+          upc: String @deprecated
+          name: String
+        }
+    "#;
+    let schema = Schema::parse_and_validate(schema, "schema.graphql").unwrap();
+
+    assert_eq!(
+        schema.type_of_field("Query", "products"),
+        Some(&apollo_compiler::ty!([Product!]!))
+    );
+    assert_eq!(
+        schema.type_of_field("Node", "id"),
+        Some(&apollo_compiler::ty!(ID!))
+    );
+    assert_eq!(
+        schema.type_of_field("Product", "__typename"),
+        Some(&apollo_compiler::ty!(String!))
+    );
+    assert_eq!(schema.type_of_field("Product", "price"), None);
+    assert_eq!(schema.type_of_field("Missing", "id"), None);
+
+    let sku = schema.type_field("Product", "sku").unwrap();
+    assert!(sku.is_deprecated());
+    assert_eq!(sku.deprecation_reason(), Some("Use `id`"));
+    let upc = schema.type_field("Product", "upc").unwrap();
+    assert!(upc.is_deprecated());
+    assert_eq!(upc.deprecation_reason(), Some("No longer supported"));
+    let name = schema.type_field("Product", "name").unwrap();
+    assert!(!name.is_deprecated());
+    assert_eq!(name.deprecation_reason(), None);
+}
+
+#[test]
+fn implementers() {
+    let schema = r#"
+        type Query { node: Node }
+
+        interface Node { id: ID! }
+        interface Resource implements Node { id: ID! url: String }
+        interface Image implements Resource & Node { id: ID! url: String width: Int }
+
+        type Photo implements Image & Resource & Node { id: ID! url: String width: Int }
+        type User implements Node { id: ID! }
+        type Post { id: ID! }
+        extend type Post implements Node
+
+        # Declares only the direct interface, which is invalid but still a transitive implementer
+        type Thumbnail implements Image { id: ID! url: String width: Int }
+    "#;
+    let schema = Schema::parse(schema, "schema.graphql").unwrap();
+
+    let implementers = schema.implementers("Node");
+    let mut objects: Vec<_> = implementers.objects.iter().map(|n| n.as_str()).collect();
+    objects.sort();
+    assert_eq!(objects, ["Photo", "Post", "Thumbnail", "User"]);
+    let mut interfaces: Vec<_> = implementers.interfaces.iter().map(|n| n.as_str()).collect();
+    interfaces.sort();
+    assert_eq!(interfaces, ["Image", "Resource"]);
+
+    let implementers = schema.implementers("Image");
+    let mut objects: Vec<_> = implementers.objects.iter().map(|n| n.as_str()).collect();
+    objects.sort();
+    assert_eq!(objects, ["Photo", "Thumbnail"]);
+    assert!(implementers.interfaces.is_empty());
+
+    assert_eq!(schema.implementers("Photo"), Default::default());
+    assert_eq!(schema.implementers("Missing"), Default::default());
+}
+
+#[test]
+fn root_operation() {
+    use apollo_compiler::ast::OperationType;
+
+    // Default names are only used for object types
+    let schema = Schema::parse(
+        "type Query { a: Int } scalar Mutation type Subscription { b: Int }",
+        "schema.graphql",
+    )
+    .unwrap();
+    assert_eq!(
+        schema.root_operation(OperationType::Query).unwrap(),
+        "Query"
+    );
+    assert!(schema.root_operation(OperationType::Mutation).is_none());
+    assert_eq!(
+        schema.root_operation(OperationType::Subscription).unwrap(),
+        "Subscription"
+    );
+
+    // The implicit schema definition can be extended
+    let schema = Schema::parse(
+        "type Query { a: Int } type Write { b: Int } extend schema { mutation: Write }",
+        "schema.graphql",
+    )
+    .unwrap();
+    assert_eq!(
+        schema.root_operation(OperationType::Query).unwrap(),
+        "Query"
+    );
+    assert_eq!(
+        schema.root_operation(OperationType::Mutation).unwrap(),
+        "Write"
+    );
+
+    // An explicit schema definition takes precedence over default names
+    let schema = Schema::parse(
+        "schema { query: Root } type Root { a: Int } type Query { b: Int }",
+        "schema.graphql",
+    )
+    .unwrap();
+    assert_eq!(schema.root_operation(OperationType::Query).unwrap(), "Root");
+}
+
+#[test]
+fn references_to() {
+    let sdl = r#"
+schema { query: Node }
+directive @tag(node: [Node!]) on FIELD_DEFINITION
+interface Node { id: ID! }
+interface Entity implements Node { id: ID! parent: Node }
+type Thing implements Node & Entity {
+  id: ID!
+  parent: Node
+  children(filter: NodeFilter, first: Int): [[Node]!]!
+}
+union Any = Thing
+input NodeFilter { id: ID, nested: [NodeFilter!]! }
+extend type Thing { other: Node }
+"#;
+    let schema = Schema::parse(sdl, "schema.graphql").unwrap();
+    let lines_and_columns = |name: &str| {
+        schema
+            .references_to(name)
+            .into_iter()
+            .map(|location| {
+                assert_eq!(&sdl[location.offset()..location.end_offset()], name);
+                let (line, column) = sdl[..location.offset()]
+                    .lines()
+                    .enumerate()
+                    .last()
+                    .map(|(line, text)| (line, text.len()))
+                    .unwrap();
+                (line + 1, column + 1)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // The definition comes first, then uses in schema order.
+    // `Thing.other` comes from an extension and is a field type reference too.
+    assert_eq!(
+        lines_and_columns("Node"),
+        [
+            (4, 11),
+            (2, 17),
+            (5, 29),
+            (5, 52),
+            (6, 23),
+            (8, 11),
+            (9, 47),
+            (13, 28),
+            (3, 23),
+        ]
+    );
+    assert_eq!(
+        lines_and_columns("NodeFilter"),
+        [(12, 7), (9, 20), (12, 37)]
+    );
+    assert_eq!(lines_and_columns("Thing"), [(6, 6), (11, 13)]);
+    assert!(lines_and_columns("Missing").is_empty());
+}
+
+#[test]
+fn is_built_in() {
+    let schema = Schema::parse(
+        r#"
+        type Query { a: Int }
+        scalar URL
+        directive @deprecated(reason: String) on FIELD_DEFINITION
+        "#,
+        "schema.graphql",
+    )
+    .unwrap();
+
+    for name in ["Int", "Float", "String", "Boolean", "ID"] {
+        assert!(schema.is_built_in(name), "{name}");
+    }
+    for name in [
+        "__Schema",
+        "__Type",
+        "__TypeKind",
+        "__Field",
+        "__InputValue",
+        "__EnumValue",
+        "__Directive",
+        "__DirectiveLocation",
+    ] {
+        assert!(schema.is_built_in(name), "{name}");
+    }
+    for name in ["skip", "include", "specifiedBy"] {
+        assert!(schema.is_built_in(name), "{name}");
+    }
+    // Redefined by the schema
+    assert!(!schema.is_built_in("deprecated"));
+    for name in ["Query", "URL", "Undefined"] {
+        assert!(!schema.is_built_in(name), "{name}");
+    }
+}
+
+#[test]
+fn type_definitions() {
+    let schema = Schema::parse(
+        r#"
+        type Query { a: Int }
+        extend type Query { b: Int }
+        interface I { a: Int }
+        union U = Query
+        extend union U = Other
+        type Other { c: Int }
+        enum E { A }
+        extend enum E { B }
+        input In { a: Int }
+        scalar URL
+        "#,
+        "schema.graphql",
+    )
+    .unwrap();
+    let definitions = schema.type_definitions();
+
+    let objects: Vec<_> = definitions.objects.keys().map(|n| n.as_str()).collect();
+    assert_eq!(
+        objects,
+        [
+            "__Schema",
+            "__Type",
+            "__Field",
+            "__InputValue",
+            "__EnumValue",
+            "__Directive",
+            "Query",
+            "Other"
+        ]
+    );
+    assert_eq!(definitions.objects["Query"].fields.len(), 2);
+    assert_eq!(definitions.unions["U"].members.len(), 2);
+    assert_eq!(definitions.enums["E"].values.len(), 2);
+    assert!(definitions.interfaces.contains_key("I"));
+    assert!(definitions.input_objects.contains_key("In"));
+    let custom_scalars: Vec<_> = definitions
+        .scalars
+        .values()
+        .filter(|def| !def.is_built_in())
+        .map(|def| def.name.as_str())
+        .collect();
+    assert_eq!(custom_scalars, ["URL"]);
+    let total = definitions.scalars.len()
+        + definitions.objects.len()
+        + definitions.interfaces.len()
+        + definitions.unions.len()
+        + definitions.enums.len()
+        + definitions.input_objects.len();
+    assert_eq!(total, schema.types.len());
+}
+
+#[test]
+fn validate_orders_diagnostics_deterministically() {
+    use apollo_compiler::ast::{Definition, Document, FieldDefinition, ObjectTypeDefinition};
+    use apollo_compiler::{name, ty, Node};
+
+    // Definitions created programmatically have no source location,
+    // so their diagnostics can only be ordered by message.
+    let mut document = Document::new();
+    for (type_name, field_type) in [
+        (name!("Query"), ty!(Int)),
+        (name!("Zebra"), ty!(Undefined1)),
+        (name!("Apple"), ty!(Undefined2)),
+        (name!("Mango"), ty!(Undefined3)),
+    ] {
+        document
+            .definitions
+            .push(Definition::ObjectTypeDefinition(Node::new(
+                ObjectTypeDefinition {
+                    description: None,
+                    name: type_name,
+                    implements_interfaces: Vec::new(),
+                    directives: Default::default(),
+                    fields: vec![Node::new(FieldDefinition {
+                        description: None,
+                        name: name!("field"),
+                        arguments: Vec::new(),
+                        ty: field_type,
+                        directives: Default::default(),
+                    })],
+                },
+            )));
+    }
+
+    let messages = || -> Vec<String> {
+        let schema = document.to_schema().unwrap();
+        let errors = schema.validate().unwrap_err().errors;
+        errors.iter().map(|error| error.error.to_string()).collect()
+    };
+    let expected = [
+        "cannot find type `Undefined1` in this document",
+        "cannot find type `Undefined2` in this document",
+        "cannot find type `Undefined3` in this document",
+    ];
+    for _ in 0..10 {
+        assert_eq!(messages(), expected);
+    }
+}
+
+#[test]
+fn validate_rejects_empty_definitions() {
+    use apollo_compiler::name;
+    use apollo_compiler::schema;
+
+    let mut schema = Schema::parse("type Query { a: Int }", "schema.graphql").unwrap();
+    schema.types.insert(
+        name!("EmptyObject"),
+        schema::ObjectType {
+            description: Default::default(),
+            name: name!("EmptyObject"),
+            implements_interfaces: Default::default(),
+            directives: Default::default(),
+            fields: Default::default(),
+        }
+        .into(),
+    );
+    schema.types.insert(
+        name!("EmptyUnion"),
+        schema::UnionType {
+            description: Default::default(),
+            name: name!("EmptyUnion"),
+            directives: Default::default(),
+            members: Default::default(),
+        }
+        .into(),
+    );
+    schema.types.insert(
+        name!("EmptyEnum"),
+        schema::EnumType {
+            description: Default::default(),
+            name: name!("EmptyEnum"),
+            directives: Default::default(),
+            values: Default::default(),
+        }
+        .into(),
+    );
+
+    // Serializing stays infallible
+    let sdl = schema.to_string();
+    assert!(sdl.contains("type EmptyObject\n"), "{sdl}");
+    assert!(sdl.contains("union EmptyUnion\n"), "{sdl}");
+    assert!(sdl.contains("enum EmptyEnum\n"), "{sdl}");
+
+    let errors = schema.validate().unwrap_err().errors;
+    let messages: Vec<_> = errors.iter().map(|error| error.error.to_string()).collect();
+    assert_eq!(
+        messages,
+        [
+            "enum type `EmptyEnum` must define one or more values",
+            "object type `EmptyObject` must define one or more fields",
+            "union type `EmptyUnion` must have at least one member type",
+        ],
+        "{errors}"
+    );
+}
+
+#[test]
+fn type_fields() {
+    let schema = Schema::parse(
+        r#"
+        type Query { node: Node }
+        interface Entity { id: ID!, createdAt: String }
+        interface Node implements Entity { id: ID!, createdAt: String }
+        type User implements Node & Entity {
+          "The user's own id"
+          id: ID!
+          name: String
+        }
+        extend type User { createdAt: String }
+        type Cyclic implements Cyclic { a: Int }
+        "#,
+        "schema.graphql",
+    )
+    .unwrap();
+
+    let names = |type_name| -> Vec<_> {
+        schema
+            .type_fields(type_name)
+            .into_keys()
+            .map(|name| name.to_string())
+            .collect()
+    };
+    assert_eq!(names("User"), ["id", "name", "createdAt"]);
+    assert_eq!(names("Node"), ["id", "createdAt"]);
+    assert_eq!(names("Cyclic"), ["a"]);
+    assert!(names("String").is_empty());
+    assert!(names("Undefined").is_empty());
+
+    // Fields redeclared by the object take precedence over interface fields
+    let fields = schema.type_fields("User");
+    assert_eq!(
+        fields["id"].description.as_deref(),
+        Some("The user's own id")
+    );
+}
+
+#[test]
+fn possible_types() {
+    let schema = Schema::parse(
+        r#"
+        type Query { search: [SearchResult] }
+        interface Node { id: ID! }
+        interface Resource implements Node { id: ID! }
+        type User implements Node { id: ID! }
+        type Image implements Resource & Node { id: ID! }
+        type Video { id: ID! }
+        extend type Video implements Resource & Node
+        union SearchResult = User
+        extend union SearchResult = Video | Image
+        "#,
+        "schema.graphql",
+    )
+    .unwrap();
+
+    let names = |type_name| -> Vec<_> {
+        schema
+            .possible_types(type_name)
+            .into_iter()
+            .map(|name| name.to_string())
+            .collect()
+    };
+    assert_eq!(names("Node"), ["User", "Image", "Video"]);
+    assert_eq!(names("Resource"), ["Image", "Video"]);
+    assert_eq!(names("SearchResult"), ["User", "Video", "Image"]);
+    assert!(names("User").is_empty());
+    assert!(names("String").is_empty());
+    assert!(names("Undefined").is_empty());
+}
+
+#[test]
+fn reachable_types() {
+    let schema = Schema::parse(
+        r#"
+        schema { query: Root, mutation: Mutation }
+        directive @cost(weight: Weight) on FIELD_DEFINITION
+        type Root { node(id: ID!): Node, search: SearchResult }
+        type Mutation { update(input: UpdateInput): Boolean }
+        interface Node { id: ID! }
+        interface Resource implements Node { id: ID! }
+        type Image implements Resource & Node { id: ID!, next: Image }
+        union SearchResult = User
+        type User { id: ID! }
+        input UpdateInput { nested: UpdateInput, date: Date }
+        scalar Date
+        scalar Weight
+        type Query { unused: Orphan }
+        type Orphan { id: ID! }
+        enum Unused { A }
+        "#,
+        "schema.graphql",
+    )
+    .unwrap();
+
+    let mut reachable: Vec<_> = schema
+        .reachable_types()
+        .into_iter()
+        .filter(|name| !schema.is_built_in(name))
+        .map(|name| name.to_string())
+        .collect();
+    reachable.sort();
+    assert_eq!(
+        reachable,
+        [
+            "Date",
+            "Image",
+            "Mutation",
+            "Node",
+            "Resource",
+            "Root",
+            "SearchResult",
+            "UpdateInput",
+            "User",
+            "Weight",
+        ]
+    );
+
+    // Built-in types are only reachable if used, here through introspection
+    let reachable = schema.reachable_types();
+    assert!(reachable.contains("__Directive"));
+    assert!(reachable.contains("Boolean"));
+    assert!(!reachable.contains("Int"));
+    assert!(!reachable.contains("Float"));
+
+    // Without root operations, only the arguments of built-in directives are reachable
+    let schema = Schema::parse("scalar Lonely", "schema.graphql").unwrap();
+    let mut reachable: Vec<_> = schema.reachable_types().into_iter().collect();
+    reachable.sort();
+    assert_eq!(reachable, ["Boolean", "String"]);
+}
+
+#[test]
+fn is_recursive() {
+    let schema = Schema::parse(
+        r#"
+        type Query { node: Node, search: [SearchResult], leaf: Leaf }
+        interface Node { id: ID! }
+        type Folder implements Node { id: ID!, children: [Node!]! }
+        type File implements Node { id: ID!, size(unit: Unit): Int }
+        union SearchResult = Folder | Article
+        type Article { related: [SearchResult] }
+        type Leaf implements Named { name: String }
+        interface Named { name: String }
+        input Unit { base: String }
+        "#,
+        "schema.graphql",
+    )
+    .unwrap();
+
+    // Through interface implementers
+    assert!(schema.is_recursive("Node"));
+    assert!(schema.is_recursive("Folder"));
+    // `File` is a `Node` but does not contain one
+    assert!(!schema.is_recursive("File"));
+    // Through union members
+    assert!(schema.is_recursive("SearchResult"));
+    assert!(schema.is_recursive("Article"));
+    // Implementing an interface does not nest values
+    assert!(!schema.is_recursive("Leaf"));
+    assert!(!schema.is_recursive("Named"));
+    assert!(!schema.is_recursive("Query"));
+    assert!(!schema.is_recursive("Unit"));
+    assert!(!schema.is_recursive("Int"));
+    assert!(!schema.is_recursive("Undefined"));
+    // Introspection types describe themselves
+    assert!(schema.is_recursive("__Type"));
+}
+
+#[test]
+fn scalar_with_fields() {
+    let input = r#"
+scalar Date { year: Int }
+scalar Time
+extend scalar Time @specifiedBy(url: "https://example.com") { hour: Int }
+scalar Separate # note
+{ b }
+scalar Named
+query Q { a }
+type Query { a: Date, b: Time, c: Separate, d: Named }
+"#;
+    let errors = Schema::parse_and_validate(input, "schema.graphql")
+        .unwrap_err()
+        .errors;
+    let errors: Vec<_> = errors
+        .iter()
+        .map(|diagnostic| (diagnostic.error.code(), diagnostic.error.to_string()))
+        .collect();
+    assert_eq!(
+        errors,
+        [
+            (
+                "ScalarWithFields",
+                "scalar type `Date` cannot have fields".to_owned()
+            ),
+            (
+                "ScalarWithFields",
+                "scalar type `Time` cannot have fields".to_owned()
+            ),
+            (
+                "ScalarWithFields",
+                "scalar type `Separate` cannot have fields".to_owned()
+            ),
+            (
+                "ExecutableDefinition",
+                "a schema document must not contain an operation definition".to_owned()
+            ),
+        ]
+    );
+
+    let input = "scalar Crlf\r\n{ c }\r\ntype Query { a: Crlf }";
+    let errors = Schema::parse_and_validate(input, "schema.graphql")
+        .unwrap_err()
+        .errors;
+    let codes: Vec<_> = errors
+        .iter()
+        .map(|diagnostic| diagnostic.error.code())
+        .collect();
+    assert_eq!(codes, ["ScalarWithFields"]);
+
+    // Documents built in code are checked the same way
+    let mut document =
+        ast::Document::parse("scalar Built\ntype Query { a: Built }", "schema.graphql").unwrap();
+    let shorthand = ast::OperationDefinition {
+        operation_type: ast::OperationType::Query,
+        name: None,
+        variables: Vec::new(),
+        directives: Default::default(),
+        selection_set: Vec::new(),
+    };
+    document.definitions.insert(
+        1,
+        ast::Definition::OperationDefinition(Node::new(shorthand)),
+    );
+    let errors = document.to_schema().unwrap_err().errors;
+    let codes: Vec<_> = errors
+        .iter()
+        .map(|diagnostic| diagnostic.error.code())
+        .collect();
+    assert_eq!(codes, ["ScalarWithFields"]);
+
+    // In a mixed document, the selection set is a query shorthand
+    let input = "scalar Date { a }\ntype Query { a: Date }";
+    apollo_compiler::parse_mixed_validate(input, "mixed.graphql").unwrap();
+}
+
+#[test]
+fn duplicate_root_operations() {
+    let input = r#"schema {
+  query: A
+  mutation: A
+  query: B
+}
+extend schema { mutation: B }
+type A { a: Int }
+type B { b: Int }
+"#;
+    let errors = Schema::parse_and_validate(input, "schema.graphql")
+        .unwrap_err()
+        .errors;
+    let duplicates: Vec<_> = errors
+        .iter()
+        .map(|diagnostic| {
+            let location = diagnostic.get_line_column().unwrap();
+            (diagnostic.error.code(), location.line, location.column)
+        })
+        .collect();
+    assert_eq!(
+        duplicates,
+        [
+            ("DuplicateRootOperation", 4, 3),
+            ("DuplicateRootOperation", 6, 17)
+        ]
+    );
+    let report = errors.to_string();
+    assert!(
+        report.contains("previous definition of `query` here"),
+        "{report}"
+    );
+    assert!(report.contains("`query` redefined here"), "{report}");
+    assert!(
+        report.contains("previous definition of `mutation` here"),
+        "{report}"
+    );
+
+    // The same object type can be used for several root operations
+    let input = "schema { query: A, mutation: A, subscription: A } type A { a: Int }";
+    Schema::parse_and_validate(input, "schema.graphql").unwrap();
+}
+
+#[test]
+fn resolved_directive_arguments() {
+    use apollo_compiler::ast::DirectiveArgumentError;
+
+    let input = r#"
+directive @key(fields: String!, resolvable: Boolean = true, meta: Meta) repeatable on OBJECT
+input Meta { graph: Graph!, weight: Float = 1, labels: [String] }
+enum Graph { PRODUCTS REVIEWS }
+
+type Query { a: Int }
+type Product
+  @key(fields: "id", meta: { graph: REVIEWS, weight: 2, labels: "a" })
+  @key(fields: "upc", meta: { graph: PRODUCTS })
+  @key(fields: 1, resolvable: null, meta: { graph: USERS })
+  @key(resolvable: false, extra: 1)
+  @undefined
+{ id: ID }
+"#;
+    let schema = Schema::parse(input, "schema.graphql").unwrap();
+    let directives = &schema.types["Product"].directives();
+
+    let resolve = |index: usize| directives[index].resolved_arguments(&schema);
+    let serialized = |index: usize| -> Vec<(String, String)> {
+        resolve(index)
+            .unwrap()
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.serialize().no_indent().to_string()))
+            .collect()
+    };
+    assert_eq!(
+        serialized(0),
+        [
+            ("fields".to_owned(), r#""id""#.to_owned()),
+            ("resolvable".to_owned(), "true".to_owned()),
+            (
+                "meta".to_owned(),
+                r#"{graph: REVIEWS, weight: 2, labels: ["a"]}"#.to_owned()
+            ),
+        ]
+    );
+    // Default values are applied to omitted input object fields
+    assert_eq!(
+        serialized(1)[2],
+        ("meta".to_owned(), "{graph: PRODUCTS, weight: 1}".to_owned())
+    );
+
+    let messages = |index: usize| -> Vec<String> {
+        resolve(index)
+            .unwrap_err()
+            .iter()
+            .map(|error| error.to_string())
+            .collect()
+    };
+    assert_eq!(
+        messages(2),
+        [
+            "invalid value for argument `@key(fields:)`: expected value of type String!, found an integer",
+            "invalid value for argument `@key(meta:)`: value `USERS` does not exist on `Graph`",
+        ]
+    );
+    assert_eq!(
+        messages(3),
+        [
+            "the directive `@key` has no argument `extra`",
+            "the required argument `@key(fields:)` is not provided",
+        ]
+    );
+    assert_eq!(
+        resolve(4).unwrap_err(),
+        [DirectiveArgumentError::UndefinedDirective {
+            directive: apollo_compiler::name!("undefined")
+        }]
+    );
+}
@@ -0,0 +1,376 @@
+use apollo_compiler::ast::Definition;
+use apollo_compiler::ast::Directive;
+use apollo_compiler::ast::DirectiveDefinition;
+use apollo_compiler::ast::DirectiveLocation;
+use apollo_compiler::ast::Document;
+use apollo_compiler::ast::EnumTypeDefinition;
+use apollo_compiler::ast::EnumTypeExtension;
+use apollo_compiler::ast::EnumValueDefinition;
+use apollo_compiler::ast::Field;
+use apollo_compiler::ast::FieldDefinition;
+use apollo_compiler::ast::InputValueDefinition;
+use apollo_compiler::ast::ObjectTypeDefinition;
+use apollo_compiler::ast::ObjectTypeExtension;
+use apollo_compiler::ast::OperationDefinition;
+use apollo_compiler::ast::OperationType;
+use apollo_compiler::ast::SchemaDefinition;
+use apollo_compiler::ast::Selection;
+use apollo_compiler::ast::Value;
+use apollo_compiler::ast::VariableDefinition;
+use apollo_compiler::name;
+use apollo_compiler::ty;
+use apollo_compiler::Node;
+use apollo_compiler::Schema;
+
+#[test]
+fn multi_line_description_is_a_block_string() {
+    let def = ObjectTypeDefinition {
+        description: Some("The root query type.\nEntry point for reads.".into()),
+        name: name!("Query"),
+        implements_interfaces: Vec::new(),
+        directives: Default::default(),
+        fields: Vec::new(),
+    };
+    let expected = r#""""
+The root query type.
+Entry point for reads.
+"""
+type Query"#;
+    assert_eq!(def.to_string(), expected);
+
+    let doc = Document::parse(expected, "schema.graphql").unwrap();
+    let reparsed = doc.definitions[0].as_object_type_definition().unwrap();
+    assert_eq!(reparsed.description, def.description);
+}
+
+#[test]
+fn multi_line_description_block_string_is_indented() {
+    let input = r#"
+type Query {
+  "A field with\na long description"
+  field: Int
+}
+"#;
+    let doc = Document::parse(input, "schema.graphql").unwrap();
+    let expected = r#"type Query {
+  """
+  A field with
+  a long description
+  """
+  field: Int
+}
+"#;
+    assert_eq!(doc.to_string(), expected);
+}
+
+#[test]
+fn object_type_extension_round_trips() {
+    let def = ObjectTypeExtension {
+        name: name!("Query"),
+        implements_interfaces: vec![name!("Node")],
+        directives: Default::default(),
+        fields: vec![Node::new(FieldDefinition {
+            description: None,
+            name: name!("id"),
+            arguments: Vec::new(),
+            ty: ty!(ID!),
+            directives: Default::default(),
+        })],
+    };
+    let expected = "extend type Query implements Node {\n  id: ID!\n}";
+    assert_eq!(def.to_string(), expected);
+
+    let doc = Document::parse(expected, "schema.graphql").unwrap();
+    let reparsed = doc.definitions[0].as_object_type_extension().unwrap();
+    assert_eq!(**reparsed, def);
+}
+
+#[test]
+fn enum_type_extension_round_trips() {
+    let value = |value| {
+        Node::new(EnumValueDefinition {
+            description: None,
+            value,
+            directives: Default::default(),
+        })
+    };
+    let def = EnumTypeExtension {
+        name: name!("Color"),
+        directives: Default::default(),
+        values: vec![value(name!("CYAN")), value(name!("MAGENTA"))],
+    };
+    let expected = "extend enum Color {\n  CYAN\n  MAGENTA\n}";
+    assert_eq!(def.to_string(), expected);
+
+    let doc = Document::parse(expected, "schema.graphql").unwrap();
+    let reparsed = doc.definitions[0].as_enum_type_extension().unwrap();
+    assert_eq!(**reparsed, def);
+}
+
+#[test]
+fn directives_keep_source_order() {
+    let input = "type Query @c @a @b @a {\n  field: Int @z @y\n}\n";
+    let doc = Document::parse(input, "schema.graphql").unwrap();
+    assert_eq!(doc.to_string(), input);
+}
+
+#[test]
+fn dedup_non_repeatable_directives() {
+    let schema = Schema::parse(
+        r#"
+        directive @key(fields: String!) repeatable on OBJECT
+        directive @shareable on OBJECT
+        type Query { a: Int }
+        "#,
+        "schema.graphql",
+    )
+    .unwrap();
+    let doc = Document::parse(
+        r#"type Product @shareable @key(fields: "id") @shareable @key(fields: "sku") @custom @custom"#,
+        "product.graphql",
+    )
+    .unwrap();
+    let mut def = doc.definitions[0]
+        .as_object_type_definition()
+        .unwrap()
+        .clone();
+    def.make_mut().directives.dedup_non_repeatable(&schema);
+    assert_eq!(
+        def.to_string(),
+        r#"type Product @shareable @key(fields: "id") @key(fields: "sku") @custom @custom"#
+    );
+}
+
+#[test]
+fn nested_values() {
+    let value = Value::Object(vec![
+        (name!("a"), 1.into()),
+        (name!("b"), vec![Value::from(true), Value::Null].into()),
+        (
+            name!("c"),
+            Node::new(Value::Object(vec![(
+                name!("d"),
+                Node::new(Value::Enum(name!("RED"))),
+            )])),
+        ),
+        (name!("e"), Node::new(Value::List(Vec::new()))),
+        (name!("f"), Node::new(Value::Variable(name!("var")))),
+    ]);
+    assert_eq!(
+        value.serialize().no_indent().to_string(),
+        "{a: 1, b: [true, null], c: {d: RED}, e: [], f: $var}"
+    );
+}
+
+#[test]
+fn string_values_are_escaped() {
+    let value = Value::from(vec!["quote \" and \\ backslash", "bell\u{7}"]);
+    assert_eq!(
+        value.serialize().no_indent().to_string(),
+        r#"["quote \" and \\ backslash", "bell\u0007"]"#
+    );
+
+    // Multi-line strings are serialized as block strings
+    let value = Value::from("first line\nsecond line");
+    assert_eq!(value.to_string(), "\"\"\"\nfirst line\nsecond line\n\"\"\"");
+
+    let doc = Document::parse(format!("{{ f(arg: {value}) }}"), "query.graphql").unwrap();
+    let operation = doc.definitions[0].as_operation_definition().unwrap();
+    let field = operation.selection_set[0].as_field().unwrap();
+    assert_eq!(
+        field.arguments[0].value.as_str(),
+        Some("first line\nsecond line")
+    );
+}
+
+#[test]
+fn operation_definition_round_trips() {
+    let operation = OperationDefinition {
+        operation_type: OperationType::Query,
+        name: Some(name!("GetUser")),
+        variables: vec![Node::new(VariableDefinition {
+            name: name!("id"),
+            ty: Node::new(ty!(ID!)),
+            default_value: None,
+            directives: Default::default(),
+        })],
+        directives: Default::default(),
+        selection_set: vec![Selection::Field(Node::new(Field {
+            alias: Some(name!("author")),
+            name: name!("user"),
+            arguments: vec![(name!("id"), Node::new(Value::Variable(name!("id")))).into()],
+            directives: [Directive {
+                name: name!("include"),
+                arguments: vec![(name!("if"), true).into()],
+            }]
+            .into_iter()
+            .collect(),
+            selection_set: vec![Selection::Field(Node::new(Field {
+                alias: None,
+                name: name!("name"),
+                arguments: Vec::new(),
+                directives: Default::default(),
+                selection_set: Vec::new(),
+            }))],
+        }))],
+    };
+    let expected = r#"query GetUser($id: ID!) {
+  author: user(id: $id) @include(if: true) {
+    name
+  }
+}"#;
+    assert_eq!(operation.to_string(), expected);
+
+    let doc = Document::parse(expected, "query.graphql").unwrap();
+    assert_eq!(
+        doc.definitions[0].as_operation_definition(),
+        Some(&Node::new(operation))
+    );
+}
+
+#[test]
+fn directive_definition_round_trips() {
+    let directive_def = DirectiveDefinition {
+        description: Some("Attach metadata".into()),
+        name: name!("tag"),
+        arguments: vec![Node::new(InputValueDefinition {
+            description: None,
+            name: name!("name"),
+            ty: Node::new(ty!(String!)),
+            default_value: None,
+            directives: Default::default(),
+        })],
+        repeatable: true,
+        locations: vec![DirectiveLocation::Field, DirectiveLocation::Object],
+    };
+    let expected = r#""""Attach metadata"""
+directive @tag(name: String!) repeatable on FIELD | OBJECT"#;
+    assert_eq!(directive_def.to_string(), expected);
+
+    let doc = Document::parse(expected, "schema.graphql").unwrap();
+    assert_eq!(
+        doc.definitions[0].as_directive_definition(),
+        Some(&Node::new(directive_def))
+    );
+}
+
+#[test]
+fn schema_definition_round_trips() {
+    let schema_def = SchemaDefinition {
+        description: Some("Entry points".into()),
+        directives: [Directive {
+            name: name!("contact"),
+            arguments: Vec::new(),
+        }]
+        .into_iter()
+        .collect(),
+        root_operations: vec![
+            Node::new((OperationType::Query, name!("Query"))),
+            Node::new((OperationType::Mutation, name!("Mutation"))),
+        ],
+    };
+    let expected = r#""""Entry points"""
+schema @contact {
+  query: Query
+  mutation: Mutation
+}"#;
+    assert_eq!(schema_def.to_string(), expected);
+
+    let doc = Document::parse(expected, "schema.graphql").unwrap();
+    assert_eq!(
+        doc.definitions[0].as_schema_definition(),
+        Some(&Node::new(schema_def))
+    );
+}
+
+#[test]
+fn enum_type_definition_round_trips() {
+    let value = |name, description: Option<&str>, directives: Vec<Directive>| {
+        Node::new(EnumValueDefinition {
+            description: description.map(Into::into),
+            value: name,
+            directives: directives.into_iter().collect(),
+        })
+    };
+    let deprecated = Directive {
+        name: name!("deprecated"),
+        arguments: vec![(name!("reason"), "use HEX").into()],
+    };
+    let tag = Directive {
+        name: name!("tag"),
+        arguments: vec![(name!("name"), "palette").into()],
+    };
+
+    let mut doc = Document::new();
+    doc.definitions
+        .push(Definition::EnumTypeDefinition(Node::new(
+            EnumTypeDefinition {
+                description: Some("Colors of the palette".into()),
+                name: name!("Color"),
+                directives: [tag].into_iter().collect(),
+                values: vec![
+                    value(name!("RED"), Some("Pure red"), vec![deprecated]),
+                    value(name!("GREEN"), None, vec![]),
+                ],
+            },
+        )));
+    doc.definitions
+        .push(Definition::EnumTypeExtension(Node::new(
+            EnumTypeExtension {
+                name: name!("Color"),
+                directives: Default::default(),
+                values: vec![value(name!("BLUE"), None, vec![])],
+            },
+        )));
+
+    let expected = r#""""Colors of the palette"""
+enum Color @tag(name: "palette") {
+  """Pure red"""
+  RED @deprecated(reason: "use HEX")
+  GREEN
+}
+
+extend enum Color {
+  BLUE
+}
+"#;
+    assert_eq!(doc.to_string(), expected);
+
+    let parsed = Document::parse(expected, "schema.graphql").unwrap();
+    assert_eq!(parsed.definitions, doc.definitions);
+
+    // Values from the extension are part of the enum type in a `Schema`
+    let schema = Schema::parse(expected, "schema.graphql").unwrap();
+    let color = schema.get_enum("Color").unwrap();
+    let values: Vec<_> = color.values.keys().map(|name| name.as_str()).collect();
+    assert_eq!(values, ["RED", "GREEN", "BLUE"]);
+    assert!(color.values["RED"].directives.get("deprecated").is_some());
+}
+
+#[test]
+fn comment_round_trips() {
+    let doc = Document::parse("{ a }\n\ntype Query { a: Int }", "mixed.graphql").unwrap();
+    let comment = "First line\r\n\nThird line\rFourth line";
+
+    // A comment does not prevent using the query shorthand
+    let output = doc.serialize().comment(comment).to_string();
+    let expected = r#"# First line
+#
+# Third line
+# Fourth line
+{
+  a
+}
+
+type Query {
+  a: Int
+}
+"#;
+    assert_eq!(output, expected);
+    assert_eq!(Document::parse(output, "mixed.graphql").unwrap(), doc);
+
+    // The comment ends with a line break even on a single line
+    let output = doc.serialize().no_indent().comment("note").to_string();
+    assert_eq!(output, "# note\n{ a } type Query { a: Int }");
+    assert_eq!(Document::parse(output, "mixed.graphql").unwrap(), doc);
+}
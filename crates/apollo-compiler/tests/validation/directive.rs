@@ -0,0 +1,358 @@
+use apollo_compiler::name;
+use apollo_compiler::validation::ValidationOptions;
+use apollo_compiler::ExecutableDocument;
+use apollo_compiler::Schema;
+
+#[test]
+fn it_fails_validation_with_unknown_directive_argument() {
+    let input = r#"
+directive @tag(name: String!) on FIELD_DEFINITION
+
+type Query {
+  field: Int @tag(name: "a", other: true)
+}
+"#;
+    let errors = Schema::parse_and_validate(input, "schema.graphql")
+        .unwrap_err()
+        .errors
+        .to_string();
+    assert!(
+        errors.contains("the argument `other` is not supported by `@tag`"),
+        "{errors}"
+    );
+    assert_eq!(errors.matches("Error:").count(), 1, "{errors}");
+}
+
+#[test]
+fn it_fails_validation_with_missing_required_directive_argument() {
+    let input = r#"
+directive @tag(name: String!, weight: Int! = 1) on FIELD_DEFINITION
+
+type Query {
+  field: Int @tag
+  other: Int @tag(name: null)
+}
+"#;
+    let errors = Schema::parse_and_validate(input, "schema.graphql")
+        .unwrap_err()
+        .errors
+        .to_string();
+    assert_eq!(
+        errors
+            .matches("the required argument `@tag(name:)` is not provided")
+            .count(),
+        2,
+        "{errors}"
+    );
+    assert!(!errors.contains("`@tag(weight:)`"), "{errors}");
+}
+
+#[test]
+fn it_accepts_repeated_applications_of_repeatable_directive() {
+    let input = r#"
+directive @tag(name: String!) repeatable on FIELD_DEFINITION
+
+type Query {
+  field: Int @tag(name: "a") @tag(name: "b")
+}
+"#;
+    Schema::parse_and_validate(input, "schema.graphql").unwrap();
+}
+
+#[test]
+fn it_checks_arguments_of_each_repeated_application() {
+    let input = r#"
+directive @tag(name: String!) repeatable on FIELD_DEFINITION
+
+type Query {
+  field: Int @tag(name: "a") @tag(label: "b")
+}
+"#;
+    let errors = Schema::parse_and_validate(input, "schema.graphql")
+        .unwrap_err()
+        .errors
+        .to_string();
+    assert!(
+        errors.contains("the argument `label` is not supported by `@tag`"),
+        "{errors}"
+    );
+    assert!(
+        errors.contains("the required argument `@tag(name:)` is not provided"),
+        "{errors}"
+    );
+    assert!(!errors.contains("can only be used once"), "{errors}");
+}
+
+#[test]
+fn it_fails_validation_with_duplicate_directive_argument_definitions() {
+    let input = r#"
+directive @tag(name: String, name: Int) on FIELD_DEFINITION
+
+type Query {
+  field: Int
+}
+"#;
+    let errors = Schema::parse_and_validate(input, "schema.graphql")
+        .unwrap_err()
+        .errors
+        .to_string();
+    assert!(
+        errors.contains("the argument `@tag(name:)` is defined multiple times"),
+        "{errors}"
+    );
+    assert_eq!(errors.matches("Error:").count(), 1, "{errors}");
+}
+
+#[test]
+fn it_fails_validation_with_output_type_directive_argument() {
+    let input = r#"
+directive @tag(name: Query) on FIELD_DEFINITION
+
+type Query {
+  field: Int
+}
+"#;
+    let errors = Schema::parse_and_validate(input, "schema.graphql")
+        .unwrap_err()
+        .errors
+        .to_string();
+    assert!(
+        errors.contains("the argument `@tag(name:)` must be of an input type"),
+        "{errors}"
+    );
+    assert_eq!(errors.matches("Error:").count(), 1, "{errors}");
+}
+
+#[test]
+fn it_fails_validation_with_directive_applied_to_its_own_definition() {
+    let input = r#"
+directive @tag(name: String @tag) on FIELD_DEFINITION | ARGUMENT_DEFINITION
+
+type Query {
+  field: Int
+}
+"#;
+    let errors = Schema::parse_and_validate(input, "schema.graphql")
+        .unwrap_err()
+        .errors
+        .to_string();
+    assert!(
+        errors.contains("`tag` directive definition cannot reference itself"),
+        "{errors}"
+    );
+    assert_eq!(errors.matches("Error:").count(), 1, "{errors}");
+}
+
+#[test]
+fn it_accepts_configured_builtin_directives_without_definitions() {
+    let sdl = r#"
+type Query {
+  field: Int @tag(name: "a")
+  other: Int @undefined
+  third: Int @experimental(reason: "testing")
+}
+
+directive @tag(name: Int) on FIELD_DEFINITION
+"#;
+    let options = ValidationOptions::new().with_builtin_directives([
+        name!("tag"),
+        name!("experimental"),
+        name!("unused"),
+    ]);
+    let errors = Schema::parse(sdl, "schema.graphql")
+        .unwrap()
+        .validate_with(&options)
+        .unwrap_err()
+        .to_string();
+    // The explicit definition still applies
+    assert!(
+        errors.contains("expected value of type Int, found a string"),
+        "{errors}"
+    );
+    assert!(
+        errors.contains("cannot find directive `@undefined` in this document"),
+        "{errors}"
+    );
+    assert!(!errors.contains("experimental"), "{errors}");
+
+    let options = ValidationOptions::new().with_builtin_directives([name!("defer")]);
+    let (schema, _) = Schema::parse("type Query { field: Int }", "schema.graphql")
+        .unwrap()
+        .validate_with(&options)
+        .unwrap();
+    let document =
+        ExecutableDocument::parse(&schema, "{ ... @defer { field } }", "query.graphql").unwrap();
+    assert!(document.clone().validate(&schema).is_err());
+    document.validate_with(&schema, &options).unwrap();
+}
+
+#[test]
+fn it_validates_one_of_only_if_defined() {
+    let sdl = r#"
+type Query { product(by: ProductBy): Int }
+input ProductBy @oneOf { id: ID!, name: String }
+"#;
+    let errors = Schema::parse_and_validate(sdl, "schema.graphql")
+        .unwrap_err()
+        .errors;
+    let codes: Vec<_> = errors.iter().map(|error| error.error.code()).collect();
+    assert_eq!(codes, ["UndefinedDirective"], "{errors}");
+
+    let options = ValidationOptions::new().with_builtin_directives([name!("oneOf")]);
+    let (schema, _) = Schema::parse(sdl, "schema.graphql")
+        .unwrap()
+        .validate_with(&options)
+        .unwrap();
+    ExecutableDocument::parse_and_validate(
+        &schema,
+        r#"{ product(by: { id: "1", name: "a" }) }"#,
+        "query.graphql",
+    )
+    .unwrap();
+
+    let sdl = format!("directive @oneOf on INPUT_OBJECT\n{sdl}");
+    let errors = Schema::parse_and_validate(sdl, "schema.graphql")
+        .unwrap_err()
+        .errors;
+    let codes: Vec<_> = errors.iter().map(|error| error.error.code()).collect();
+    assert_eq!(codes, ["InvalidOneOfDefinition"], "{errors}");
+}
+
+#[test]
+fn it_fails_validation_with_duplicate_directives_at_every_location() {
+    let input = r#"
+directive @d on SCHEMA | SCALAR | OBJECT | FIELD_DEFINITION | ARGUMENT_DEFINITION | INTERFACE | UNION | ENUM | ENUM_VALUE | INPUT_OBJECT | INPUT_FIELD_DEFINITION | QUERY | FIELD | FRAGMENT_DEFINITION | FRAGMENT_SPREAD | INLINE_FRAGMENT | VARIABLE_DEFINITION
+directive @r repeatable on OBJECT | FIELD
+schema @d @d { query: Query }
+type Query @d @r @r { a(x: Int @d @d): Int @d @d }
+extend type Query @d
+scalar S @d @d
+interface I @d @d { a: Int }
+union U @d @d = Query
+enum E @d @d { A @d @d }
+input In @d @d { f: Int @d @d }
+query Q($v: Int @d @d) @d @d { a(x: $v) @d @d @r @r ... on Query @d @d { a(x: $v) } ...F @d @d }
+fragment F on Query @d @d { a(x: $v) }
+"#;
+    let errors = apollo_compiler::parse_mixed_validate(input, "schema.graphql").unwrap_err();
+    let duplicates: Vec<_> = errors
+        .iter()
+        .filter(|diag| {
+            diag.error.to_string()
+                == "non-repeatable directive d can only be used once per location"
+        })
+        .map(|diag| {
+            let location = diag.get_line_column().unwrap();
+            (location.line, location.column)
+        })
+        .collect();
+    // One diagnostic per location, pointing at the second application
+    assert_eq!(
+        duplicates,
+        [
+            (4, 11),
+            (5, 35),
+            (5, 47),
+            (6, 19),
+            (7, 13),
+            (8, 16),
+            (9, 12),
+            (10, 11),
+            (10, 21),
+            (11, 13),
+            (11, 28),
+            (12, 20),
+            (12, 27),
+            (12, 44),
+            (12, 69),
+            (12, 93),
+            (13, 24),
+        ]
+    );
+    assert_eq!(errors.len(), duplicates.len(), "{errors}");
+}
+
+#[test]
+fn it_validates_skip_and_include_arguments() {
+    let schema =
+        Schema::parse_and_validate("type Query { a: Int, b: Query }", "schema.graphql").unwrap();
+    let input = r#"
+query Valid($skip: Boolean!, $include: Boolean = true) {
+  a @skip(if: $skip)
+  b @include(if: $include) { a }
+  ...A @skip(if: false)
+  ... @include(if: true) { a }
+}
+
+query Invalid($string: String, $nullable: Boolean) @skip(if: true) {
+  a @skip
+  b @include(if: "yes") { a }
+  x: a @skip(if: $string)
+  y: a @include(if: true, unless: false)
+  ...A @include
+  ...B @skip(if: 1)
+  ... @include(if: $nullable) { a }
+}
+
+fragment A on Query { a }
+fragment B on Query { a }
+"#;
+    let errors = ExecutableDocument::parse_and_validate(&schema, input, "query.graphql")
+        .unwrap_err()
+        .errors;
+    let messages: Vec<_> = errors.iter().map(|error| error.error.to_string()).collect();
+    assert_eq!(
+        messages,
+        [
+            "skip directive is not supported for QUERY location",
+            "the required argument `@skip(if:)` is not provided",
+            "expected value of type Boolean!, found a string",
+            "variable `$string` of type `String` cannot be used for argument `if` of type `Boolean!`",
+            "the argument `unless` is not supported by `@include`",
+            "the required argument `@include(if:)` is not provided",
+            "expected value of type Boolean!, found an integer",
+            "variable `$nullable` of type `Boolean` cannot be used for argument `if` of type `Boolean!`",
+        ],
+        "{errors}"
+    );
+}
+
+#[test]
+fn it_lists_allowed_locations_for_misplaced_type_system_directives() {
+    let input = r#"
+directive @onSchema on SCHEMA
+
+type Query @deprecated {
+  field: Int @onSchema
+}
+"#;
+    let errors = Schema::parse_and_validate(input, "schema.graphql")
+        .unwrap_err()
+        .errors;
+    let codes: Vec<_> = errors
+        .iter()
+        .map(|diagnostic| (diagnostic.error.code(), diagnostic.error.to_string()))
+        .collect();
+    assert_eq!(
+        codes,
+        [
+            (
+                "UnsupportedLocation",
+                "deprecated directive is not supported for OBJECT location".to_string()
+            ),
+            (
+                "UnsupportedLocation",
+                "onSchema directive is not supported for FIELD_DEFINITION location".to_string()
+            ),
+        ]
+    );
+
+    let errors = errors.to_string();
+    assert!(
+        errors.contains(
+            "declared support for: FIELD_DEFINITION, ARGUMENT_DEFINITION, INPUT_FIELD_DEFINITION, ENUM_VALUE"
+        ),
+        "{errors}"
+    );
+    assert!(errors.contains("declared support for: SCHEMA"), "{errors}");
+}
@@ -209,3 +209,41 @@ scalar Url @specifiedBy(url: "https://tools.ietf.org/html/rfc3986")
         "{errors}"
     );
 }
+
+#[test]
+fn it_fails_validation_with_cyclic_interface_implementations() {
+    let input = r#"
+type Query {
+  node: Node
+}
+
+interface Node implements Node {
+  id: ID!
+}
+
+interface A implements B & C {
+  id: ID!
+}
+
+interface B implements C & A {
+  id: ID!
+}
+
+interface C {
+  id: ID!
+}
+"#;
+    let errors = Schema::parse_and_validate(input, "schema.graphql")
+        .unwrap_err()
+        .errors;
+    let messages: Vec<_> = errors.iter().map(|error| error.error.to_string()).collect();
+    assert_eq!(
+        messages,
+        [
+            "interface Node cannot implement itself",
+            "interface A cannot implement itself through other interfaces",
+            "interface B cannot implement itself through other interfaces",
+        ],
+        "{errors}"
+    );
+}
@@ -1,3 +1,4 @@
+mod directive;
 mod interface;
 mod object;
 mod operation;
@@ -274,3 +275,217 @@ fn validate_variable_usage_without_type_system() {
     let doc = ast::Document::parse(input, "query.graphql").unwrap();
     doc.validate_standalone_executable().unwrap()
 }
+
+#[test]
+fn downgrade_diagnostics_to_warnings() {
+    use apollo_compiler::validation::Severity;
+    use apollo_compiler::validation::ValidationOptions;
+
+    let input = r#"
+type Query {
+  a: Int @unknown
+  b: Undefined
+}
+"#;
+    let schema = Schema::parse(input, "schema.graphql").unwrap();
+    let errors = schema.clone().validate().unwrap_err().errors;
+    assert_eq!(errors.errors().count(), 2);
+    assert_eq!(errors.warnings().count(), 0);
+
+    let options = ValidationOptions::new().severity("UndefinedDirective", Severity::Warning);
+    let errors = schema.clone().validate_with(&options).unwrap_err().errors;
+    assert!(errors.has_errors());
+    assert_eq!(errors.errors().count(), 1);
+    assert_eq!(errors.warnings().count(), 1);
+    assert_eq!(errors.len(), 2);
+
+    let warning = errors.warnings().next().unwrap();
+    assert_eq!(warning.error.severity(), Severity::Warning);
+    let rendered = warning.to_string();
+    assert!(
+        rendered.starts_with("Warning: cannot find directive `@unknown`"),
+        "{rendered}"
+    );
+    let error = errors.errors().next().unwrap().to_string();
+    assert!(
+        error.starts_with("Error: cannot find type `Undefined`"),
+        "{error}"
+    );
+
+    let options = options.severity("UndefinedDefinition", Severity::Warning);
+    let (_schema, warnings) = schema.validate_with(&options).unwrap();
+    assert!(!warnings.has_errors());
+    assert_eq!(warnings.warnings().count(), 2);
+}
+
+#[test]
+fn downgrade_executable_diagnostics_to_warnings() {
+    use apollo_compiler::validation::Severity;
+    use apollo_compiler::validation::ValidationOptions;
+
+    let schema = Schema::parse_and_validate("type Query { a: Int }", "schema.graphql").unwrap();
+    let input = "query { a } fragment Unused on Query { a }";
+    let document = ExecutableDocument::parse(&schema, input, "query.graphql").unwrap();
+    let errors = document.clone().validate(&schema).unwrap_err().errors;
+    assert_eq!(errors.errors().count(), 1);
+
+    let options = ValidationOptions::new().severity("UnusedFragment", Severity::Warning);
+    let (_document, warnings) = document.validate_with(&schema, &options).unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings.warnings().count(), 1);
+}
+
+#[test]
+fn diagnostic_codes() {
+    use apollo_compiler::validation::DiagnosticCategory;
+
+    let input = r#"
+type Query {
+  a: Int @unknown
+}
+type Query {
+  b: Int
+}
+type Query2 {
+"#;
+    let errors = Schema::parse_and_validate(input, "schema.graphql")
+        .unwrap_err()
+        .errors;
+    let codes: Vec<_> = errors
+        .iter()
+        .map(|diagnostic| (diagnostic.error.code(), diagnostic.error.category()))
+        .collect();
+    assert_eq!(
+        codes,
+        [
+            ("UndefinedDirective", DiagnosticCategory::Validation),
+            ("TypeDefinitionCollision", DiagnosticCategory::Build),
+            ("EmptyFieldSet", DiagnosticCategory::Validation),
+            ("SyntaxError", DiagnosticCategory::Syntax),
+        ]
+    );
+
+    let schema = Schema::parse_and_validate("type Query { a: Int }", "schema.graphql").unwrap();
+    let errors = ExecutableDocument::parse_and_validate(&schema, "{ b }", "query.graphql")
+        .unwrap_err()
+        .errors;
+    let codes: Vec<_> = errors
+        .iter()
+        .map(|diagnostic| (diagnostic.error.code(), diagnostic.error.category()))
+        .collect();
+    assert_eq!(codes, [("UndefinedField", DiagnosticCategory::Build)]);
+}
+
+#[test]
+fn location_line_column_in_source_file() {
+    for newline in ["\n", "\r\n", "\r"] {
+        let input = format!("# caf\u{e9}{newline}{newline}  {{ a }}");
+        let doc = ast::Document::parse(&input, "query.graphql").unwrap();
+        let source = doc.sources.values().next().unwrap();
+        let location = doc.definitions[0].location().unwrap();
+        let expected = GraphQLLocation { line: 3, column: 3 };
+        assert_eq!(
+            location.line_column_in(source),
+            Some(expected),
+            "{newline:?}"
+        );
+        // Consistent with diagnostics, which look up the source file by ID
+        assert_eq!(
+            GraphQLLocation::from_node(&doc.sources, Some(location)),
+            Some(expected),
+            "{newline:?}"
+        );
+    }
+    let doc = ast::Document::parse("{ a }", "query.graphql").unwrap();
+    let source = doc.sources.values().next().unwrap();
+    let location = doc.definitions[0].location().unwrap();
+    assert_eq!(
+        location.line_column_in(source),
+        Some(GraphQLLocation { line: 1, column: 1 })
+    );
+    let field = doc.definitions[0]
+        .as_operation_definition()
+        .unwrap()
+        .selection_set[0]
+        .as_field()
+        .unwrap();
+    let location = field.name.location().unwrap();
+    assert_eq!(
+        location.line_column_in(source),
+        Some(GraphQLLocation { line: 1, column: 3 })
+    );
+    let truncated = ast::Document::parse("{", "query.graphql")
+        .unwrap_err()
+        .partial;
+    let truncated = truncated.sources.values().next().unwrap();
+    assert_eq!(location.line_column_in(truncated), None);
+}
+
+#[test]
+fn excessive_type_nesting() {
+    use apollo_compiler::validation::ValidationOptions;
+
+    let sdl = r#"
+type Query {
+  ok(arg: [[Int]]): [[Int]]
+  tooDeep: [[[Int]]]
+  tooDeepArg(arg: [[[Int!]!]!]!): Int
+}
+
+input Input {
+  tooDeep: [[[[String]]]]
+}
+"#;
+    // No limit by default
+    Schema::parse_and_validate(sdl, "schema.graphql").unwrap();
+
+    let options = ValidationOptions::new().max_type_nesting(2);
+    let schema = Schema::parse(sdl, "schema.graphql").unwrap();
+    let errors = schema.validate_with(&options).unwrap_err().errors;
+    let diagnostics: Vec<_> = errors
+        .iter()
+        .map(|error| {
+            let location = error.get_line_column().unwrap();
+            (location.line, location.column, error.error.to_string())
+        })
+        .collect();
+    assert_eq!(
+        diagnostics,
+        [
+            (
+                4,
+                3,
+                "type `[[[Int]]]` has 3 nested lists, more than the limit of 2".to_string()
+            ),
+            (
+                5,
+                19,
+                "type `[[[Int!]!]!]!` has 3 nested lists, more than the limit of 2".to_string()
+            ),
+            (
+                9,
+                12,
+                "type `[[[[String]]]]` has 4 nested lists, more than the limit of 2".to_string()
+            ),
+        ],
+        "{errors}"
+    );
+
+    let (schema, _) = Schema::parse("type Query { a(arg: [[Int]]): Int }", "schema.graphql")
+        .unwrap()
+        .validate_with(&options)
+        .unwrap();
+    let query = "query ($ok: [[Int]], $tooDeep: [[[Int]]]) { a(arg: $ok) }";
+    let document = ExecutableDocument::parse(&schema, query, "query.graphql").unwrap();
+    let errors = document.clone().validate(&schema).unwrap_err().errors;
+    assert!(!errors.to_string().contains("nested lists"), "{errors}");
+    let errors = document
+        .validate_with(&schema, &options)
+        .unwrap_err()
+        .errors
+        .to_string();
+    assert!(
+        errors.contains("type `[[[Int]]]` has 3 nested lists, more than the limit of 2"),
+        "{errors}"
+    );
+}
@@ -1,4 +1,5 @@
 use apollo_compiler::parse_mixed_validate;
+use apollo_compiler::Schema;
 
 #[test]
 fn it_generates_diagnostics_for_non_output_field_types() {
@@ -65,3 +66,100 @@ scalar Url @specifiedBy(url: "https://tools.ietf.org/html/rfc3986")
         "{errors}"
     );
 }
+
+#[test]
+fn it_checks_input_and_output_positions_through_wrapper_types() {
+    let input = r#"
+type Query {
+  points: [Point2D!]!
+  nested(filter: [[Person!]]!): Int
+  search(kind: SearchResult!): Int
+  entity(kind: [NamedEntity]): Int
+}
+
+interface NamedEntity {
+  name: String
+  related(to: [NamedEntity!]): String
+}
+
+type Person implements NamedEntity {
+  name: String
+  related(to: [NamedEntity!]): String
+}
+
+union SearchResult = Person
+
+input Point2D {
+  x: Float
+  y: Float
+  owner: [Person!]!
+}
+"#;
+    let errors = Schema::parse_and_validate(input, "schema.graphql")
+        .unwrap_err()
+        .errors;
+    let messages: Vec<_> = errors.iter().map(|error| error.error.to_string()).collect();
+    assert_eq!(
+        messages,
+        [
+            "`points` field must return an output type",
+            "`filter` field must be of an input type",
+            "`kind` field must be of an input type",
+            "`kind` field must be of an input type",
+            "`to` field must be of an input type",
+            "`to` field must be of an input type",
+            "`owner` field must be of an input type",
+        ],
+        "{errors}"
+    );
+}
+
+#[test]
+fn it_fails_validation_with_duplicate_argument_definitions() {
+    let input = r#"
+type Query {
+  field(a: Int, a: String): Int
+}
+
+interface Node {
+  id(a: Int, b: Int, a: Int): ID
+}
+
+extend type Query {
+  other(b: Int, b: Int): Int
+}
+
+directive @dir(a: Int, a: String) on FIELD
+"#;
+    let errors = Schema::parse_and_validate(input, "schema.graphql")
+        .unwrap_err()
+        .errors;
+    let duplicates: Vec<_> = errors
+        .iter()
+        .map(|diagnostic| {
+            let location = diagnostic.get_line_column().unwrap();
+            (diagnostic.error.code(), location.line, location.column)
+        })
+        .collect();
+    assert_eq!(
+        duplicates,
+        [
+            ("UniqueInputValue", 3, 9),
+            ("UniqueInputValue", 7, 6),
+            ("UniqueInputValue", 11, 9),
+            ("UniqueDirectiveArgumentDefinition", 14, 16),
+        ]
+    );
+
+    // Both definitions are labelled
+    let errors = errors.to_string();
+    assert!(
+        errors.contains("previous definition of `a` here"),
+        "{errors}"
+    );
+    assert!(errors.contains("`a` defined again here"), "{errors}");
+    assert!(
+        errors.contains("`a` must only be defined once in the arguments of `@dir`."),
+        "{errors}"
+    );
+}
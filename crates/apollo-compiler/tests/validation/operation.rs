@@ -35,10 +35,15 @@ type Cat {
     let errors = parse_mixed_validate(input, "schema.graphql")
         .unwrap_err()
         .to_string();
+    assert_eq!(
+        errors
+            .matches("anonymous operation must be the only operation in the document")
+            .count(),
+        1,
+        "{errors}"
+    );
     assert!(
-        errors.contains(
-            "anonymous operation cannot be selected when the document contains other operations"
-        ),
+        errors.contains("conflicting operation `getOtherPet` defined here"),
         "{errors}"
     );
 }
@@ -212,3 +217,123 @@ type Product {
         "{errors}"
     );
 }
+
+#[test]
+fn it_validates_fields_in_fragments_and_meta_fields() {
+    let input = r#"
+query {
+  __typename
+  __schema { description }
+  __type(name: "Pet") { name }
+  pet {
+    __typename
+    __schema { description }
+    ...petFields
+    ... on Dog { bark purr }
+    ... on Cat { purr }
+  }
+}
+
+fragment petFields on Pet {
+  name
+  bark
+  __type(name: "Dog") { name }
+}
+
+type Query {
+  pet: Pet
+}
+
+interface Pet {
+  name: String
+}
+
+type Dog implements Pet {
+  name: String
+  bark: String
+}
+
+type Cat implements Pet {
+  name: String
+  purr: String
+}
+"#;
+
+    let errors = parse_mixed_validate(input, "schema.graphql")
+        .unwrap_err()
+        .to_string();
+    let undefined: Vec<_> = errors
+        .lines()
+        .filter(|line| line.starts_with("Error: "))
+        .collect();
+    assert_eq!(
+        undefined,
+        [
+            "Error: type `Pet` does not have a field `__schema`",
+            "Error: type `Dog` does not have a field `purr`",
+            "Error: type `Pet` does not have a field `bark`",
+            "Error: type `Pet` does not have a field `__type`",
+        ],
+        "{errors}"
+    );
+}
+
+#[test]
+fn it_fails_validation_with_impossible_fragment_spreads() {
+    let input = r#"
+query {
+  pet {
+    ... on Dog { name }
+    ... on Robot { model }
+    ...catFields
+  }
+  dog {
+    ... on Pet { name }
+    ... on Cat { name }
+    ... on CatOrDog { __typename }
+    ... on Machine { __typename }
+  }
+  catOrDog {
+    ... on Dog { name }
+    ... on Robot { model }
+  }
+}
+
+fragment catFields on Cat { name }
+
+type Query {
+  pet: Pet
+  dog: Dog
+  catOrDog: CatOrDog
+}
+
+interface Pet { name: String }
+interface Machine { model: String }
+type Dog implements Pet { name: String }
+type Cat implements Pet { name: String }
+type Robot implements Machine { model: String }
+union CatOrDog = Cat | Dog
+"#;
+    let errors = parse_mixed_validate(input, "schema.graphql").unwrap_err();
+    let impossible: Vec<_> = errors
+        .iter()
+        .map(|diagnostic| {
+            let location = diagnostic.get_line_column().unwrap();
+            (diagnostic.error.code(), location.line)
+        })
+        .collect();
+    assert_eq!(
+        impossible,
+        [
+            // Interface to object
+            ("InvalidFragmentSpread", 5),
+            // Object to object
+            ("InvalidFragmentSpread", 10),
+            // Object to interface
+            ("InvalidFragmentSpread", 12),
+            // Union to member
+            ("InvalidFragmentSpread", 16),
+        ],
+        "{errors}"
+    );
+}
@@ -176,3 +176,54 @@ fn not_long_enough_input_object_chain_applies_correctly() {
     let _schema = apollo_compiler::Schema::parse_and_validate(schema, "input_objects.graphql")
         .expect("must not have recursion errors");
 }
+
+#[test]
+fn self_referencing_non_null_input_object() {
+    let schema = r#"
+      type Query {
+        field(arg: A): Boolean
+      }
+
+      input A {
+        a: A!
+      }
+    "#;
+
+    let partial = apollo_compiler::Schema::parse_and_validate(schema, "input_cycle.graphql")
+        .expect_err("must have a cycle error");
+
+    let expected = expect_test::expect![[r#"
+        Error: `A` input object cannot reference itself
+           ╭─[input_cycle.graphql:6:7]
+           │
+         6 │ ╭─▶       input A {
+         7 │ │           a: A!
+           │ │           ──┬──  
+           │ │             ╰──── `A` circularly references `A` here
+         8 │ ├─▶       }
+           │ │             
+           │ ╰───────────── cyclical input object definition
+        ───╯
+    "#]];
+    expected.assert_eq(&partial.errors.to_string());
+}
+
+#[test]
+fn nullable_or_list_input_object_self_reference_is_allowed() {
+    let schema = r#"
+      type Query {
+        field(b: B, c: C): Boolean
+      }
+
+      input B {
+        b: B
+      }
+
+      input C {
+        c: [C!]!
+      }
+    "#;
+
+    let _schema = apollo_compiler::Schema::parse_and_validate(schema, "input_cycle.graphql")
+        .expect("must not have cycle errors");
+}
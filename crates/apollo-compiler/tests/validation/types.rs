@@ -949,18 +949,22 @@ mod invalid_enum_values {
         }
       "#,
             expect![[r#"
-                Error: expected value of type DogCommand, found a string
+                Error: expected a value of enum `DogCommand`, found the string "SIT"
                     ╭─[query.graphql:3:33]
                     │
                   3 │     doesKnowCommand(dogCommand: "SIT")
                     │                                 ──┬──  
-                    │                                   ╰──── provided value is a string
+                    │                                   ╰──── enum values are not strings
                     │
-                    ├─[schema.graphql:27:31]
+                    ├─[schema.graphql:16:1]
                     │
-                 27 │   doesKnowCommand(dogCommand: DogCommand): Boolean
-                    │                               ─────┬────  
-                    │                                    ╰────── expected type declared here as DogCommand
+                 16 │ ╭─▶ enum DogCommand {
+                    ┆ ┆   
+                 20 │ ├─▶ }
+                    │ │       
+                    │ ╰─────── enum defined here
+                    │     
+                    │     Help: remove the quotes: `SIT`
                 ────╯
             "#]],
         );
@@ -1668,6 +1672,70 @@ mod invalid_input_object_values {
         );
     }
 
+    #[test]
+    fn object_missing_required_and_unknown_fields() {
+        expect_errors(
+            r#"
+        {
+          complicatedArgs {
+            complexArgField(complexArg: {
+              intField: 4,
+              unknownA: 1,
+              unknownB: 2
+            })
+          }
+        }
+      "#,
+            expect![[r#"
+                Error: the required field `ComplexInput.requiredField` is not provided
+                    ╭─[query.graphql:3:33]
+                    │
+                  3 │ ╭─▶     complexArgField(complexArg: {
+                    ┆ ┆   
+                  7 │ ├─▶     })
+                    │ │            
+                    │ ╰──────────── missing value for field `requiredField`
+                    │
+                    ├─[schema.graphql:60:3]
+                    │
+                 60 │   requiredField: Boolean!
+                    │   ───────────┬───────────  
+                    │              ╰───────────── field defined here
+                ────╯
+                Error: field `unknownA` does not exist on `ComplexInput`
+                    ╭─[query.graphql:5:7]
+                    │
+                  5 │       unknownA: 1,
+                    │       ─────┬─────  
+                    │            ╰─────── value does not exist on `ComplexInput` input object
+                    │
+                    ├─[schema.graphql:59:1]
+                    │
+                 59 │ ╭─▶ input ComplexInput {
+                    ┆ ┆   
+                 66 │ ├─▶ }
+                    │ │       
+                    │ ╰─────── input object defined here
+                ────╯
+                Error: field `unknownB` does not exist on `ComplexInput`
+                    ╭─[query.graphql:6:7]
+                    │
+                  6 │       unknownB: 2
+                    │       ─────┬─────  
+                    │            ╰─────── value does not exist on `ComplexInput` input object
+                    │
+                    ├─[schema.graphql:59:1]
+                    │
+                 59 │ ╭─▶ input ComplexInput {
+                    ┆ ┆   
+                 66 │ ├─▶ }
+                    │ │       
+                    │ ╰─────── input object defined here
+                ────╯
+            "#]],
+        );
+    }
+
     #[test]
     fn custom_scalar_accept_complex_literals() {
         use apollo_compiler::ExecutableDocument;
@@ -1828,23 +1896,23 @@ mod variable_default_values {
         }
       ",
             expect![[r#"
-                Error: expected value of type Int!, found null
+                Error: non-null type Int! cannot have a default value of null
                    ╭─[query.graphql:2:14]
                    │
                  2 │   $a: Int! = null,
                    │       ──┬─   ──┬─  
-                   │         ╰────────── expected type declared here as Int!
+                   │         ╰────────── type declared here as Int!
                    │                │   
-                   │                ╰─── provided value is null
+                   │                ╰─── invalid default value
                 ───╯
-                Error: expected value of type String!, found null
+                Error: non-null type String! cannot have a default value of null
                    ╭─[query.graphql:3:17]
                    │
                  3 │   $b: String! = null,
                    │       ───┬───   ──┬─  
-                   │          ╰──────────── expected type declared here as String!
+                   │          ╰──────────── type declared here as String!
                    │                   │   
-                   │                   ╰─── provided value is null
+                   │                   ╰─── invalid default value
                 ───╯
                 Error: the required field `ComplexInput.requiredField` is not provided
                     ╭─[query.graphql:4:22]
@@ -1,4 +1,10 @@
+use apollo_compiler::ast;
+use apollo_compiler::name;
 use apollo_compiler::parse_mixed_validate;
+use apollo_compiler::schema::ExtendedType;
+use apollo_compiler::ExecutableDocument;
+use apollo_compiler::Node;
+use apollo_compiler::Schema;
 
 #[test]
 fn it_raises_undefined_variable_in_query_error() {
@@ -132,3 +138,119 @@ type Product {
         "{errors}"
     );
 }
+
+#[test]
+fn it_lists_required_variables_and_validates_defaults() {
+    let schema = Schema::parse_and_validate(
+        "type Query { field(a: Int, b: Int, c: [Int], d: Int): Int }",
+        "schema.graphql",
+    )
+    .unwrap();
+
+    let input = r#"
+query($a: Int!, $b: Int! = 1, $c: [Int!]!, $d: Int) {
+  field(a: $a, b: $b, c: $c, d: $d)
+}
+"#;
+    let document = ExecutableDocument::parse_and_validate(&schema, input, "query.graphql").unwrap();
+    let operation = document.get_operation(None).unwrap();
+    assert_eq!(operation.required_variables(), ["a", "c"]);
+
+    let input = r#"
+query($a: Int! = "str", $b: Int! = null) {
+  field(a: $a, b: $b)
+}
+"#;
+    let invalid =
+        ExecutableDocument::parse_and_validate(&schema, input, "query.graphql").unwrap_err();
+    let messages: Vec<_> = invalid
+        .errors
+        .iter()
+        .map(|error| error.error.to_string())
+        .collect();
+    assert_eq!(
+        messages,
+        [
+            "expected value of type Int!, found a string",
+            "non-null type Int! cannot have a default value of null",
+        ]
+    );
+    let codes: Vec<_> = invalid
+        .errors
+        .iter()
+        .map(|error| error.error.code())
+        .collect();
+    assert_eq!(codes, ["UnsupportedValueType", "InvalidDefaultValue"]);
+    // Variables with a default value are not required, even if the default is invalid
+    let operation = invalid.partial.get_operation(None).unwrap();
+    assert!(operation.required_variables().is_empty());
+}
+
+#[test]
+fn it_raises_variables_in_default_values() {
+    // The parser rejects variables in default values, but they can be added programmatically
+    let mut schema = Schema::parse(
+        r#"
+type Query { field(a: Int = 1): Int }
+input Filter { limits: [Int] = [1] }
+"#,
+        "schema.graphql",
+    )
+    .unwrap();
+    let variable = Node::new(ast::Value::Variable(name!("x")));
+    let ExtendedType::Object(query) = &mut schema.types["Query"] else {
+        panic!("expected an object type")
+    };
+    let field = query.make_mut().fields["field"].make_mut();
+    field.arguments[0].make_mut().default_value = Some(variable.clone());
+    let ExtendedType::InputObject(filter) = &mut schema.types["Filter"] else {
+        panic!("expected an input object type")
+    };
+    let limits = filter.make_mut().fields["limits"].make_mut();
+    limits.default_value = Some(Node::new(ast::Value::List(vec![variable.clone()])));
+
+    let invalid = schema.validate().unwrap_err();
+    let codes: Vec<_> = invalid
+        .errors
+        .iter()
+        .map(|error| error.error.code())
+        .collect();
+    assert_eq!(codes, ["InvalidDefaultValue", "InvalidDefaultValue"]);
+    let messages: Vec<_> = invalid
+        .errors
+        .iter()
+        .map(|error| error.error.to_string())
+        .collect();
+    assert_eq!(
+        messages,
+        [
+            "variable `$x` cannot be used in the default value of type Int",
+            "variable `$x` cannot be used in the default value of type [Int]",
+        ]
+    );
+
+    let schema =
+        Schema::parse_and_validate("type Query { field(a: Int): Int }", "schema.graphql").unwrap();
+    let mut document = ExecutableDocument::parse(
+        &schema,
+        "query($a: Int = 1, $b: Int) { field(a: $a) field2: field(a: $b) }",
+        "query.graphql",
+    )
+    .unwrap();
+    let operation = document.anonymous_operation.as_mut().unwrap().make_mut();
+    operation.variables[0].make_mut().default_value =
+        Some(Node::new(ast::Value::Variable(name!("b"))));
+
+    let invalid = document.validate(&schema).unwrap_err();
+    let codes: Vec<_> = invalid
+        .errors
+        .iter()
+        .map(|error| error.error.code())
+        .collect();
+    assert_eq!(codes, ["InvalidDefaultValue"]);
+    let message = invalid.errors.iter().next().unwrap().error.to_string();
+    assert_eq!(
+        message,
+        "variable `$b` cannot be used in the default value of type Int"
+    );
+}
@@ -33,6 +33,17 @@ fn bench_supergraph_parser(c: &mut Criterion) {
     });
 }
 
+fn bench_supergraph_check(c: &mut Criterion) {
+    let schema = include_str!("../test_data/parser/ok/0032_supergraph.graphql");
+
+    c.bench_function("supergraph_check", move |b| {
+        b.iter(|| {
+            let errors = apollo_parser::Parser::new(schema).check();
+            assert!(errors.is_empty(), "error parsing query: {errors:?}");
+        })
+    });
+}
+
 fn bench_supergraph_lexer(c: &mut Criterion) {
     let schema = include_str!("../test_data/parser/ok/0032_supergraph.graphql");
 
@@ -47,5 +58,10 @@ fn bench_supergraph_lexer(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, bench_supergraph_lexer, bench_supergraph_parser);
+criterion_group!(
+    benches,
+    bench_supergraph_lexer,
+    bench_supergraph_parser,
+    bench_supergraph_check
+);
 criterion_main!(benches);
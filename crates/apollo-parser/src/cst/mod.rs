@@ -107,7 +107,7 @@ mod node_ext;
 
 use std::marker::PhantomData;
 
-use crate::{SyntaxKind, SyntaxNodeChildren, SyntaxToken};
+use crate::{SyntaxKind, SyntaxNodeChildren, SyntaxToken, TextRange};
 
 pub use crate::{parser::SyntaxNodePtr, SyntaxNode};
 
@@ -164,6 +164,57 @@ pub trait CstToken {
     }
 }
 
+/// A child node required by the grammar that is missing from the tree.
+///
+/// This is returned by the `try_*` getters, like [`FieldDefinition::try_ty`], which are
+/// alternatives to the `Option`-returning getters for children that are not optional
+/// in the grammar.
+///
+/// In a tree parsed without errors, these children are always present. When the parser
+/// recovers from an error, it keeps the partial node instead, so any required child may be
+/// missing: for example the `Name` of `type { a: Int }`, the `Type` of `type Query { a: }`,
+/// or the `Value` of `{ f(a: ) }`.
+///
+/// ```rust
+/// use apollo_parser::{cst, Parser};
+///
+/// let cst = Parser::new("type Query { a: }").parse();
+/// assert_eq!(cst.errors().len(), 1);
+/// let Some(cst::Definition::ObjectTypeDefinition(object)) = cst.document().definitions().next()
+/// else {
+///     panic!("expected an object type")
+/// };
+/// let field = object.fields_definition().unwrap().field_definitions().next().unwrap();
+/// assert_eq!(field.try_name().unwrap().text(), "a");
+/// let error = field.try_ty().unwrap_err();
+/// assert_eq!(error.to_string(), "missing Type in FIELD_DEFINITION");
+/// assert_eq!(u32::from(error.range().start()), 13);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error)]
+#[error("missing {expected} in {parent:?}")]
+pub struct MissingNode {
+    parent: SyntaxKind,
+    expected: &'static str,
+    range: TextRange,
+}
+
+impl MissingNode {
+    /// The kind of the node whose child is missing.
+    pub fn parent_kind(&self) -> SyntaxKind {
+        self.parent
+    }
+
+    /// The name of the missing node in the grammar, such as `Name` or `Type`.
+    pub fn expected(&self) -> &'static str {
+        self.expected
+    }
+
+    /// The text range of the node whose child is missing.
+    pub fn range(&self) -> TextRange {
+        self.range
+    }
+}
+
 /// An iterator over `SyntaxNode` children of a particular CST type.
 #[derive(Debug, Clone)]
 pub struct CstChildren<N> {
@@ -194,6 +245,18 @@ mod support {
         parent.children().find_map(N::cast)
     }
 
+    pub(super) fn required<N: CstNode>(
+        parent: &SyntaxNode,
+        child: Option<N>,
+        expected: &'static str,
+    ) -> Result<N, super::MissingNode> {
+        child.ok_or_else(|| super::MissingNode {
+            parent: parent.kind(),
+            expected,
+            range: parent.text_range(),
+        })
+    }
+
     pub(super) fn children<N: CstNode>(parent: &SyntaxNode) -> CstChildren<N> {
         CstChildren::new(parent)
     }
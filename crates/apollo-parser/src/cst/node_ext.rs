@@ -146,6 +146,65 @@ impl cst::Definition {
     }
 }
 
+impl cst::OperationDefinition {
+    /// Return whether this operation is written in shorthand form, as a bare selection set
+    /// `{ … }` without the `query` keyword.
+    pub fn is_shorthand(&self) -> bool {
+        self.operation_type().is_none()
+    }
+}
+
+/// Add `try_*` getters for children that are required by the grammar,
+/// returning a [`cst::MissingNode`] error instead of `None`.
+macro_rules! try_getters {
+    ($($node:ident { $($try_getter:ident => $getter:ident: $Child:ident),+ $(,)? })+) => {
+        $(
+            impl cst::$node {
+                $(
+                    #[doc = concat!(
+                        "Like [`", stringify!($getter), "`][Self::", stringify!($getter),
+                        "], but returns an error if the `", stringify!($Child),
+                        "` is missing after error recovery."
+                    )]
+                    pub fn $try_getter(&self) -> Result<cst::$Child, cst::MissingNode> {
+                        cst::support::required(self.syntax(), self.$getter(), stringify!($Child))
+                    }
+                )+
+            }
+        )+
+    };
+}
+
+try_getters! {
+    DirectiveDefinition { try_name => name: Name }
+    ScalarTypeDefinition { try_name => name: Name }
+    ObjectTypeDefinition { try_name => name: Name }
+    InterfaceTypeDefinition { try_name => name: Name }
+    UnionTypeDefinition { try_name => name: Name }
+    EnumTypeDefinition { try_name => name: Name }
+    InputObjectTypeDefinition { try_name => name: Name }
+    ScalarTypeExtension { try_name => name: Name }
+    ObjectTypeExtension { try_name => name: Name }
+    InterfaceTypeExtension { try_name => name: Name }
+    UnionTypeExtension { try_name => name: Name }
+    EnumTypeExtension { try_name => name: Name }
+    InputObjectTypeExtension { try_name => name: Name }
+    Field { try_name => name: Name }
+    Alias { try_name => name: Name }
+    Argument { try_name => name: Name, try_value => value: Value }
+    FragmentName { try_name => name: Name }
+    NamedType { try_name => name: Name }
+    Variable { try_name => name: Name }
+    EnumValue { try_name => name: Name }
+    ObjectField { try_name => name: Name, try_value => value: Value }
+    VariableDefinition { try_ty => ty: Type }
+    DefaultValue { try_value => value: Value }
+    ListType { try_ty => ty: Type }
+    Directive { try_name => name: Name }
+    FieldDefinition { try_name => name: Name, try_ty => ty: Type }
+    InputValueDefinition { try_name => name: Name, try_ty => ty: Type }
+}
+
 impl From<cst::StringValue> for String {
     fn from(val: cst::StringValue) -> Self {
         Self::from(&val)
@@ -170,6 +229,19 @@ fn unescape_string(input: &str) -> String {
                 // TODO: https://github.com/apollographql/apollo-rs/issues/657 needs
                 // changes both here and in `lexer/mod.rs`
                 let mut unicode = || {
+                    // Braced escape sequences like `\u{1F600}` are only accepted
+                    // by the lexer when following the draft spec edition.
+                    if iter.as_str().starts_with('{') {
+                        let value =
+                            iter.by_ref()
+                                .skip(1)
+                                .take_while(|c| *c != '}')
+                                .fold(0, |acc, c| {
+                                    let digit = c.to_digit(16).unwrap();
+                                    (acc << 4) + digit
+                                });
+                        return char::from_u32(value).unwrap();
+                    }
                     // 1. Let value be the 16-bit hexadecimal value represented
                     // by the sequence of hexadecimal digits within EscapedUnicode.
                     let value = iter.by_ref().take(4).fold(0, |acc, c| {
@@ -472,6 +544,10 @@ mod string_tests {
             unescape_string(r"unicode \u1234\u5678\u90AB\uCDEF"),
             "unicode \u{1234}\u{5678}\u{90AB}\u{CDEF}"
         );
+        assert_eq!(
+            unescape_string(r"braced unicode \u{1F600}\u{41}"),
+            "braced unicode \u{1F600}A"
+        );
     }
 }
 
@@ -575,3 +651,98 @@ quite a lot"
         );
     }
 }
+
+#[cfg(test)]
+mod operation_definition_tests {
+    use crate::{cst, Parser};
+
+    fn operations(input: &str) -> Vec<cst::OperationDefinition> {
+        let cst = Parser::new(input).parse();
+        assert_eq!(cst.errors().len(), 0);
+        cst.document()
+            .definitions()
+            .filter_map(|def| match def {
+                cst::Definition::OperationDefinition(op) => Some(op),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn it_detects_shorthand_operations() {
+        let shorthand = &operations("{ field }")[0];
+        assert!(shorthand.is_shorthand());
+        assert!(shorthand.operation_type().is_none());
+
+        let explicit = &operations("query { field }")[0];
+        assert!(!explicit.is_shorthand());
+        assert!(explicit.operation_type().unwrap().query_token().is_some());
+
+        let named = operations("query Named { field } mutation { field }");
+        assert!(named.iter().all(|op| !op.is_shorthand()));
+    }
+}
+
+#[cfg(test)]
+mod try_getters_tests {
+    use crate::{cst, cst::CstNode, Parser};
+
+    fn first_definition(input: &str) -> cst::Definition {
+        let cst = Parser::new(input).parse();
+        assert_ne!(cst.errors().len(), 0, "{input} should have errors");
+        cst.document().definitions().next().unwrap()
+    }
+
+    #[test]
+    fn it_reports_missing_required_children() {
+        let cst::Definition::ObjectTypeDefinition(object) = first_definition("type { a: Int }")
+        else {
+            panic!("expected an object type");
+        };
+        let error = object.try_name().unwrap_err();
+        assert_eq!(
+            error.parent_kind(),
+            crate::SyntaxKind::OBJECT_TYPE_DEFINITION
+        );
+        assert_eq!(error.expected(), "Name");
+        let field = object
+            .fields_definition()
+            .unwrap()
+            .field_definitions()
+            .next()
+            .unwrap();
+        assert_eq!(field.try_name().unwrap().text(), "a");
+        assert!(field.try_ty().is_ok());
+
+        let cst::Definition::OperationDefinition(operation) = first_definition("{ f(a: ) }") else {
+            panic!("expected an operation");
+        };
+        let cst::Selection::Field(field) = operation
+            .selection_set()
+            .unwrap()
+            .selections()
+            .next()
+            .unwrap()
+        else {
+            panic!("expected a field");
+        };
+        let argument = field.arguments().unwrap().arguments().next().unwrap();
+        assert_eq!(argument.try_name().unwrap().text(), "a");
+        let error = argument.try_value().unwrap_err();
+        assert_eq!(error.to_string(), "missing Value in ARGUMENT");
+        assert_eq!(error.range(), argument.syntax().text_range());
+
+        let cst::Definition::OperationDefinition(operation) = first_definition("query($v: ) { f }")
+        else {
+            panic!("expected an operation");
+        };
+        let variable = operation
+            .variable_definitions()
+            .unwrap()
+            .variable_definitions()
+            .next()
+            .unwrap();
+        assert_eq!(variable.variable().unwrap().try_name().unwrap().text(), "v");
+        assert_eq!(variable.try_ty().unwrap_err().expected(), "Type");
+    }
+}
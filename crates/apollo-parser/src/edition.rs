@@ -0,0 +1,47 @@
+/// Edition of the GraphQL specification to follow while lexing and parsing.
+///
+/// Some details of the grammar differ between editions of the specification.
+/// By default, `apollo-parser` follows the [October 2021] edition.
+///
+/// * [`June2018`][SpecEdition::June2018] does not have `repeatable` directive definitions.
+/// * [`Draft`][SpecEdition::Draft] allows braced unicode escape sequences
+///   in string values, such as `"\u{1F600}"`.
+///
+/// ```rust
+/// use apollo_parser::{Parser, SpecEdition};
+///
+/// let input = "directive @tag(name: String!) repeatable on OBJECT";
+///
+/// let cst = Parser::new(input).parse();
+/// assert_eq!(0, cst.errors().len());
+///
+/// let cst = Parser::new(input)
+///     .spec_edition(SpecEdition::June2018)
+///     .parse();
+/// assert_eq!(1, cst.errors().len());
+/// ```
+///
+/// [October 2021]: https://spec.graphql.org/October2021/
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum SpecEdition {
+    /// <https://spec.graphql.org/June2018/>
+    June2018,
+    /// <https://spec.graphql.org/October2021/>
+    #[default]
+    October2021,
+    /// <https://spec.graphql.org/draft/>
+    Draft,
+}
+
+impl SpecEdition {
+    /// Whether directive definitions may be marked `repeatable`.
+    pub(crate) fn has_repeatable_directives(self) -> bool {
+        self >= Self::October2021
+    }
+
+    /// Whether string values may contain braced unicode escape sequences: `\u{1F600}`.
+    pub(crate) fn has_braced_unicode_escapes(self) -> bool {
+        self >= Self::Draft
+    }
+}
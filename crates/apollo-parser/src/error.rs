@@ -1,4 +1,5 @@
 use std::fmt;
+use std::ops::Range;
 
 /// An `Error` type for operations performed in the lexer and the parser.
 ///
@@ -66,6 +67,7 @@ pub struct Error {
     pub(crate) message: String,
     pub(crate) data: ErrorData,
     pub(crate) index: usize,
+    pub(crate) detail_range: Option<Range<usize>>,
 }
 
 impl Error {
@@ -75,6 +77,7 @@ impl Error {
             message: message.into(),
             data: ErrorData::Text(data),
             index: 0,
+            detail_range: None,
         }
     }
 
@@ -84,6 +87,7 @@ impl Error {
             message: message.into(),
             data: ErrorData::Text(data),
             index,
+            detail_range: None,
         }
     }
 
@@ -92,6 +96,7 @@ impl Error {
             message: message.into(),
             data: ErrorData::LimitExceeded,
             index,
+            detail_range: None,
         }
     }
 
@@ -100,6 +105,7 @@ impl Error {
             message: message.into(),
             data: ErrorData::Eof,
             index,
+            detail_range: None,
         }
     }
 
@@ -130,6 +136,29 @@ impl Error {
         self.index
     }
 
+    /// Get the length in bytes of the source text covered by the error.
+    ///
+    /// Together with [`Error::index`], this gives the range of input that a
+    /// recovering parser can skip over: `index..index + length` always covers
+    /// the whole lexeme that was consumed. See [`Error::detail_range`] for the
+    /// part of it that the error is about.
+    pub fn length(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Get the byte range of the part of the input that the error is about, if
+    /// it is narrower than the range covered by the error. For example, an
+    /// invalid escape sequence covers the whole string, but this range only
+    /// includes the escape sequence. This is useful to underline in editors.
+    pub fn detail_range(&self) -> Option<Range<usize>> {
+        self.detail_range.clone()
+    }
+
+    pub(crate) fn with_detail_range(mut self, range: Range<usize>) -> Self {
+        self.detail_range = Some(range);
+        self
+    }
+
     /// Get a reference to the error's message.
     pub fn message(&self) -> &str {
         self.message.as_ref()
@@ -139,7 +168,7 @@ impl Error {
 impl fmt::Debug for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let start = self.index;
-        let end = self.index + self.data.len();
+        let end = self.index + self.length();
 
         write!(
             f,
@@ -1,6 +1,6 @@
 use std::str::CharIndices;
 
-use crate::Error;
+use crate::{Error, SpecEdition};
 
 /// Peekable iterator over a char sequence.
 #[derive(Debug, Clone)]
@@ -11,6 +11,11 @@ pub(crate) struct Cursor<'a> {
     chars: CharIndices<'a>,
     pending: Option<char>,
     pub(crate) err: Option<Error>,
+    pub(crate) edition: SpecEdition,
+    pub(crate) strict_bom: bool,
+    pub(crate) string_limit: Option<usize>,
+    /// Number of characters consumed in the current token.
+    pub(crate) consumed: usize,
 }
 
 impl<'a> Cursor<'a> {
@@ -22,6 +27,10 @@ impl<'a> Cursor<'a> {
             source: input,
             chars: input.char_indices(),
             err: None,
+            edition: SpecEdition::default(),
+            strict_bom: false,
+            string_limit: None,
+            consumed: 0,
         }
     }
 }
@@ -73,6 +82,7 @@ impl<'a> Cursor<'a> {
 
     /// Moves to the next character.
     pub(crate) fn bump(&mut self) -> Option<char> {
+        self.consumed += 1;
         if self.pending.is_some() {
             return self.pending.take();
         }
@@ -97,6 +107,7 @@ impl<'a> Cursor<'a> {
             self.offset = pos;
 
             if c_in == c {
+                self.consumed += 1;
                 return true;
             }
 
@@ -120,8 +131,10 @@ impl<'a> Cursor<'a> {
         self.source.get(start..=self.index).unwrap()
     }
 
-    /// Add error object to the cursor.
+    /// Add error object to the cursor. Only the first error in a token is kept.
     pub(crate) fn add_err(&mut self, err: Error) {
-        self.err = Some(err)
+        if self.err.is_none() {
+            self.err = Some(err)
+        }
     }
 }
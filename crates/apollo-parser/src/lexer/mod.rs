@@ -2,7 +2,8 @@ mod cursor;
 mod token;
 mod token_kind;
 
-use crate::{lexer::cursor::Cursor, Error, LimitTracker};
+use crate::{lexer::cursor::Cursor, Error, LimitTracker, SpecEdition};
+use std::ops::Range;
 
 pub use token::Token;
 pub use token_kind::TokenKind;
@@ -30,6 +31,9 @@ pub struct Lexer<'a> {
     finished: bool,
     cursor: Cursor<'a>,
     pub(crate) limit_tracker: LimitTracker,
+    token_count: usize,
+    error_count: usize,
+    ignore_commas: bool,
 }
 
 #[derive(Debug)]
@@ -37,6 +41,8 @@ enum State {
     Start,
     Ident,
     StringLiteralEscapedUnicode(usize),
+    /// Contains the byte offset where the hexadecimal digits start.
+    StringLiteralEscapedBracedUnicode(usize),
     StringLiteral,
     StringLiteralStart,
     BlockStringLiteral,
@@ -51,6 +57,9 @@ enum State {
     ExponentDigit,
     Whitespace,
     Comment,
+    Slash,
+    BlockComment,
+    BlockCommentStar,
     SpreadOperator,
     MinusSign,
 }
@@ -78,6 +87,41 @@ impl<'a> Lexer<'a> {
             cursor: Cursor::new(input),
             finished: false,
             limit_tracker: LimitTracker::new(usize::MAX),
+            token_count: 0,
+            error_count: 0,
+            ignore_commas: false,
+        }
+    }
+
+    /// Create a lexer for GraphQL source text given as bytes, for example from a network read.
+    ///
+    /// GraphQL source text must be UTF-8. If it is not, this returns an error
+    /// located at the first invalid byte. Otherwise, this behaves like [`Lexer::new`].
+    ///
+    /// ```rust
+    /// use apollo_parser::Lexer;
+    ///
+    /// let lexer = Lexer::from_bytes(b"{ a }").unwrap();
+    /// assert_eq!(lexer.count(), 6);
+    ///
+    /// let error = Lexer::from_bytes(b"{ a\xFF }").unwrap_err();
+    /// assert_eq!(error.message(), "invalid UTF-8 sequence starting with byte 0xFF");
+    /// assert_eq!(error.index(), 3);
+    /// ```
+    pub fn from_bytes(input: &'a [u8]) -> Result<Self, Error> {
+        match std::str::from_utf8(input) {
+            Ok(input) => Ok(Self::new(input)),
+            Err(err) => {
+                let index = err.valid_up_to();
+                Err(Error::with_loc(
+                    format!(
+                        "invalid UTF-8 sequence starting with byte {:#04X}",
+                        input[index]
+                    ),
+                    String::new(),
+                    index,
+                ))
+            }
         }
     }
 
@@ -86,6 +130,104 @@ impl<'a> Lexer<'a> {
         self
     }
 
+    /// Configure the edition of the GraphQL specification to follow.
+    ///
+    /// By default, the lexer follows the October 2021 edition.
+    pub fn with_spec_edition(mut self, edition: SpecEdition) -> Self {
+        self.cursor.edition = edition;
+        self
+    }
+
+    /// Report byte order marks (U+FEFF) that are not at the very start of the input.
+    ///
+    /// The GraphQL specification allows a byte order mark anywhere that whitespace is allowed,
+    /// which is the default behaviour. A byte order mark in the middle of a document is
+    /// usually the result of concatenating files, so strict mode reports it as an error.
+    /// A leading byte order mark is always accepted.
+    pub fn with_strict_bom(mut self, strict: bool) -> Self {
+        self.cursor.strict_bom = strict;
+        self
+    }
+
+    /// Limit the length of string values, in characters including the quotes.
+    ///
+    /// When a string value exceeds the limit, the lexer reports a limit error
+    /// and stops instead of reading the rest of the input.
+    /// ```rust
+    /// use apollo_parser::Lexer;
+    ///
+    /// let query = r#"{ search(text: "a very long string") }"#;
+    /// let (_, errors) = Lexer::new(query).with_string_limit(8).lex();
+    /// assert_eq!(errors.len(), 1);
+    /// assert!(errors[0].is_limit());
+    /// assert_eq!(errors[0].index(), 15);
+    /// ```
+    pub fn with_string_limit(mut self, limit: usize) -> Self {
+        self.cursor.string_limit = Some(limit);
+        self
+    }
+
+    /// Skip comma tokens, which are insignificant in GraphQL, like whitespace.
+    ///
+    /// By default, commas are produced as [`TokenKind::Comma`] tokens.
+    /// Skipped commas are not included in [`Lexer::token_count`] and do not count towards
+    /// the token limit.
+    /// ```rust
+    /// use apollo_parser::{Lexer, TokenKind};
+    ///
+    /// let (tokens, _) = Lexer::new("{ a, b }").with_ignored_commas(true).lex();
+    /// assert!(tokens.iter().all(|token| token.kind() != TokenKind::Comma));
+    /// ```
+    pub fn with_ignored_commas(mut self, ignore: bool) -> Self {
+        self.ignore_commas = ignore;
+        self
+    }
+
+    /// Check that a GraphQL source text lexes without errors.
+    ///
+    /// This stops at the first error and does not collect tokens, so it is cheaper
+    /// than [`Lexer::lex`] when only the outcome is needed.
+    /// ```rust
+    /// use apollo_parser::Lexer;
+    ///
+    /// assert!(Lexer::validate("{ animal }").is_ok());
+    ///
+    /// let error = Lexer::validate("{ animal % }").unwrap_err();
+    /// assert_eq!(error.index(), 9);
+    /// ```
+    pub fn validate(input: &str) -> Result<(), Error> {
+        for item in Lexer::new(input) {
+            item?;
+        }
+        Ok(())
+    }
+
+    /// Returns the number of tokens produced so far.
+    ///
+    /// ```rust
+    /// use apollo_parser::Lexer;
+    ///
+    /// let mut lexer = Lexer::new("{ animal % }");
+    /// lexer.by_ref().for_each(drop);
+    /// // `{`, whitespace, `animal`, whitespace, whitespace, `}` and EOF
+    /// assert_eq!(lexer.token_count(), 7);
+    /// assert_eq!(lexer.error_count(), 1);
+    /// assert!(lexer.has_errors());
+    /// ```
+    pub fn token_count(&self) -> usize {
+        self.token_count
+    }
+
+    /// Returns the number of errors produced so far.
+    pub fn error_count(&self) -> usize {
+        self.error_count
+    }
+
+    /// Returns whether any errors were produced so far.
+    pub fn has_errors(&self) -> bool {
+        self.error_count > 0
+    }
+
     /// Lex the full source text, consuming the lexer.
     pub fn lex(self) -> (Vec<Token<'a>>, Vec<Error>) {
         let mut tokens = vec![];
@@ -106,33 +248,60 @@ impl<'a> Iterator for Lexer<'a> {
     type Item = Result<Token<'a>, Error>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.finished {
-            return None;
-        }
-
-        if self.limit_tracker.check_and_increment() {
-            self.finished = true;
-            return Some(Err(Error::limit(
-                "token limit reached, aborting lexing",
-                self.cursor.index(),
-            )));
-        }
+        loop {
+            if self.finished {
+                return None;
+            }
 
-        match self.cursor.advance() {
-            Ok(token) => {
-                if matches!(token.kind(), TokenKind::Eof) {
-                    self.finished = true;
-                }
+            let index = self.cursor.index();
+            let result = self.cursor.advance();
+            // Skipped commas do not count towards the token limit
+            if matches!(&result, Ok(token) if self.ignore_commas && token.kind() == TokenKind::Comma)
+            {
+                continue;
+            }
 
-                Some(Ok(token))
+            if self.limit_tracker.check_and_increment() {
+                self.finished = true;
+                self.error_count += 1;
+                return Some(Err(Error::limit(
+                    "token limit reached, aborting lexing",
+                    index,
+                )));
             }
-            Err(err) => Some(Err(err)),
+
+            return match result {
+                Ok(token) => {
+                    if matches!(token.kind(), TokenKind::Eof) {
+                        self.finished = true;
+                    }
+
+                    self.token_count += 1;
+                    Some(Ok(token))
+                }
+                Err(err) => {
+                    if err.is_limit() {
+                        self.finished = true;
+                    }
+                    self.error_count += 1;
+                    Some(Err(err))
+                }
+            };
         }
     }
 }
 
 impl<'a> Cursor<'a> {
     fn advance(&mut self) -> Result<Token<'a>, Error> {
+        let start = self.index();
+        // Errors always span the partially lexed token, so that a caller can skip past it.
+        self.lex_token().map_err(|mut err| {
+            err.index = start;
+            err
+        })
+    }
+
+    fn lex_token(&mut self) -> Result<Token<'a>, Error> {
         let mut state = State::Start;
         let mut token = Token {
             kind: TokenKind::Eof,
@@ -140,10 +309,13 @@ impl<'a> Cursor<'a> {
             index: self.index(),
         };
 
+        self.consumed = 0;
+
         loop {
             let Some(c) = self.bump() else {
                 return self.eof(state, token);
             };
+            self.check_string_limit(&token)?;
             match state {
                 State::Start => {
                     match c {
@@ -155,10 +327,20 @@ impl<'a> Cursor<'a> {
                             token.kind = TokenKind::Comment;
                             state = State::Comment;
                         }
+                        '/' => {
+                            state = State::Slash;
+                        }
                         '.' => {
                             token.kind = TokenKind::Spread;
                             state = State::SpreadOperator;
                         }
+                        '\u{FEFF}' if self.is_unexpected_bom() => {
+                            return Err(Error::with_loc(
+                                "unexpected byte order mark, it is only allowed at the start of the input",
+                                self.current_str().to_string(),
+                                token.index,
+                            ));
+                        }
                         c if is_whitespace_assimilated(c) => {
                             token.kind = TokenKind::Whitespace;
                             state = State::Whitespace;
@@ -249,6 +431,12 @@ impl<'a> Cursor<'a> {
                             token.data = self.current_str();
                             return Ok(token);
                         }
+                        '\\' => {
+                            return Err(Error::new(
+                                "Unexpected backslash `\\`, escape sequences are only allowed inside strings",
+                                self.current_str().to_string(),
+                            ))
+                        }
                         c => {
                             return Err(Error::new(
                                 format!("Unexpected character \"{}\"", c),
@@ -265,6 +453,11 @@ impl<'a> Cursor<'a> {
                     }
                 },
                 State::Whitespace => match c {
+                    // Leave the byte order mark to be reported on its own
+                    '\u{FEFF}' if self.is_unexpected_bom() => {
+                        token.data = self.prev_str();
+                        return self.done(token);
+                    }
                     curr if is_whitespace_assimilated(curr) => {}
                     _ => {
                         token.data = self.prev_str();
@@ -310,15 +503,20 @@ impl<'a> Cursor<'a> {
                 },
                 State::StringLiteralEscapedUnicode(remaining) => match c {
                     '"' => {
-                        self.add_err(Error::new(
+                        let escape_sequence_start = self.offset - (4 - remaining) - 2; // include "\u"
+                        self.add_escape_err(
                             "incomplete unicode escape sequence",
-                            c.to_string(),
-                        ));
+                            escape_sequence_start..self.offset,
+                        );
                         token.data = self.current_str();
                         return self.done(token);
                     }
                     c if !c.is_ascii_hexdigit() => {
-                        self.add_err(Error::new("invalid unicode escape sequence", c.to_string()));
+                        let escape_sequence_start = self.offset - (4 - remaining) - 2; // include "\u"
+                        self.add_escape_err(
+                            "invalid unicode escape sequence",
+                            escape_sequence_start..self.offset + c.len_utf8(),
+                        );
                         state = State::StringLiteral;
 
                         continue;
@@ -336,13 +534,12 @@ impl<'a> Cursor<'a> {
                                 // TODO: https://github.com/apollographql/apollo-rs/issues/657 needs
                                 // changes both here and in `ast/node_ext.rs`
                                 let escape_sequence_start = hex_start - 2; // include "\u"
-                                let escape_sequence = &self.source[escape_sequence_start..hex_end];
-                                self.add_err(Error::new(
+                                self.add_escape_err(
                                     "surrogate code point is invalid in unicode escape sequence \
                                      (paired surrogate not supported yet: \
                                      https://github.com/apollographql/apollo-rs/issues/657)",
-                                    escape_sequence.to_owned(),
-                                ));
+                                    escape_sequence_start..hex_end,
+                                );
                             }
                             continue;
                         }
@@ -350,6 +547,43 @@ impl<'a> Cursor<'a> {
                         state = State::StringLiteralEscapedUnicode(remaining - 1)
                     }
                 },
+                State::StringLiteralEscapedBracedUnicode(hex_start) => match c {
+                    '"' => {
+                        let escape_sequence_start = hex_start - 3; // include "\u{"
+                        self.add_escape_err(
+                            "incomplete unicode escape sequence",
+                            escape_sequence_start..self.offset,
+                        );
+                        token.data = self.current_str();
+                        return self.done(token);
+                    }
+                    '}' => {
+                        state = State::StringLiteral;
+                        let hex = &self.source[hex_start..self.offset];
+                        let is_valid = u32::from_str_radix(hex, 16)
+                            .ok()
+                            .and_then(char::from_u32)
+                            .is_some();
+                        if !is_valid {
+                            let escape_sequence_start = hex_start - 3; // include "\u{"
+                            self.add_escape_err(
+                                "invalid unicode escape sequence",
+                                escape_sequence_start..self.offset + 1,
+                            );
+                        }
+                    }
+                    c if !c.is_ascii_hexdigit() => {
+                        let escape_sequence_start = hex_start - 3; // include "\u{"
+                        self.add_escape_err(
+                            "invalid unicode escape sequence",
+                            escape_sequence_start..self.offset + c.len_utf8(),
+                        );
+                        state = State::StringLiteral;
+
+                        continue;
+                    }
+                    _ => {}
+                },
                 State::StringLiteral => match c {
                     '"' => {
                         token.data = self.current_str();
@@ -361,6 +595,12 @@ impl<'a> Cursor<'a> {
                     '\\' => {
                         state = State::StringLiteralBackslash;
                     }
+                    curr if !is_source_char(curr) => {
+                        self.add_err(Error::new(
+                            format!("invalid character U+{:04X} in string literal", curr as u32),
+                            "".to_string(),
+                        ));
+                    }
                     _ => {}
                 },
                 State::BlockStringLiteralBackslash => match c {
@@ -388,11 +628,18 @@ impl<'a> Cursor<'a> {
                     curr if is_escaped_char(curr) => {
                         state = State::StringLiteral;
                     }
+                    'u' if self.edition.has_braced_unicode_escapes() && self.eatc('{') => {
+                        state = State::StringLiteralEscapedBracedUnicode(self.offset + 1);
+                    }
                     'u' => {
                         state = State::StringLiteralEscapedUnicode(4);
                     }
                     _ => {
-                        self.add_err(Error::new("unexpected escaped character", c.to_string()));
+                        let escape_sequence_start = self.offset - 1; // include the backslash
+                        self.add_escape_err(
+                            "unexpected escaped character",
+                            escape_sequence_start..self.offset + c.len_utf8(),
+                        );
 
                         state = State::StringLiteral;
                     }
@@ -451,7 +698,7 @@ impl<'a> Cursor<'a> {
                     _ => {
                         return Err(Error::new(
                             format!("Unexpected character `{c}`, expected fractional digit"),
-                            self.current_str().to_string(),
+                            self.unexpected_char_data(c).to_string(),
                         ));
                     }
                 },
@@ -481,7 +728,7 @@ impl<'a> Cursor<'a> {
                     _ => {
                         return Err(Error::new(
                             format!("Unexpected character `{c}`, expected exponent digit or sign"),
-                            self.current_str().to_string(),
+                            self.unexpected_char_data(c).to_string(),
                         ))
                     }
                 },
@@ -492,7 +739,7 @@ impl<'a> Cursor<'a> {
                     _ => {
                         return Err(Error::new(
                             format!("Unexpected character `{c}`, expected exponent digit"),
-                            self.current_str().to_string(),
+                            self.unexpected_char_data(c).to_string(),
                         ))
                     }
                 },
@@ -516,7 +763,13 @@ impl<'a> Cursor<'a> {
                         token.data = self.current_str();
                         return Ok(token);
                     }
-                    return self.unterminated_spread_operator(&token);
+                    if c != '.' {
+                        // Leave the unexpected character for the next token
+                        let data = self.prev_str();
+                        return self.unterminated_spread_operator(&token, data);
+                    }
+                    let data = self.spread_operator_data();
+                    return self.unterminated_spread_operator(&token, data);
                 }
                 State::MinusSign => match c {
                     '0' => {
@@ -528,7 +781,7 @@ impl<'a> Cursor<'a> {
                     _ => {
                         return Err(Error::new(
                             format!("Unexpected character `{c}`"),
-                            self.current_str().to_string(),
+                            self.unexpected_char_data(c).to_string(),
                         ))
                     }
                 },
@@ -539,6 +792,32 @@ impl<'a> Cursor<'a> {
                     }
                     _ => {}
                 },
+                State::Slash => match c {
+                    '*' => {
+                        state = State::BlockComment;
+                    }
+                    _ => {
+                        return Err(Error::new(
+                            "Unexpected character \"/\"",
+                            self.prev_str().to_string(),
+                        ));
+                    }
+                },
+                // Block comments are not GraphQL, but are reported as a single error
+                State::BlockComment => {
+                    if c == '*' {
+                        state = State::BlockCommentStar;
+                    }
+                }
+                State::BlockCommentStar => match c {
+                    '/' => {
+                        return Err(block_comment_error(self.current_str()));
+                    }
+                    '*' => {}
+                    _ => {
+                        state = State::BlockComment;
+                    }
+                },
             }
         }
     }
@@ -549,6 +828,11 @@ impl<'a> Cursor<'a> {
                 token.index += 1;
                 Ok(token)
             }
+            State::Slash => Err(Error::new(
+                "Unexpected character \"/\"",
+                self.current_str().to_string(),
+            )),
+            State::BlockComment | State::BlockCommentStar => Err(block_comment_error(self.drain())),
             State::StringLiteralStart => {
                 let curr = self.current_str();
 
@@ -560,6 +844,7 @@ impl<'a> Cursor<'a> {
             State::StringLiteral
             | State::BlockStringLiteral
             | State::StringLiteralEscapedUnicode(_)
+            | State::StringLiteralEscapedBracedUnicode(_)
             | State::BlockStringLiteralBackslash
             | State::StringLiteralBackslash => {
                 let curr = self.drain();
@@ -570,7 +855,10 @@ impl<'a> Cursor<'a> {
                     token.index,
                 ))
             }
-            State::SpreadOperator => self.unterminated_spread_operator(&token),
+            State::SpreadOperator => {
+                let data = self.spread_operator_data();
+                self.unterminated_spread_operator(&token, data)
+            }
             State::MinusSign => Err(Error::new(
                 "Unexpected character \"-\"",
                 self.current_str().to_string(),
@@ -600,13 +888,21 @@ impl<'a> Cursor<'a> {
         }
     }
 
-    fn unterminated_spread_operator(&mut self, token: &Token<'a>) -> Result<Token<'a>, Error> {
-        let data = if self.is_pending() {
+    /// Returns the data of a partial spread operator, leaving a pending character
+    /// for the next token.
+    fn spread_operator_data(&mut self) -> &'a str {
+        if self.is_pending() {
             self.prev_str()
         } else {
             self.current_str()
-        };
+        }
+    }
 
+    fn unterminated_spread_operator(
+        &self,
+        token: &Token<'a>,
+        data: &str,
+    ) -> Result<Token<'a>, Error> {
         Err(Error::with_loc(
             "Unterminated spread operator",
             data.to_string(),
@@ -614,7 +910,49 @@ impl<'a> Cursor<'a> {
         ))
     }
 
+    /// Returns the data of a number that was interrupted by an unexpected character `c`.
+    ///
+    /// A character that may belong to the same token, like in `1.e`, is included.
+    /// Other characters, like in `1.}`, are left for the next token so that they are not lost.
+    fn unexpected_char_data(&mut self, c: char) -> &'a str {
+        if c == '.' || is_name_continue(c) {
+            self.current_str()
+        } else {
+            self.prev_str()
+        }
+    }
+
+    /// Returns whether the byte order mark just read must be reported:
+    /// in strict mode, when it is not at the start of the input.
+    fn is_unexpected_bom(&self) -> bool {
+        self.strict_bom && self.offset != 0
+    }
+
+    /// Stop lexing a string value that exceeds the string limit.
+    fn check_string_limit(&self, token: &Token<'a>) -> Result<(), Error> {
+        match self.string_limit {
+            Some(limit) if token.kind == TokenKind::StringValue && self.consumed > limit => {
+                Err(Error::limit(
+                    format!(
+                        "string value exceeds the limit of {limit} characters, aborting lexing"
+                    ),
+                    token.index,
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Record an invalid escape sequence in a string value. The error covers the whole
+    /// string, while its detail range points at the escape sequence itself.
+    fn add_escape_err(&mut self, message: &str, escape_sequence: Range<usize>) {
+        let data = self.source[escape_sequence.clone()].to_string();
+        self.add_err(Error::new(message, data).with_detail_range(escape_sequence));
+    }
+
     fn done(&mut self, token: Token<'a>) -> Result<Token<'a>, Error> {
+        // The closing quotes of a block string are consumed without a check
+        self.check_string_limit(&token)?;
         if let Some(mut err) = self.err() {
             err.set_data(token.data.to_string());
             err.index = token.index;
@@ -625,6 +963,13 @@ impl<'a> Cursor<'a> {
     }
 }
 
+fn block_comment_error(data: &str) -> Error {
+    Error::new(
+        "GraphQL does not support block comments, use `#` for comments",
+        data.to_string(),
+    )
+}
+
 /// Ignored tokens other than comments and commas are assimilated to whitespace
 /// <https://spec.graphql.org/October2021/#Ignored>
 fn is_whitespace_assimilated(c: char) -> bool {
@@ -651,6 +996,14 @@ fn is_name_continue(c: char) -> bool {
     matches!(c, 'a'..='z' | 'A'..='Z' | '0'..='9' | '_')
 }
 
+// SourceCharacter
+//     U+0009  U+000A  U+000D  U+0020–U+FFFF
+// Characters above U+FFFF are represented by surrogate pairs in the spec grammar,
+// so any `char` from U+0020 up is allowed.
+fn is_source_char(c: char) -> bool {
+    matches!(c, '\t' | '\n' | '\r') || c >= ' '
+}
+
 fn is_line_terminator(c: char) -> bool {
     matches!(c, '\n' | '\r')
 }
@@ -665,6 +1018,10 @@ fn is_escaped_char(c: char) -> bool {
 mod test {
     use super::*;
 
+    fn token(kind: TokenKind, data: &str, index: usize) -> Token<'_> {
+        Token { kind, data, index }
+    }
+
     #[test]
     fn unterminated_string() {
         let schema = r#"
@@ -678,6 +1035,248 @@ type Query {
         dbg!(errors);
     }
 
+    #[test]
+    fn control_character_in_string() {
+        let (tokens, errors) = Lexer::new("\"a\u{7}b\" c").lex();
+        assert_eq!(
+            errors,
+            &[Error::with_loc(
+                "invalid character U+0007 in string literal",
+                "\"a\u{7}b\"".to_string(),
+                0
+            )]
+        );
+        // Lexing continues after the string
+        assert_eq!(tokens.last().unwrap().kind(), TokenKind::Eof);
+        assert!(tokens.iter().any(|token| token.data() == "c"));
+
+        let (_, errors) = Lexer::new("\"a\tb\"").lex();
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn error_spans() {
+        let input = "{ a % b }";
+        let (tokens, errors) = Lexer::new(input).lex();
+        assert_eq!(
+            errors,
+            &[Error::with_loc(
+                "Unexpected character \"%\"",
+                "%".to_string(),
+                4
+            )]
+        );
+        assert_eq!(errors[0].index(), 4);
+        assert_eq!(errors[0].length(), 1);
+        assert!(tokens.iter().any(|token| token.data() == "b"));
+
+        let input = "{ a(b: 00) }";
+        let (_, errors) = Lexer::new(input).lex();
+        assert_eq!(errors[0].index(), 7);
+        assert_eq!(errors[0].length(), 2);
+        assert_eq!(
+            &input[errors[0].index()..errors[0].index() + errors[0].length()],
+            "00"
+        );
+
+        let input = "{ a(b: \"unterminated) }";
+        let (_, errors) = Lexer::new(input).lex();
+        assert_eq!(errors[0].message(), "unterminated string value");
+        assert_eq!(errors[0].index(), 7);
+        assert_eq!(errors[0].length(), input.len() - 7);
+    }
+
+    #[test]
+    fn unexpected_escaped_character() {
+        let input = r#"{ a(b: "a\qb") c }"#;
+        let (tokens, errors) = Lexer::new(input).lex();
+        assert_eq!(
+            errors,
+            &[
+                Error::with_loc("unexpected escaped character", r#""a\qb""#.to_string(), 7)
+                    .with_detail_range(9..11)
+            ]
+        );
+        assert_eq!(&input[errors[0].detail_range().unwrap()], r"\q");
+        // Lexing continues after the string
+        assert!(tokens.iter().any(|token| token.data() == "c"));
+
+        let (_, errors) = Lexer::new(r#""\é""#).lex();
+        assert_eq!(errors[0].index(), 0);
+        assert_eq!(errors[0].detail_range(), Some(1..4));
+
+        let input = r#""\u12x" "\uD800" "\u{D800}" "\u{}" "\u12""#;
+        let (_, errors) = Lexer::new(input)
+            .with_spec_edition(SpecEdition::Draft)
+            .lex();
+        let escape_sequences: Vec<_> = errors
+            .iter()
+            .map(|error| &input[error.detail_range().unwrap()])
+            .collect();
+        assert_eq!(
+            escape_sequences,
+            &[r"\u12x", r"\uD800", r"\u{D800}", r"\u{}", r"\u12"]
+        );
+    }
+
+    #[test]
+    fn validate() {
+        assert_eq!(Lexer::validate("type Query { a: Int }"), Ok(()));
+        assert_eq!(Lexer::validate(""), Ok(()));
+
+        // Only the first error is reported
+        assert_eq!(
+            Lexer::validate("{ a: 00, b: \"unterminated }"),
+            Err(Error::with_loc(
+                "Numbers must not have non-significant leading zeroes",
+                "00".to_string(),
+                5
+            ))
+        );
+    }
+
+    #[test]
+    fn crlf_line_terminator() {
+        let (tokens, errors) = Lexer::new("a\r\nb").lex();
+        assert!(errors.is_empty());
+        assert_eq!(
+            tokens,
+            &[
+                token(TokenKind::Name, "a", 0),
+                token(TokenKind::Whitespace, "\r\n", 1),
+                token(TokenKind::Name, "b", 3),
+                token(TokenKind::Eof, "", 4),
+            ]
+        );
+
+        // Newline styles are kept as written, so the source can be reproduced exactly
+        let input = "a\r\n\nb\rc\r\n";
+        let (tokens, _) = Lexer::new(input).lex();
+        let reconstructed: String = tokens.iter().map(|token| token.data()).collect();
+        assert_eq!(reconstructed, input);
+    }
+
+    #[test]
+    fn leading_bom() {
+        let input = "\u{FEFF}type Query { a: Int }";
+        let (tokens, errors) = Lexer::new(input).with_strict_bom(true).lex();
+        assert!(errors.is_empty());
+        assert_eq!(tokens[0].kind(), TokenKind::Whitespace);
+        assert_eq!(tokens[0].data(), "\u{FEFF}");
+    }
+
+    #[test]
+    fn stacked_leading_boms() {
+        let input = "\u{FEFF}\u{FEFF}type Query { a: Int }";
+
+        let (tokens, errors) = Lexer::new(input).lex();
+        assert!(errors.is_empty());
+        assert_eq!(tokens[0].data(), "\u{FEFF}\u{FEFF}");
+
+        // Only the first byte order mark is at the start of the input
+        let (tokens, errors) = Lexer::new(input).with_strict_bom(true).lex();
+        assert_eq!(
+            errors,
+            &[Error::with_loc(
+                "unexpected byte order mark, it is only allowed at the start of the input",
+                "\u{FEFF}".to_string(),
+                3
+            )]
+        );
+        assert_eq!(tokens[0].kind(), TokenKind::Whitespace);
+        assert_eq!(tokens[0].data(), "\u{FEFF}");
+        assert_eq!(tokens[0].index(), 0);
+        assert_eq!(tokens[1].data(), "type");
+    }
+
+    #[test]
+    fn bom_in_the_middle() {
+        let input = "type Query { a: Int }\n\u{FEFF}type Mutation { b: Int }";
+
+        let (_, errors) = Lexer::new(input).lex();
+        assert!(errors.is_empty());
+
+        let (tokens, errors) = Lexer::new(input).with_strict_bom(true).lex();
+        assert_eq!(
+            errors,
+            &[Error::with_loc(
+                "unexpected byte order mark, it is only allowed at the start of the input",
+                "\u{FEFF}".to_string(),
+                22
+            )]
+        );
+        assert!(tokens.iter().any(|token| token.data() == "Mutation"));
+
+        // Whitespace around the byte order mark is kept
+        let (tokens, errors) = Lexer::new("{ a \u{FEFF} }").with_strict_bom(true).lex();
+        assert_eq!(
+            errors,
+            &[Error::with_loc(
+                "unexpected byte order mark, it is only allowed at the start of the input",
+                "\u{FEFF}".to_string(),
+                4
+            )]
+        );
+        let whitespace: Vec<_> = tokens
+            .iter()
+            .filter(|token| token.kind() == TokenKind::Whitespace)
+            .map(|token| (token.data(), token.index()))
+            .collect();
+        assert_eq!(whitespace, [(" ", 1), (" ", 3), (" ", 7)]);
+    }
+
+    #[test]
+    fn block_comment() {
+        let (tokens, errors) = Lexer::new("/* hi */ { a }").lex();
+        assert_eq!(
+            errors,
+            &[Error::with_loc(
+                "GraphQL does not support block comments, use `#` for comments",
+                "/* hi */".to_string(),
+                0
+            )]
+        );
+        let kinds: Vec<_> = tokens
+            .iter()
+            .filter(|token| !token.kind().is_trivia())
+            .map(|token| (token.kind(), token.data()))
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                (TokenKind::LCurly, "{"),
+                (TokenKind::Name, "a"),
+                (TokenKind::RCurly, "}"),
+                (TokenKind::Eof, ""),
+            ]
+        );
+
+        let cst = crate::Parser::new("/* hi */ { a }").parse();
+        assert_eq!(cst.errors().len(), 1);
+        assert_eq!(cst.document().definitions().count(), 1);
+    }
+
+    #[test]
+    fn compare_tokens() {
+        let (tokens, errors) = Lexer::new("{ a }").lex();
+        assert!(errors.is_empty());
+        assert_eq!(
+            tokens,
+            &[
+                token(TokenKind::LCurly, "{", 0),
+                token(TokenKind::Whitespace, " ", 1),
+                token(TokenKind::Name, "a", 2),
+                token(TokenKind::Whitespace, " ", 3),
+                token(TokenKind::RCurly, "}", 4),
+                token(TokenKind::Eof, "", 5),
+            ]
+        );
+
+        // The index is part of the comparison
+        let (other, _) = Lexer::new(" { a }").lex();
+        assert_ne!(tokens[0], other[1]);
+    }
+
     #[test]
     fn token_limit() {
         let lexer = Lexer::new("type Query { a a a a a a a a a }").with_limit(10);
@@ -720,6 +1319,234 @@ type Query {
         );
     }
 
+    #[test]
+    fn string_limit() {
+        fn limit(input: &str, limit: usize) -> (Vec<Token<'_>>, Vec<Error>) {
+            Lexer::new(input).with_string_limit(limit).lex()
+        }
+
+        // Quotes are included in the length
+        let (tokens, errors) = limit(r#""abcd""#, 6);
+        assert_eq!(tokens.len(), 2);
+        assert!(errors.is_empty());
+        let (tokens, errors) = limit(r#""abcd""#, 5);
+        assert!(tokens.is_empty());
+        assert_eq!(
+            errors,
+            &[Error::limit(
+                "string value exceeds the limit of 5 characters, aborting lexing",
+                0
+            )]
+        );
+
+        let (tokens, errors) = limit(r#""""abcd""""#, 10);
+        assert_eq!(tokens.len(), 2);
+        assert!(errors.is_empty());
+        let (tokens, errors) = limit(r#""""abcd""""#, 9);
+        assert!(tokens.is_empty());
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].is_limit());
+
+        // Only string values are limited, and lexing stops at the first long string
+        let (tokens, errors) = limit(r#"longerName "ok" "too long" "ok""#, 4);
+        let kinds: Vec<_> = tokens.iter().map(|token| token.kind()).collect();
+        assert_eq!(
+            kinds,
+            [
+                TokenKind::Name,
+                TokenKind::Whitespace,
+                TokenKind::StringValue,
+                TokenKind::Whitespace,
+            ]
+        );
+        assert_eq!(
+            errors,
+            &[Error::limit(
+                "string value exceeds the limit of 4 characters, aborting lexing",
+                16
+            )]
+        );
+    }
+
+    #[test]
+    fn token_and_error_counts() {
+        let mut lexer = Lexer::new("type Query { ..a a a a a a a a a }").with_limit(10);
+        assert_eq!(lexer.token_count(), 0);
+        assert!(!lexer.has_errors());
+
+        let (mut tokens, mut errors) = (0, 0);
+        while let Some(item) = lexer.next() {
+            match item {
+                Ok(_) => tokens += 1,
+                Err(_) => errors += 1,
+            }
+            assert_eq!(lexer.token_count(), tokens);
+            assert_eq!(lexer.error_count(), errors);
+        }
+        assert_eq!(lexer.token_count(), 9);
+        assert_eq!(lexer.error_count(), 2);
+        assert!(lexer.has_errors());
+    }
+
+    #[test]
+    fn token_raw_source() {
+        let source = "# caf\u{e9}\nquery { name(arg: \"\u{1F600}\") }";
+        let (tokens, errors) = Lexer::new(source).lex();
+        assert!(errors.is_empty());
+        for token in &tokens {
+            assert_eq!(token.raw(source), token.data());
+        }
+        let name = tokens
+            .iter()
+            .find(|token| token.kind() == TokenKind::Name && token.data() == "name")
+            .unwrap();
+        assert_eq!(name.raw(source), "name");
+    }
+
+    #[test]
+    fn braced_unicode_escape() {
+        let input = r#""\u{1F600} \u{41}""#;
+
+        let (tokens, errors) = Lexer::new(input).lex();
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(
+            errors,
+            &[
+                Error::with_loc("invalid unicode escape sequence", input.to_string(), 0)
+                    .with_detail_range(1..4)
+            ]
+        );
+
+        let (tokens, errors) = Lexer::new(input)
+            .with_spec_edition(SpecEdition::Draft)
+            .lex();
+        assert!(errors.is_empty());
+        assert_eq!(tokens[0].kind, TokenKind::StringValue);
+        assert_eq!(tokens[0].data, input);
+
+        let input = r#""\u{D800} \u{}""#;
+        let (_, errors) = Lexer::new(input)
+            .with_spec_edition(SpecEdition::Draft)
+            .lex();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].message(), "invalid unicode escape sequence");
+    }
+
+    #[test]
+    fn recovers_after_error() {
+        let (tokens, errors) = Lexer::new("@ ^ {").lex();
+        assert_eq!(
+            errors,
+            &[Error::with_loc(
+                "Unexpected character \"^\"",
+                "^".to_string(),
+                2
+            )]
+        );
+        let tokens: Vec<_> = tokens.iter().map(|t| (t.kind, t.data, t.index)).collect();
+        assert_eq!(
+            tokens,
+            &[
+                (TokenKind::At, "@", 0),
+                (TokenKind::Whitespace, " ", 1),
+                (TokenKind::Whitespace, " ", 3),
+                (TokenKind::LCurly, "{", 4),
+                (TokenKind::Eof, "", 5),
+            ]
+        );
+    }
+
+    #[test]
+    fn from_bytes() {
+        let input = "type Query { a: String @deprecated(reason: \"é\") }";
+        let tokens: Vec<_> = Lexer::from_bytes(input.as_bytes()).unwrap().collect();
+        assert_eq!(tokens, Lexer::new(input).collect::<Vec<_>>());
+
+        // Truncated two-byte sequence for "é"
+        let input = b"type Query { a: String @deprecated(reason: \"\xC3\") }";
+        let error = Lexer::from_bytes(input).unwrap_err();
+        assert_eq!(
+            error,
+            Error::with_loc(
+                "invalid UTF-8 sequence starting with byte 0xC3",
+                String::new(),
+                44
+            )
+        );
+    }
+
+    #[test]
+    fn stray_backslash() {
+        let (tokens, errors) = Lexer::new("{ a \\ b }").lex();
+        assert_eq!(
+            errors,
+            &[Error::with_loc(
+                "Unexpected backslash `\\`, escape sequences are only allowed inside strings",
+                "\\".to_string(),
+                4
+            )]
+        );
+        let tokens: Vec<_> = tokens.iter().map(|t| (t.kind, t.data, t.index)).collect();
+        assert_eq!(
+            tokens,
+            &[
+                (TokenKind::LCurly, "{", 0),
+                (TokenKind::Whitespace, " ", 1),
+                (TokenKind::Name, "a", 2),
+                (TokenKind::Whitespace, " ", 3),
+                (TokenKind::Whitespace, " ", 5),
+                (TokenKind::Name, "b", 6),
+                (TokenKind::Whitespace, " ", 7),
+                (TokenKind::RCurly, "}", 8),
+                (TokenKind::Eof, "", 9),
+            ]
+        );
+    }
+
+    #[test]
+    fn errors_make_progress_without_skipping_input() {
+        let inputs = [
+            "^^{",
+            "&^%$ {",
+            "-{",
+            "- {",
+            "1. {",
+            "1.}",
+            "1e {",
+            "1e+}",
+            ". a",
+            ".. a",
+            "..",
+            ".",
+            "0x1 {",
+            "01 {",
+            "\"abc",
+            "\"\"\"abc",
+            "a\u{7}b",
+            r#""a\qb""#,
+        ];
+        for input in inputs {
+            let mut data = String::new();
+            let mut last_index = None;
+            for item in Lexer::new(input) {
+                let (item_data, index) = match &item {
+                    Ok(token) => (token.data(), token.index()),
+                    Err(error) => (error.data(), error.index()),
+                };
+                assert!(
+                    last_index < Some(index),
+                    "{input:?}: {item:?} did not advance"
+                );
+                last_index = Some(index);
+                data.push_str(item_data);
+            }
+            assert_eq!(
+                data, input,
+                "{input:?}: tokens and errors should cover the input"
+            );
+        }
+    }
+
     #[test]
     fn stream_produces_original_input() {
         let schema = r#"
@@ -793,4 +1620,68 @@ Escape character followed by a quote:
             )]
         );
     }
+
+    #[test]
+    fn ignored_commas() {
+        let input = "query($a: Int, $b: Int,,) { f(a: $a, b: $b), g }";
+        let kinds = |lexer: Lexer| -> Vec<TokenKind> {
+            lexer
+                .map(|token| token.unwrap().kind())
+                .filter(|kind| *kind != TokenKind::Whitespace)
+                .collect()
+        };
+
+        let with_commas = kinds(Lexer::new(input));
+        assert_eq!(
+            with_commas
+                .iter()
+                .filter(|kind| **kind == TokenKind::Comma)
+                .count(),
+            5
+        );
+        let without_commas = kinds(Lexer::new(input).with_ignored_commas(true));
+        assert!(!without_commas.contains(&TokenKind::Comma));
+        let expected: Vec<_> = with_commas
+            .into_iter()
+            .filter(|kind| *kind != TokenKind::Comma)
+            .collect();
+        assert_eq!(without_commas, expected);
+
+        let mut lexer = Lexer::new("a,,,b").with_ignored_commas(true);
+        lexer.by_ref().for_each(drop);
+        // `a`, `b` and EOF
+        assert_eq!(lexer.token_count(), 3);
+        // Skipped commas do not count towards the token limit
+        let input = "{a,,,,,,,,b}";
+        let (tokens, errors) = Lexer::new(input).with_limit(5).lex();
+        assert_eq!(tokens.len(), 5);
+        assert!(errors[0].is_limit());
+        let (tokens, errors) = Lexer::new(input)
+            .with_limit(5)
+            .with_ignored_commas(true)
+            .lex();
+        assert!(errors.is_empty());
+        assert_eq!(tokens.len(), 5);
+        let mut lexer = Lexer::new(input).with_limit(4).with_ignored_commas(true);
+        let tokens: Vec<_> = lexer.by_ref().collect();
+        assert_eq!(lexer.token_count(), 4);
+        assert!(tokens[4].as_ref().unwrap_err().is_limit());
+    }
+
+    #[test]
+    fn token_kind_categories() {
+        let input = "query($a: [Int!] = 1.5) @d { ...on X | Y & \"s\" 2, a } # c";
+        let (tokens, errors) = Lexer::new(input).lex();
+        assert!(errors.is_empty());
+        for token in tokens {
+            let kind = token.kind();
+            let categories = [kind.is_punctuation(), kind.is_literal(), kind.is_trivia()];
+            let expected = usize::from(!matches!(kind, TokenKind::Name | TokenKind::Eof));
+            assert_eq!(
+                categories.iter().filter(|is| **is).count(),
+                expected,
+                "{token:?}"
+            );
+        }
+    }
 }
@@ -1,9 +1,40 @@
 use std::fmt;
+use std::ops::Range;
 
 use crate::TokenKind;
 
 /// A token generated by the lexer.
-#[derive(Clone)]
+///
+/// Tokens compare equal if they have the same kind, the same source text,
+/// and start at the same index.
+///
+/// The accessors [`kind`][Self::kind], [`data`][Self::data], [`index`][Self::index] and
+/// [`span`][Self::span] are the stable way to read a token; its fields are private.
+///
+/// ```rust
+/// use apollo_parser::{Lexer, TokenKind};
+///
+/// let source = r#"{ greet(name: "José") count }"#;
+/// let mut tokens = Vec::new();
+/// for token in Lexer::new(source) {
+///     let token = token.expect("no lexing errors");
+///     if matches!(token.kind(), TokenKind::Name | TokenKind::StringValue) {
+///         tokens.push((token.data(), token.span()));
+///     }
+/// }
+/// // Spans are byte ranges
+/// assert_eq!(
+///     tokens,
+///     [
+///         ("greet", 2..7),
+///         ("name", 8..12),
+///         ("\"José\"", 14..21),
+///         ("count", 23..28),
+///     ]
+/// );
+/// assert_eq!(&source[tokens[2].1.clone()], "\"José\"");
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Token<'a> {
     pub(crate) kind: TokenKind,
     pub(crate) data: &'a str,
@@ -11,20 +42,50 @@ pub struct Token<'a> {
 }
 
 impl<'a> Token<'a> {
-    /// Get a reference to the token's kind.
+    /// Returns the kind of this token.
     pub fn kind(&self) -> TokenKind {
         self.kind
     }
 
-    /// Get a reference to the token's data.
-    pub fn data(&self) -> &str {
+    /// Returns the source text of this token, borrowed from the lexer's input.
+    ///
+    /// This is empty for [`TokenKind::Eof`].
+    pub fn data(&self) -> &'a str {
         self.data
     }
 
-    /// Get a reference to the token's loc.
+    /// Returns the byte offset in the lexer's input where this token starts.
     pub fn index(&self) -> usize {
         self.index
     }
+
+    /// Returns the byte range of this token in the lexer's input.
+    ///
+    /// This is `index()..index() + data().len()`.
+    pub fn span(&self) -> Range<usize> {
+        self.index..self.index + self.data.len()
+    }
+
+    /// Get the slice of `source` that this token was lexed from.
+    ///
+    /// `source` must be the input the token was lexed from.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the token's byte range is out of bounds for `source`,
+    /// or does not fall on character boundaries.
+    ///
+    /// ```
+    /// use apollo_parser::{Lexer, TokenKind};
+    ///
+    /// let source = "{ name }";
+    /// let (tokens, _) = Lexer::new(source).lex();
+    /// let name = tokens.iter().find(|t| t.kind() == TokenKind::Name).unwrap();
+    /// assert_eq!(name.raw(source), "name");
+    /// ```
+    pub fn raw<'s>(&self, source: &'s str) -> &'s str {
+        &source[self.span()]
+    }
 }
 
 impl<'a> fmt::Debug for Token<'a> {
@@ -12,8 +12,13 @@
 /// TokenKinds can be accessed by a convenience macro, `T!`. For example to
 /// access the Bang TokenKind, you may match with `TokenKind::Bang`, or use the
 /// macro `T![!]`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// New kinds of tokens may be added in minor releases, so matches on `TokenKind`
+/// need a wildcard arm. The category methods like [`TokenKind::is_punctuation`]
+/// keep working when kinds are added, and are preferable to listing kinds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(u16)]
+#[non_exhaustive]
 pub enum TokenKind {
     Whitespace, // \r | \n |   | \t
     Comment,    // # comment
@@ -41,6 +46,70 @@ pub enum TokenKind {
     Float,
 }
 
+impl TokenKind {
+    /// Returns whether this token is one of the [punctuators](https://spec.graphql.org/October2021/#Punctuator):
+    /// `! $ & ( ) ... : = @ [ ] { } |`.
+    ///
+    /// Commas are not punctuators: they are [trivia](Self::is_trivia).
+    ///
+    /// ```rust
+    /// use apollo_parser::TokenKind;
+    ///
+    /// assert!(TokenKind::Spread.is_punctuation());
+    /// assert!(!TokenKind::Comma.is_punctuation());
+    /// assert!(!TokenKind::Name.is_punctuation());
+    /// ```
+    pub fn is_punctuation(self) -> bool {
+        matches!(
+            self,
+            Self::Bang
+                | Self::Dollar
+                | Self::Amp
+                | Self::Spread
+                | Self::Colon
+                | Self::Eq
+                | Self::At
+                | Self::LParen
+                | Self::RParen
+                | Self::LBracket
+                | Self::RBracket
+                | Self::LCurly
+                | Self::RCurly
+                | Self::Pipe
+        )
+    }
+
+    /// Returns whether this token is a string, integer, or float literal.
+    ///
+    /// Names are not literals, including `true`, `false`, and `null`.
+    ///
+    /// ```rust
+    /// use apollo_parser::TokenKind;
+    ///
+    /// assert!(TokenKind::StringValue.is_literal());
+    /// assert!(TokenKind::Float.is_literal());
+    /// assert!(!TokenKind::Name.is_literal());
+    /// ```
+    pub fn is_literal(self) -> bool {
+        matches!(self, Self::StringValue | Self::Int | Self::Float)
+    }
+
+    /// Returns whether this token is [ignored](https://spec.graphql.org/October2021/#Ignored)
+    /// by the grammar: whitespace, including line terminators and byte order marks,
+    /// comments, and commas.
+    ///
+    /// ```rust
+    /// use apollo_parser::TokenKind;
+    ///
+    /// assert!(TokenKind::Comment.is_trivia());
+    /// assert!(TokenKind::Comma.is_trivia());
+    /// assert!(!TokenKind::Eof.is_trivia());
+    /// ```
+    pub fn is_trivia(self) -> bool {
+        matches!(self, Self::Whitespace | Self::Comment | Self::Comma)
+    }
+}
+
 /// Create a new `TokenKind`.
 #[macro_export]
 macro_rules! T {
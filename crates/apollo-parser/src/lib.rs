@@ -5,15 +5,19 @@ mod lexer;
 mod tests;
 
 pub mod cst;
+mod edition;
 mod error;
 mod limit;
 mod parser;
 
 pub use crate::lexer::Lexer;
 pub use crate::lexer::{Token, TokenKind};
-pub use crate::parser::{Parser, SyntaxElement, SyntaxKind, SyntaxNode, SyntaxTree};
-pub(crate) use crate::parser::{SyntaxNodeChildren, SyntaxToken, TokenText};
+pub(crate) use crate::parser::TokenText;
+pub use crate::parser::{
+    Parser, SyntaxElement, SyntaxKind, SyntaxNode, SyntaxNodeChildren, SyntaxToken, SyntaxTree,
+};
 pub use rowan::TextRange;
 
+pub use crate::edition::SpecEdition;
 pub use crate::error::Error;
 pub use crate::limit::LimitTracker;
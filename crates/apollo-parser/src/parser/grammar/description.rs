@@ -5,6 +5,10 @@ use crate::{Parser, SyntaxKind};
 /// *Description*:
 ///     StringValue
 pub(crate) fn description(p: &mut Parser) {
+    if !p.keep_descriptions {
+        p.bump(SyntaxKind::STRING);
+        return;
+    }
     let _g = p.start_node(SyntaxKind::DESCRIPTION);
     let _g_string = p.start_node(SyntaxKind::STRING_VALUE);
     p.bump(SyntaxKind::STRING)
@@ -12,7 +16,7 @@ pub(crate) fn description(p: &mut Parser) {
 
 #[cfg(test)]
 mod tests {
-    use crate::cst;
+    use crate::cst::{self, CstNode};
 
     use super::*;
     #[test]
@@ -45,4 +49,76 @@ type Query {
         }
         panic!("object type definition has not been catched");
     }
+
+    #[test]
+    fn it_can_skip_descriptions() {
+        let schema = r#"
+"schema description"
+schema { query: Query }
+"""
+description for Query object type
+"""
+type Query {
+  "field description"
+  products(
+    "argument description"
+    category: ProductCategory = ALL
+  ): [Product]
+}
+"enum description"
+enum ProductCategory {
+  "enum value description"
+  ALL
+}
+"#;
+        let cst = Parser::new(schema).keep_descriptions(false).parse();
+        assert!(cst.errors.is_empty());
+
+        let document = cst.document();
+        // The source text is preserved
+        assert_eq!(document.syntax().to_string(), schema);
+        assert!(!document
+            .syntax()
+            .descendants()
+            .any(|node| node.kind() == SyntaxKind::DESCRIPTION));
+
+        let mut definitions = document.definitions();
+        let Some(cst::Definition::SchemaDefinition(_)) = definitions.next() else {
+            panic!("expected a schema definition")
+        };
+        let Some(cst::Definition::ObjectTypeDefinition(query)) = definitions.next() else {
+            panic!("expected an object type definition")
+        };
+        assert!(query.description().is_none());
+        assert_eq!(query.name().unwrap().text(), "Query");
+        let field = query
+            .fields_definition()
+            .unwrap()
+            .field_definitions()
+            .next()
+            .unwrap();
+        assert!(field.description().is_none());
+        assert_eq!(field.name().unwrap().text(), "products");
+        let argument = field
+            .arguments_definition()
+            .unwrap()
+            .input_value_definitions()
+            .next()
+            .unwrap();
+        assert!(argument.description().is_none());
+        assert_eq!(argument.name().unwrap().text(), "category");
+        let Some(cst::Definition::EnumTypeDefinition(category)) = definitions.next() else {
+            panic!("expected an enum type definition")
+        };
+        assert!(category.description().is_none());
+        let value = category
+            .enum_values_definition()
+            .unwrap()
+            .enum_value_definitions()
+            .next()
+            .unwrap();
+        assert!(value.description().is_none());
+        assert_eq!(value.enum_value().unwrap().text(), "ALL");
+        assert!(definitions.next().is_none());
+    }
 }
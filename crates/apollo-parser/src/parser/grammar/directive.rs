@@ -39,6 +39,9 @@ pub(crate) fn directive_definition(p: &mut Parser) {
 
     if let Some(node) = p.peek_data() {
         if node.as_str() == "repeatable" {
+            if !p.edition().has_repeatable_directives() {
+                p.err("repeatable directives are not supported in this edition of the spec");
+            }
             p.bump(SyntaxKind::repeatable_KW);
         }
     }
@@ -222,6 +225,20 @@ directive @example(isTreat: Boolean, treatKind: String) repeatable on FIELD | MU
         panic!("Expected CST to have a Directive Definition");
     }
 
+    #[test]
+    fn it_rejects_repeatable_kw_in_june_2018_edition() {
+        let schema = r#"
+directive @example(isTreat: Boolean, treatKind: String) repeatable on FIELD | MUTATION
+        "#;
+        let parser = Parser::new(schema).spec_edition(crate::SpecEdition::June2018);
+        let cst = parser.parse();
+
+        assert_eq!(
+            cst.errors().map(|err| err.message()).collect::<Vec<_>>(),
+            ["repeatable directives are not supported in this edition of the spec"]
+        );
+    }
+
     #[test]
     fn it_can_access_directive_location_on_directive_definition() {
         let schema = r#"
@@ -41,6 +41,9 @@ pub(crate) fn fields_definition(p: &mut Parser) {
     let _g = p.start_node(SyntaxKind::FIELDS_DEFINITION);
     p.bump(S!['{']);
     while let Some(TokenKind::Name | TokenKind::StringValue) = p.peek() {
+        if p.at_definition_boundary() {
+            break;
+        }
         // Guaranteed to eat at least one token if the next token is a Name or StringValue
         field_definition(p);
     }
@@ -1,5 +1,5 @@
 use crate::parser::grammar::value::Constness;
-use crate::parser::grammar::{description, directive, name};
+use crate::parser::grammar::{description, directive, name, object};
 use crate::{Parser, SyntaxKind, TokenKind, T};
 
 /// See: https://spec.graphql.org/October2021/#ScalarTypeDefinition
@@ -22,6 +22,8 @@ pub(crate) fn scalar_type_definition(p: &mut Parser) {
         _ => p.err("expected a Name"),
     }
 
+    invalid_implements_interfaces(p);
+
     if let Some(T![@]) = p.peek() {
         directive::directives(p, Constness::Const);
     }
@@ -41,8 +43,24 @@ pub(crate) fn scalar_type_extension(p: &mut Parser) {
         _ => p.err("expected a Name"),
     }
 
+    let has_interfaces = invalid_implements_interfaces(p);
+
     match p.peek() {
         Some(T![@]) => directive::directives(p, Constness::Const),
+        // Already reported an error for this extension
+        _ if has_interfaces => {}
         _ => p.err("expected Directives"),
     }
 }
+
+/// Scalar types cannot implement interfaces, but parse an `implements` clause anyway
+/// so that it does not derail the rest of the document.
+fn invalid_implements_interfaces(p: &mut Parser) -> bool {
+    if let Some("implements") = p.peek_data().as_deref() {
+        p.err("scalar types cannot implement interfaces");
+        object::implements_interfaces(p);
+        true
+    } else {
+        false
+    }
+}
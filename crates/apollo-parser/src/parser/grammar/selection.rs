@@ -77,6 +77,12 @@ pub(crate) fn selection(p: &mut Parser) {
             T!['{'] => {
                 break;
             }
+            TokenKind::Name if p.at_definition_boundary() => {
+                if !has_selection {
+                    p.err("expected at least one Selection in Selection Set");
+                }
+                break;
+            }
             TokenKind::Name => {
                 field::field(p);
                 has_selection = true;
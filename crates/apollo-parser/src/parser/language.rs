@@ -1,6 +1,46 @@
 use crate::SyntaxKind;
 
 /// A node in the immutable tree. It has other nodes and tokens as children.
+///
+/// This is the untyped, lossless layer beneath the typed [`cst`](crate::cst) nodes,
+/// which can be useful for generic tree tooling.
+/// Every typed node gives access to its syntax node with [`CstNode::syntax`](crate::cst::CstNode::syntax).
+///
+/// ```rust
+/// use apollo_parser::cst::CstNode;
+/// use apollo_parser::{Parser, SyntaxKind, SyntaxNode, SyntaxToken};
+///
+/// let tree = Parser::new("{ a }").parse();
+/// let root: SyntaxNode = tree.document().syntax().clone();
+/// assert_eq!(root.kind(), SyntaxKind::DOCUMENT);
+/// assert_eq!(root.text().to_string(), "{ a }");
+///
+/// let operation = root.children().next().unwrap();
+/// assert_eq!(operation.kind(), SyntaxKind::OPERATION_DEFINITION);
+///
+/// let selection_set = operation.children().next().unwrap();
+/// let kinds: Vec<SyntaxKind> = selection_set
+///     .children_with_tokens()
+///     .map(|element| element.kind())
+///     .collect();
+/// assert_eq!(
+///     kinds,
+///     [
+///         SyntaxKind::L_CURLY,
+///         SyntaxKind::WHITESPACE,
+///         SyntaxKind::FIELD,
+///         SyntaxKind::WHITESPACE,
+///         SyntaxKind::R_CURLY,
+///     ]
+/// );
+/// let tokens: Vec<SyntaxToken> = selection_set
+///     .children_with_tokens()
+///     .filter_map(|element| element.into_token())
+///     .collect();
+/// assert_eq!(tokens[0].text(), "{");
+/// let field = selection_set.children().next().unwrap();
+/// assert_eq!(u32::from(field.text_range().start()), 2);
+/// ```
 pub type SyntaxNode = rowan::SyntaxNode<GraphQLLanguage>;
 /// A leaf node in the syntax tree.
 pub type SyntaxToken = rowan::SyntaxToken<GraphQLLanguage>;
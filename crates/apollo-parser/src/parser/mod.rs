@@ -1,6 +1,7 @@
 mod generated;
 mod language;
 mod syntax_tree;
+mod token_source;
 mod token_text;
 
 pub(crate) mod grammar;
@@ -10,7 +11,7 @@ use std::{cell::RefCell, rc::Rc};
 use crate::{
     cst::{Document, SelectionSet, Type},
     lexer::Lexer,
-    Error, LimitTracker, Token, TokenKind,
+    Error, LimitTracker, SpecEdition, Token, TokenKind,
 };
 
 pub use generated::syntax_kind::SyntaxKind;
@@ -19,6 +20,7 @@ pub use syntax_tree::SyntaxTree;
 
 // pub(crate) use language::GraphQLLanguage;
 pub(crate) use syntax_tree::SyntaxTreeBuilder;
+use token_source::TokenSource;
 pub(crate) use token_text::TokenText;
 
 /// Parse GraphQL schemas or queries into a typed CST.
@@ -26,7 +28,9 @@ pub(crate) use token_text::TokenText;
 /// ## Example
 ///
 /// The API to parse a query or a schema is the same, as the parser currently
-/// accepts a `&str`. Here is an example of parsing a query:
+/// accepts a `&str`. The parser borrows the input, but the [`SyntaxTree`] it
+/// returns does not: see [its ownership section](SyntaxTree#ownership).
+/// Here is an example of parsing a query:
 /// ```rust
 /// use apollo_parser::Parser;
 ///
@@ -75,7 +79,8 @@ pub(crate) use token_text::TokenText;
 /// ```
 #[derive(Debug)]
 pub struct Parser<'a> {
-    lexer: Lexer<'a>,
+    /// Where tokens come from: the lexer, or tokens lexed ahead of time.
+    tokens: TokenSource<'a>,
     /// Store one lookahead token so we don't need to reparse things as much.
     current_token: Option<Token<'a>>,
     /// The in-progress tree.
@@ -88,6 +93,16 @@ pub struct Parser<'a> {
     recursion_limit: LimitTracker,
     /// Accept parsing errors?
     accept_errors: bool,
+    /// The edition of the GraphQL specification to follow.
+    spec_edition: SpecEdition,
+    /// Close unterminated blocks when a new definition starts.
+    error_recovery: bool,
+    /// Wrap descriptions in `DESCRIPTION` nodes?
+    keep_descriptions: bool,
+    /// The size of the input in bytes.
+    input_len: usize,
+    /// Reject inputs larger than this many bytes without lexing them.
+    max_input_bytes: Option<usize>,
 }
 
 /// Chosen experimentally with:
@@ -106,16 +121,64 @@ const DEFAULT_RECURSION_LIMIT: usize = 500;
 impl<'a> Parser<'a> {
     /// Create a new instance of a parser given an input string.
     pub fn new(input: &'a str) -> Self {
-        let lexer = Lexer::new(input);
+        Self::with_token_source(TokenSource::Lexer(Lexer::new(input)), input.len())
+    }
 
+    /// Create a new instance of a parser given tokens and errors from a [`Lexer`].
+    ///
+    /// This skips lexing, for example to parse the same tokens several times
+    /// with different settings, or to parse tokens that were modified after lexing.
+    /// Parsing the result of [`Lexer::lex`] builds the same syntax tree
+    /// and reports the same errors as parsing the source text with [`Parser::new`].
+    ///
+    /// The tokens must look like the output of a [`Lexer`]:
+    ///
+    /// * They cover the source text in order, without gaps, including whitespace,
+    ///   commas and comments. Each token's [`index`][Token::index] is its byte offset.
+    /// * The last token is [`TokenKind::Eof`], unless lexing was stopped by a limit error.
+    /// * Errors are located at the offset of the text they cover, and are reported
+    ///   before any token at the same offset.
+    ///
+    /// The syntax tree is built from the tokens' data, so its text is the concatenation
+    /// of the tokens. Lexer options like [`Parser::strict_bom`] and [`Parser::spec_edition`]
+    /// do not change pre-lexed tokens: set them on the [`Lexer`] instead.
+    /// [`Parser::token_limit`] still applies.
+    ///
+    /// ```rust
+    /// use apollo_parser::cst::CstNode;
+    /// use apollo_parser::{Lexer, Parser};
+    ///
+    /// let input = "type Query { a: Int }";
+    /// let (tokens, errors) = Lexer::new(input).lex();
+    ///
+    /// let cst = Parser::from_tokens(tokens.clone(), errors.clone()).parse();
+    /// assert_eq!(cst.errors().len(), 0);
+    /// assert_eq!(cst.document().syntax().to_string(), input);
+    ///
+    /// let errors = Parser::from_tokens(tokens, errors).token_limit(4).check();
+    /// assert!(errors[0].is_limit());
+    /// ```
+    pub fn from_tokens(tokens: Vec<Token<'a>>, errors: Vec<Error>) -> Self {
+        let input_len = tokens
+            .last()
+            .map_or(0, |token| token.index() + token.data().len());
+        Self::with_token_source(TokenSource::lexed(tokens, errors), input_len)
+    }
+
+    fn with_token_source(tokens: TokenSource<'a>, input_len: usize) -> Self {
         Self {
-            lexer,
+            tokens,
             current_token: None,
             builder: Rc::new(RefCell::new(SyntaxTreeBuilder::new())),
             ignored: vec![],
             errors: Vec::new(),
             recursion_limit: LimitTracker::new(DEFAULT_RECURSION_LIMIT),
             accept_errors: true,
+            spec_edition: SpecEdition::default(),
+            error_recovery: false,
+            keep_descriptions: true,
+            input_len,
+            max_input_bytes: None,
         }
     }
 
@@ -130,19 +193,116 @@ impl<'a> Parser<'a> {
     ///
     /// By default, there is no limit.
     pub fn token_limit(mut self, token_limit: usize) -> Self {
-        self.lexer = self.lexer.with_limit(token_limit);
+        self.tokens = self.tokens.with_limit(token_limit);
+        self
+    }
+
+    /// Configure the maximum size of the input in bytes. If a document or selection
+    /// set is bigger, it is rejected with a limit error before lexing starts,
+    /// and the resulting syntax tree is empty. [`Parser::parse_type`] ignores this limit.
+    ///
+    /// This is a cheap check that can be combined with [`Parser::token_limit`]
+    /// and [`Parser::recursion_limit`].
+    ///
+    /// By default, there is no limit.
+    ///
+    /// ```rust
+    /// use apollo_parser::Parser;
+    ///
+    /// let cst = Parser::new("{ a b c }").max_input_bytes(4).parse();
+    /// let error = cst.errors().next().unwrap();
+    /// assert!(error.is_limit());
+    /// assert_eq!(error.message(), "input size of 9 bytes exceeds the limit of 4 bytes");
+    /// ```
+    pub fn max_input_bytes(mut self, max_input_bytes: usize) -> Self {
+        self.max_input_bytes = Some(max_input_bytes);
+        self
+    }
+
+    /// Report byte order marks (U+FEFF) that are not at the very start of the input.
+    ///
+    /// See [`Lexer::with_strict_bom`].
+    pub fn strict_bom(mut self, strict: bool) -> Self {
+        self.tokens = self.tokens.with_strict_bom(strict);
+        self
+    }
+
+    /// Recover from unterminated selection sets and fields definitions.
+    ///
+    /// The parser always continues after a syntax error and reports every error it finds.
+    /// However, a missing `}` makes the following definitions part of the unterminated
+    /// block: `query` and `type` are valid field names.
+    ///
+    /// With error recovery, a definition keyword (`query`, `type`, `fragment`, ...)
+    /// at the start of a line ends any unterminated selection set or fields definition,
+    /// so that the following definitions are parsed into their own nodes.
+    /// Missing tokens are then marked by empty `ERROR` tokens in the tree.
+    /// As this may misinterpret a field called e.g. `query` at the start of a line,
+    /// error recovery is disabled by default.
+    ///
+    /// ```rust
+    /// use apollo_parser::Parser;
+    ///
+    /// let input = "query A { a { b }\nquery B { c }";
+    /// let cst = Parser::new(input).error_recovery(true).parse();
+    /// assert_eq!(cst.errors().len(), 1);
+    /// assert_eq!(cst.document().definitions().count(), 2);
+    /// ```
+    pub fn error_recovery(mut self, enabled: bool) -> Self {
+        self.error_recovery = enabled;
+        self
+    }
+
+    /// Configure whether descriptions are parsed into `DESCRIPTION` nodes.
+    ///
+    /// When disabled, the string token of a description is added directly to the
+    /// definition it precedes, without the `DESCRIPTION` and `STRING_VALUE` nodes,
+    /// and `description()` accessors return `None`. The syntax tree still contains
+    /// the full source text, so the positions of the following nodes are unchanged.
+    ///
+    /// By default, descriptions are kept.
+    ///
+    /// ```rust
+    /// use apollo_parser::{cst, Parser};
+    ///
+    /// let input = r#""A type" type Query { "A field" name: String }"#;
+    /// let cst = Parser::new(input).keep_descriptions(false).parse();
+    /// assert_eq!(cst.errors().len(), 0);
+    /// let Some(cst::Definition::ObjectTypeDefinition(query)) = cst.document().definitions().next()
+    /// else {
+    ///     panic!("expected an object type definition")
+    /// };
+    /// assert!(query.description().is_none());
+    /// assert_eq!(query.name().unwrap().text(), "Query");
+    /// ```
+    pub fn keep_descriptions(mut self, keep: bool) -> Self {
+        self.keep_descriptions = keep;
+        self
+    }
+
+    /// Configure the edition of the GraphQL specification to follow while
+    /// lexing and parsing.
+    ///
+    /// By default, the parser follows the October 2021 edition.
+    pub fn spec_edition(mut self, edition: SpecEdition) -> Self {
+        self.tokens = self.tokens.with_spec_edition(edition);
+        self.spec_edition = edition;
         self
     }
 
     /// Parse the current tokens.
     pub fn parse(mut self) -> SyntaxTree<Document> {
+        self.check_input_size();
         grammar::document::document(&mut self);
 
         let builder = Rc::try_unwrap(self.builder)
             .expect("More than one reference to builder left")
             .into_inner();
-        let builder =
-            builder.finish_document(self.errors, self.recursion_limit, self.lexer.limit_tracker);
+        let builder = builder.finish_document(
+            self.errors,
+            self.recursion_limit,
+            self.tokens.limit_tracker(),
+        );
 
         match builder {
             syntax_tree::SyntaxTreeWrapper::Document(tree) => tree,
@@ -153,10 +313,30 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parse the current tokens as a document and only return the syntax errors.
+    ///
+    /// This reports the same errors as [`Parser::parse`], but skips building the syntax tree.
+    /// Useful when only checking that a document is syntactically valid, for example in a linter.
+    ///
+    /// ```rust
+    /// use apollo_parser::Parser;
+    ///
+    /// let errors = Parser::new("type Query { a: Int ").check();
+    /// assert_eq!(errors.len(), 1);
+    /// assert_eq!(errors[0].message(), "expected R_CURLY, got EOF");
+    /// ```
+    pub fn check(mut self) -> Vec<Error> {
+        self.builder.borrow_mut().discard();
+        self.check_input_size();
+        grammar::document::document(&mut self);
+        self.errors
+    }
+
     /// Parse a selection set with optional outer braces.
     /// This is the expected format of the string value of the `fields` argument of some directives
     /// like [`@requires`](https://www.apollographql.com/docs/federation/federated-types/federated-directives/#requires).
     pub fn parse_selection_set(mut self) -> SyntaxTree<SelectionSet> {
+        self.check_input_size();
         grammar::selection::field_set(&mut self);
 
         let builder = Rc::try_unwrap(self.builder)
@@ -165,7 +345,7 @@ impl<'a> Parser<'a> {
         let builder = builder.finish_selection_set(
             self.errors,
             self.recursion_limit,
-            self.lexer.limit_tracker,
+            self.tokens.limit_tracker(),
         );
 
         match builder {
@@ -186,8 +366,11 @@ impl<'a> Parser<'a> {
         let builder = Rc::try_unwrap(self.builder)
             .expect("More than one reference to builder left")
             .into_inner();
-        let builder =
-            builder.finish_type(self.errors, self.recursion_limit, self.lexer.limit_tracker);
+        let builder = builder.finish_type(
+            self.errors,
+            self.recursion_limit,
+            self.tokens.limit_tracker(),
+        );
 
         match builder {
             syntax_tree::SyntaxTreeWrapper::Type(tree) => tree,
@@ -198,6 +381,62 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Apply the `max_input_bytes` limit: an input that is too big is
+    /// replaced by an empty one, so that nothing is lexed.
+    fn check_input_size(&mut self) {
+        let Some(limit) = self.max_input_bytes else {
+            return;
+        };
+        if self.input_len > limit {
+            self.tokens =
+                TokenSource::Lexer(Lexer::new("").with_limit(self.tokens.limit_tracker().limit));
+            self.push_err(Error::limit(
+                format!(
+                    "input size of {} bytes exceeds the limit of {limit} bytes",
+                    self.input_len
+                ),
+                0,
+            ));
+            self.accept_errors = false;
+        }
+    }
+
+    /// Get the edition of the GraphQL specification being followed.
+    pub(crate) fn edition(&self) -> SpecEdition {
+        self.spec_edition
+    }
+
+    /// In error recovery mode, check if the current token looks like the start of a
+    /// new definition: a definition keyword at the start of a line.
+    pub(crate) fn at_definition_boundary(&mut self) -> bool {
+        if !self.error_recovery {
+            return false;
+        }
+        let at_line_start = self.ignored.last().is_some_and(|token| {
+            token.kind() == TokenKind::Whitespace && token.data().ends_with(['\n', '\r'])
+        });
+        at_line_start
+            && self.peek_token().is_some_and(|token| {
+                token.kind() == TokenKind::Name
+                    && matches!(
+                        token.data(),
+                        "query"
+                            | "mutation"
+                            | "subscription"
+                            | "fragment"
+                            | "schema"
+                            | "scalar"
+                            | "type"
+                            | "interface"
+                            | "union"
+                            | "enum"
+                            | "input"
+                            | "directive"
+                            | "extend"
+                    )
+            })
+    }
+
     /// Check if the current token is `kind`.
     pub(crate) fn at(&mut self, token: TokenKind) -> bool {
         if let Some(t) = self.peek() {
@@ -218,8 +457,7 @@ impl<'a> Parser<'a> {
 
     /// Consume and skip ignored tokens from the lexer.
     pub(crate) fn skip_ignored(&mut self) {
-        while let Some(TokenKind::Comment | TokenKind::Whitespace | TokenKind::Comma) = self.peek()
-        {
+        while self.peek().is_some_and(TokenKind::is_trivia) {
             let token = self.pop();
             self.ignored.push(token);
         }
@@ -348,6 +586,11 @@ impl<'a> Parser<'a> {
             Error::with_loc(message, data, index)
         };
 
+        if self.error_recovery {
+            // Mark the missing token in the tree. This is placed before pending ignored tokens,
+            // which are still needed by `at_definition_boundary`.
+            self.builder.borrow_mut().token(SyntaxKind::ERROR, "");
+        }
         self.push_err(err);
     }
 
@@ -367,7 +610,7 @@ impl<'a> Parser<'a> {
 
     /// Gets the next token from the lexer.
     fn next_token(&mut self) -> Option<Token<'a>> {
-        for res in &mut self.lexer {
+        for res in &mut self.tokens {
             match res {
                 Err(err) => {
                     if err.is_limit() {
@@ -454,7 +697,7 @@ impl<'a> Parser<'a> {
             .iter()
             .cloned()
             .map(Result::Ok)
-            .chain(self.lexer.clone())
+            .chain(self.tokens.clone())
             .filter_map(Result::ok)
             .filter(|token| !matches!(token.kind(), TokenKind::Whitespace | TokenKind::Comment))
             .nth(n - 1)
@@ -576,6 +819,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn max_input_bytes() {
+        let source = "query { a { b } }";
+
+        let cst = Parser::new(source).max_input_bytes(source.len()).parse();
+        assert_eq!(cst.errors().len(), 0);
+        assert_eq!(cst.document().definitions().count(), 1);
+
+        let cst = Parser::new(source)
+            .max_input_bytes(10)
+            .token_limit(200)
+            .parse();
+        let errors = cst.errors().collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            &[&Error::limit(
+                "input size of 17 bytes exceeds the limit of 10 bytes",
+                0
+            )]
+        );
+        assert_eq!(cst.document().definitions().count(), 0);
+        assert_eq!(cst.token_limit().limit, 200);
+        // Only the EOF token was lexed
+        assert_eq!(cst.token_limit().high, 1);
+
+        let tree = Parser::new("{ a b }")
+            .max_input_bytes(3)
+            .parse_selection_set();
+        assert!(tree.errors().next().unwrap().is_limit());
+        assert_eq!(tree.errors().len(), 1);
+        assert_eq!(tree.field_set().selections().count(), 0);
+    }
+
     #[test]
     fn syntax_errors_and_limits() {
         // Syntax errors before and after the limit
@@ -675,6 +951,94 @@ mod tests {
         assert_eq!(cst.token_limit().high, 26);
     }
 
+    #[test]
+    fn strict_bom() {
+        let source = "\u{FEFF}type Query { a: Int }\n\u{FEFF}type Mutation { b: Int }";
+
+        let cst = Parser::new(source).parse();
+        assert_eq!(cst.errors().len(), 0);
+
+        let cst = Parser::new(source).strict_bom(true).parse();
+        assert_eq!(cst.errors().len(), 1);
+        assert_eq!(cst.document().definitions().count(), 2);
+    }
+
+    #[test]
+    fn source_text_round_trip() {
+        use crate::cst::CstNode;
+
+        let source = "\u{FEFF}# leading comment\ntype Query {\n  a(x: Int = 1,, y: [String!]!): Int # trailing\n}\n\n\n{ a ,b }  ";
+        let cst = Parser::new(source).parse();
+        assert_eq!(cst.errors().len(), 0);
+        assert_eq!(cst.source_text(), source);
+
+        let sources: Vec<_> = cst
+            .document()
+            .definitions()
+            .map(|def| def.source_string())
+            .collect();
+        assert_eq!(
+            sources,
+            [
+                "type Query {\n  a(x: Int = 1,, y: [String!]!): Int # trailing\n}",
+                "{ a ,b }"
+            ]
+        );
+    }
+
+    #[test]
+    fn error_recovery() {
+        use crate::cst::CstNode;
+
+        let source = "query A {\n  a {\n    b\n  }\n\nquery B {\n  c\n}\n";
+
+        // Without error recovery, `query B` is parsed as fields of `A`
+        let cst = Parser::new(source).parse();
+        assert_eq!(cst.errors().len(), 1);
+        assert_eq!(cst.document().definitions().count(), 1);
+
+        let cst = Parser::new(source).error_recovery(true).parse();
+        assert_eq!(
+            cst.errors().cloned().collect::<Vec<_>>(),
+            [Error::with_loc(
+                "expected R_CURLY, got query",
+                "query".to_string(),
+                27
+            )]
+        );
+        let operations: Vec<_> = cst
+            .document()
+            .definitions()
+            .map(|def| match def {
+                cst::Definition::OperationDefinition(op) => op,
+                _ => panic!("expected an operation"),
+            })
+            .collect();
+        assert_eq!(operations.len(), 2);
+        assert_eq!(operations[0].name().unwrap().text(), "A");
+        assert!(operations[0]
+            .selection_set()
+            .unwrap()
+            .r_curly_token()
+            .is_none());
+        let second = &operations[1];
+        assert_eq!(second.name().unwrap().text(), "B");
+        let fields: Vec<_> = second
+            .selection_set()
+            .unwrap()
+            .selections()
+            .map(|selection| match selection {
+                cst::Selection::Field(field) => field.name().unwrap().text().to_string(),
+                _ => panic!("expected a field"),
+            })
+            .collect();
+        assert_eq!(fields, ["c"]);
+
+        // The missing token is marked in the tree, which still reproduces the source text
+        assert!(cst.debug_tree().contains("ERROR@25..25 \"\""));
+        assert_eq!(cst.document().syntax().to_string(), source);
+    }
+
     #[test]
     // single char v.s. multiple is less important than consistency between consecutive calls:
     #[allow(clippy::single_char_add_str)]
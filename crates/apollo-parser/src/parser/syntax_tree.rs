@@ -40,6 +40,28 @@ use super::LimitTracker;
 /// let nodes: Vec<_> = doc.definitions().into_iter().collect();
 /// assert_eq!(nodes.len(), 1);
 /// ```
+///
+/// ## Ownership
+///
+/// A `SyntaxTree` does not borrow the source text: token text is copied into
+/// the tree while parsing, so the tree can outlive the input and be cached or
+/// sent to other threads. Only the [`Parser`][crate::Parser], the
+/// [`Lexer`][crate::Lexer] and its [`Token`][crate::Token]s borrow the input.
+/// ```rust
+/// use apollo_parser::{cst, Parser, SyntaxTree};
+///
+/// fn parse(source: String) -> SyntaxTree {
+///     Parser::new(&source).parse()
+///     // `source` is dropped here
+/// }
+///
+/// let cst = parse("type Query { name: String }".to_string());
+/// let Some(cst::Definition::ObjectTypeDefinition(query)) = cst.document().definitions().next()
+/// else {
+///     panic!("expected an object type definition")
+/// };
+/// assert_eq!(query.name().unwrap().text(), "Query");
+/// ```
 
 // NOTE(@lrlna): This enum helps us setup a type state for document and field
 // set parsing.  Without the wrapper we'd have to add type annotations to
@@ -64,8 +86,11 @@ pub struct SyntaxTree<T: CstNode = cst::Document> {
 const _: () = {
     fn assert_send<T: Send>() {}
     fn assert_sync<T: Sync>() {}
+    fn assert_static<T: 'static>() {}
     let _ = assert_send::<SyntaxTree>;
     let _ = assert_sync::<SyntaxTree>;
+    // The tree must not borrow the source text
+    let _ = assert_static::<SyntaxTree>;
 };
 
 impl<T: CstNode> SyntaxTree<T> {
@@ -84,6 +109,56 @@ impl<T: CstNode> SyntaxTree<T> {
         self.token_limit
     }
 
+    /// Render the syntax tree as an indented list of nodes and tokens,
+    /// with their kinds and source ranges.
+    ///
+    /// Unlike the `Debug` output, this does not include errors or limits.
+    ///
+    /// ```rust
+    /// use apollo_parser::Parser;
+    ///
+    /// let cst = Parser::new("{ a }").parse();
+    /// assert_eq!(
+    ///     cst.debug_tree(),
+    ///     r#"- DOCUMENT@0..5
+    ///     - OPERATION_DEFINITION@0..5
+    ///         - SELECTION_SET@0..5
+    ///             - L_CURLY@0..1 "{"
+    ///             - WHITESPACE@1..2 " "
+    ///             - FIELD@2..3
+    ///                 - NAME@2..3
+    ///                     - IDENT@2..3 "a"
+    ///             - WHITESPACE@3..4 " "
+    ///             - R_CURLY@4..5 "}"
+    /// "#
+    /// );
+    /// ```
+    pub fn debug_tree(&self) -> String {
+        let mut out = String::new();
+        print_tree(&mut out, 0, self.syntax_node().into())
+            .expect("writing to a String cannot fail");
+        out
+    }
+
+    /// Return the source text covered by the tree, including whitespace,
+    /// commas and comments.
+    ///
+    /// The tree keeps every token it was built from, so for valid input this
+    /// is exactly the text that was parsed. Text that failed to lex, such as an
+    /// unterminated string, is reported as an error and is not part of the tree.
+    /// The text of a single node is available through [`CstNode::source_string`].
+    ///
+    /// ```rust
+    /// use apollo_parser::Parser;
+    ///
+    /// let source = "query Query {\n  a, b  # comment\n}\n";
+    /// let cst = Parser::new(source).parse();
+    /// assert_eq!(cst.source_text(), source);
+    /// ```
+    pub fn source_text(&self) -> String {
+        self.syntax_node().text().to_string()
+    }
+
     pub fn green(&self) -> GreenNode {
         self.green.clone()
     }
@@ -133,30 +208,6 @@ impl SyntaxTree<cst::Type> {
 
 impl<T: CstNode> fmt::Debug for SyntaxTree<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fn print(f: &mut fmt::Formatter<'_>, indent: usize, element: SyntaxElement) -> fmt::Result {
-            let kind: SyntaxKind = element.kind();
-            write!(f, "{:indent$}", "", indent = indent)?;
-            match element {
-                rowan::NodeOrToken::Node(node) => {
-                    writeln!(f, "- {:?}@{:?}", kind, node.text_range())?;
-                    for child in node.children_with_tokens() {
-                        print(f, indent + 4, child)?;
-                    }
-                    Ok(())
-                }
-
-                rowan::NodeOrToken::Token(token) => {
-                    writeln!(
-                        f,
-                        "- {:?}@{:?} {:?}",
-                        kind,
-                        token.text_range(),
-                        token.text()
-                    )
-                }
-            }
-        }
-
         fn print_err(f: &mut fmt::Formatter<'_>, errors: Vec<Error>) -> fmt::Result {
             for err in errors {
                 writeln!(f, "- {err:?}")?;
@@ -172,15 +223,41 @@ impl<T: CstNode> fmt::Debug for SyntaxTree<T> {
             write!(f, "{recursion_limit:?}")
         }
 
-        print(f, 0, self.syntax_node().into())?;
+        print_tree(f, 0, self.syntax_node().into())?;
         print_err(f, self.errors.clone())?;
         print_recursion_limit(f, self.recursion_limit)
     }
 }
 
+fn print_tree(f: &mut impl fmt::Write, indent: usize, element: SyntaxElement) -> fmt::Result {
+    let kind: SyntaxKind = element.kind();
+    write!(f, "{:indent$}", "", indent = indent)?;
+    match element {
+        rowan::NodeOrToken::Node(node) => {
+            writeln!(f, "- {:?}@{:?}", kind, node.text_range())?;
+            for child in node.children_with_tokens() {
+                print_tree(f, indent + 4, child)?;
+            }
+            Ok(())
+        }
+
+        rowan::NodeOrToken::Token(token) => {
+            writeln!(
+                f,
+                "- {:?}@{:?} {:?}",
+                kind,
+                token.text_range(),
+                token.text()
+            )
+        }
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct SyntaxTreeBuilder {
     builder: GreenNodeBuilder<'static>,
+    /// Skip building the tree, when only the errors are needed.
+    discard: bool,
 }
 
 impl SyntaxTreeBuilder {
@@ -188,31 +265,45 @@ impl SyntaxTreeBuilder {
     pub(crate) fn new() -> Self {
         Self {
             builder: GreenNodeBuilder::new(),
+            discard: false,
         }
     }
 
+    /// Stop adding nodes and tokens to the tree.
+    pub(crate) fn discard(&mut self) {
+        self.discard = true;
+    }
+
     pub(crate) fn checkpoint(&self) -> rowan::Checkpoint {
         self.builder.checkpoint()
     }
 
     /// Start new node and make it current.
     pub(crate) fn start_node(&mut self, kind: SyntaxKind) {
-        self.builder.start_node(rowan::SyntaxKind(kind as u16));
+        if !self.discard {
+            self.builder.start_node(rowan::SyntaxKind(kind as u16));
+        }
     }
 
     /// Finish current branch and restore previous branch as current.
     pub(crate) fn finish_node(&mut self) {
-        self.builder.finish_node();
+        if !self.discard {
+            self.builder.finish_node();
+        }
     }
 
     pub(crate) fn wrap_node(&mut self, checkpoint: rowan::Checkpoint, kind: SyntaxKind) {
-        self.builder
-            .start_node_at(checkpoint, rowan::SyntaxKind(kind as u16));
+        if !self.discard {
+            self.builder
+                .start_node_at(checkpoint, rowan::SyntaxKind(kind as u16));
+        }
     }
 
     /// Adds new token to the current branch.
     pub(crate) fn token(&mut self, kind: SyntaxKind, text: &str) {
-        self.builder.token(rowan::SyntaxKind(kind as u16), text);
+        if !self.discard {
+            self.builder.token(rowan::SyntaxKind(kind as u16), text);
+        }
     }
 
     pub(crate) fn finish_document(
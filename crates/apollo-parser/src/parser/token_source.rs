@@ -0,0 +1,128 @@
+use std::rc::Rc;
+
+use crate::{lexer::Lexer, Error, LimitTracker, SpecEdition, Token};
+
+/// Where the parser reads its tokens from.
+#[derive(Clone, Debug)]
+pub(crate) enum TokenSource<'a> {
+    /// Lex the input as the parser goes.
+    Lexer(Lexer<'a>),
+    /// Replay tokens and errors that were lexed ahead of time.
+    Lexed(Lexed<'a>),
+}
+
+/// Tokens and errors from a previous lexer run, yielded in the order the lexer produced them.
+///
+/// The tokens are shared so that cloning this for lookahead is cheap.
+#[derive(Clone, Debug)]
+pub(crate) struct Lexed<'a> {
+    tokens: Rc<[Token<'a>]>,
+    errors: Rc<[Error]>,
+    next_token: usize,
+    next_error: usize,
+    finished: bool,
+    limit_tracker: LimitTracker,
+}
+
+impl<'a> TokenSource<'a> {
+    pub(crate) fn lexed(tokens: Vec<Token<'a>>, errors: Vec<Error>) -> Self {
+        Self::Lexed(Lexed {
+            tokens: tokens.into(),
+            errors: errors.into(),
+            next_token: 0,
+            next_error: 0,
+            finished: false,
+            limit_tracker: LimitTracker::new(usize::MAX),
+        })
+    }
+
+    pub(crate) fn limit_tracker(&self) -> LimitTracker {
+        match self {
+            Self::Lexer(lexer) => lexer.limit_tracker,
+            Self::Lexed(lexed) => lexed.limit_tracker,
+        }
+    }
+
+    pub(crate) fn with_limit(self, limit: usize) -> Self {
+        match self {
+            Self::Lexer(lexer) => Self::Lexer(lexer.with_limit(limit)),
+            Self::Lexed(lexed) => Self::Lexed(Lexed {
+                limit_tracker: LimitTracker::new(limit),
+                ..lexed
+            }),
+        }
+    }
+
+    /// Pre-lexed tokens are not affected: lexer options apply when they are lexed.
+    pub(crate) fn with_strict_bom(self, strict: bool) -> Self {
+        match self {
+            Self::Lexer(lexer) => Self::Lexer(lexer.with_strict_bom(strict)),
+            Self::Lexed(_) => self,
+        }
+    }
+
+    /// Pre-lexed tokens are not affected: lexer options apply when they are lexed.
+    pub(crate) fn with_spec_edition(self, edition: SpecEdition) -> Self {
+        match self {
+            Self::Lexer(lexer) => Self::Lexer(lexer.with_spec_edition(edition)),
+            Self::Lexed(_) => self,
+        }
+    }
+}
+
+impl<'a> Iterator for TokenSource<'a> {
+    type Item = Result<Token<'a>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Lexer(lexer) => lexer.next(),
+            Self::Lexed(lexed) => lexed.next(),
+        }
+    }
+}
+
+impl<'a> Iterator for Lexed<'a> {
+    type Item = Result<Token<'a>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        // Count every item like `Lexer` does, so that token limits are reported the same.
+        if self.limit_tracker.check_and_increment() {
+            self.finished = true;
+            let index = match self.tokens.get(self.next_token) {
+                Some(token) => token.index(),
+                None => self
+                    .tokens
+                    .last()
+                    .map_or(0, |token| token.index() + token.data().len()),
+            };
+            return Some(Err(Error::limit(
+                "token limit reached, aborting lexing",
+                index,
+            )));
+        }
+
+        let token = self.tokens.get(self.next_token);
+        if let Some(error) = self.errors.get(self.next_error) {
+            // An error is reported before the token that follows it in the source text.
+            let error_first = match token {
+                Some(token) => error.index() <= token.index(),
+                None => true,
+            };
+            if error_first {
+                self.next_error += 1;
+                if error.is_limit() {
+                    self.finished = true;
+                }
+                return Some(Err(error.clone()));
+            }
+        }
+
+        let token = token?.clone();
+        self.next_token += 1;
+        Some(Ok(token))
+    }
+}
@@ -26,12 +26,14 @@ fn lexer_tests() {
     dir_tests(&test_data_dir(), &["lexer/ok"], "txt", |text, path| {
         let (dumped, errors) = dump_tokens_and_errors(text);
         assert_errors_are_absent(&errors, path);
+        assert_from_tokens_matches(text);
         dumped
     });
 
     dir_tests(&test_data_dir(), &["lexer/err"], "txt", |text, path| {
         let (dumped, errors) = dump_tokens_and_errors(text);
         assert_errors_are_present(&errors, path);
+        assert_from_tokens_matches(text);
         dumped
     });
 }
@@ -41,18 +43,37 @@ fn parser_tests() {
     dir_tests(&test_data_dir(), &["parser/ok"], "txt", |text, path| {
         let parser = Parser::new(text);
         let cst = parser.parse();
-        assert_errors_are_absent(&cst.errors().cloned().collect::<Vec<_>>(), path);
+        let errors = cst.errors().cloned().collect::<Vec<_>>();
+        assert_errors_are_absent(&errors, path);
+        assert_eq!(Parser::new(text).check(), errors);
+        assert_from_tokens_matches(text);
+        assert_eq!(cst.source_text(), text);
         format!("{cst:?}")
     });
 
     dir_tests(&test_data_dir(), &["parser/err"], "txt", |text, path| {
         let parser = Parser::new(text);
         let cst = parser.parse();
-        assert_errors_are_present(&cst.errors().cloned().collect::<Vec<_>>(), path);
+        let errors = cst.errors().cloned().collect::<Vec<_>>();
+        assert_errors_are_present(&errors, path);
+        assert_eq!(Parser::new(text).check(), errors);
+        assert_from_tokens_matches(text);
         format!("{cst:?}")
     });
 }
 
+/// Parsing pre-lexed tokens must build the same tree as parsing the source text.
+fn assert_from_tokens_matches(text: &str) {
+    let (tokens, errors) = Lexer::new(text).lex();
+    let from_tokens = Parser::from_tokens(tokens, errors).parse();
+    let from_text = Parser::new(text).parse();
+    assert_eq!(format!("{from_tokens:?}"), format!("{from_text:?}"));
+    assert_eq!(
+        from_tokens.errors().collect::<Vec<_>>(),
+        from_text.errors().collect::<Vec<_>>()
+    );
+}
+
 fn assert_errors_are_present(errors: &[Error], path: &Path) {
     assert!(
         !errors.is_empty(),
@@ -6,6 +6,60 @@ use crate::DocumentBuilder;
 
 const CHARSET: &[u8] =
     b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz_\n\r\t/$#!.-+='";
+/// Sequences that need escaping or special handling when printed
+/// as a string or block string value.
+const SPECIAL_SEQUENCES: &[&str] = &[
+    "\"\"\"", "\\\"\"\"", "\"", "\\", "\\n", "\\u0041", "\r\n", "\n    ", "\n\t",
+];
+/// Characters that are easy to mishandle when computing spans and offsets,
+/// used with [`BuilderOptions::unicode_stress`][crate::BuilderOptions::unicode_stress].
+const UNICODE_CHARSET: &[char] = &[
+    // Whitespace recognized by the lexer
+    '\t',
+    ' ',
+    '\n',
+    '\r',
+    '\u{FEFF}',
+    // Whitespace the lexer does not recognize
+    '\u{00A0}',
+    '\u{2003}',
+    '\u{3000}',
+    '\u{200B}',
+    // Line and paragraph separators
+    '\u{0085}',
+    '\u{2028}',
+    '\u{2029}',
+    // Bidirectional text markers
+    '\u{200E}',
+    '\u{200F}',
+    '\u{202A}',
+    '\u{202B}',
+    '\u{202C}',
+    '\u{202D}',
+    '\u{202E}',
+    '\u{2066}',
+    '\u{2067}',
+    '\u{2068}',
+    '\u{2069}',
+    // Multi-byte characters, including a combining mark and characters outside the BMP
+    'é',
+    'e',
+    '\u{0301}',
+    'ß',
+    'Ω',
+    'ж',
+    'ש',
+    'ع',
+    '中',
+    '文',
+    '\u{FFFD}',
+    '\u{FFFF}',
+    '😀',
+    '\u{1F3F3}',
+    '\u{FE0F}',
+    '\u{200D}',
+    '\u{10FFFF}',
+];
 
 /// The `__Description` type represents a description
 ///
@@ -43,40 +97,71 @@ impl From<String> for Description {
 
 impl Arbitrary<'_> for Description {
     fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> ArbitraryResult<Self> {
-        let mut arbitrary_str = limited_string_desc(u, 100)?;
-        if arbitrary_str.trim_matches('"').is_empty() {
-            let _ = write!(arbitrary_str, "{}", u.arbitrary::<usize>()?);
-        }
-        Ok(Self(arbitrary_str))
+        arbitrary_description(u, false)
     }
 }
 
 impl<'a> DocumentBuilder<'a> {
     /// Create an arbitrary `Description`
     pub fn description(&mut self) -> ArbitraryResult<Description> {
-        self.u.arbitrary()
+        arbitrary_description(self.u, self.options.unicode_stress)
     }
-}
 
-fn limited_string_desc(u: &mut Unstructured<'_>, max_size: usize) -> ArbitraryResult<String> {
-    let size = u.int_in_range(0..=max_size)?;
+    /// Create the content of an arbitrary string value
+    pub(crate) fn string_value(&mut self, max_size: usize) -> ArbitraryResult<String> {
+        if self.options.unicode_stress {
+            limited_string_desc(self.u, max_size, true)
+        } else {
+            self.limited_string(max_size)
+        }
+    }
+}
 
-    let gen_str = String::from_utf8(
-        (0..size)
-            .map(|_curr_idx| {
-                let idx = u.arbitrary::<usize>()?;
+fn arbitrary_description(u: &mut Unstructured<'_>, unicode: bool) -> ArbitraryResult<Description> {
+    let mut arbitrary_str = limited_string_desc(u, 100, unicode)?;
+    if arbitrary_str.trim_matches('"').is_empty() {
+        let _ = write!(arbitrary_str, "{}", u.arbitrary::<usize>()?);
+    }
+    Ok(Description(arbitrary_str))
+}
 
-                let idx = idx % CHARSET.len();
+fn limited_string_desc(
+    u: &mut Unstructured<'_>,
+    max_size: usize,
+    unicode: bool,
+) -> ArbitraryResult<String> {
+    let size = u.int_in_range(0..=max_size)?;
 
-                Ok(CHARSET[idx])
-            })
-            .collect::<ArbitraryResult<Vec<u8>>>()?,
-    )
-    .unwrap();
+    let mut gen_str = String::with_capacity(size);
+    for _ in 0..size {
+        // Occasionally insert a sequence that exercises string and block string escaping
+        if u.ratio(1, 16)? {
+            gen_str.push_str(u.choose(SPECIAL_SEQUENCES)?);
+        } else if unicode && u.ratio(1, 2)? {
+            gen_str.push(unicode_char(u)?);
+        } else {
+            let idx = u.arbitrary::<usize>()? % CHARSET.len();
+            gen_str.push(CHARSET[idx] as char);
+        }
+    }
 
     Ok(gen_str)
 }
 
+/// A `SourceCharacter` from the whole Unicode range, favoring tricky characters
+fn unicode_char(u: &mut Unstructured<'_>) -> ArbitraryResult<char> {
+    if u.ratio(1, 2)? {
+        return Ok(*u.choose(UNICODE_CHARSET)?);
+    }
+    let c: char = u.arbitrary()?;
+    // Other control characters are not allowed in GraphQL documents
+    Ok(if c.is_control() && !UNICODE_CHARSET.contains(&c) {
+        '\u{FFFD}'
+    } else {
+        c
+    })
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -104,4 +189,89 @@ schema {}
             unreachable!();
         }
     }
+
+    #[test]
+    fn generated_descriptions_round_trip() {
+        use crate::description::Description;
+        use crate::test_utils::entropy;
+        use apollo_compiler::ast::ScalarTypeDefinition;
+        use apollo_parser::cst::Definition;
+        use apollo_parser::Parser;
+        use arbitrary::Unstructured;
+
+        for seed in 0..64 {
+            let data = entropy(seed, 512);
+            let mut u = Unstructured::new(&data);
+            let description: Description = u.arbitrary().unwrap();
+            let expected = String::from(description.clone());
+            let def = ScalarTypeDefinition {
+                description: Some(description.into()),
+                name: apollo_compiler::name!("Scalar"),
+                directives: Default::default(),
+            };
+            let printed = def.to_string();
+            let cst = Parser::new(&printed).parse();
+            assert_eq!(cst.errors().len(), 0, "{printed}");
+            let Some(Definition::ScalarTypeDefinition(def)) = cst.document().definitions().next()
+            else {
+                panic!("expected a scalar definition: {printed}");
+            };
+            let reparsed: String = def.description().unwrap().string_value().unwrap().into();
+            assert_eq!(reparsed, expected, "{printed}");
+        }
+    }
+
+    #[test]
+    fn unicode_stress_strings_round_trip() {
+        use crate::test_utils::entropy;
+        use crate::{Document, DocumentBuilder};
+        use apollo_compiler::ast::{DirectiveList, ScalarTypeDefinition};
+        use apollo_compiler::{name, Node};
+        use apollo_parser::cst::{Definition, Value};
+        use apollo_parser::Parser;
+        use arbitrary::Unstructured;
+
+        let mut non_ascii = 0;
+        for seed in 0..64_u64 {
+            let data = entropy(seed, 2048);
+            let mut u = Unstructured::new(&data);
+            let mut builder = DocumentBuilder::with_document(&mut u, Document::default()).unwrap();
+            builder.options.unicode_stress = true;
+            let description = String::from(builder.description().unwrap());
+            let value = builder.string_value(40).unwrap();
+            non_ascii += (description.chars().chain(value.chars()))
+                .filter(|c| !c.is_ascii())
+                .count();
+
+            let mut directives = DirectiveList::new();
+            directives.push(Node::new(apollo_compiler::ast::Directive {
+                name: name!("dir"),
+                arguments: vec![Node::new(apollo_compiler::ast::Argument {
+                    name: name!("arg"),
+                    value: Node::new(apollo_compiler::ast::Value::String(value.clone().into())),
+                })],
+            }));
+            let def = ScalarTypeDefinition {
+                description: Some(description.clone().into()),
+                name: name!("Scalar"),
+                directives,
+            };
+            let printed = def.to_string();
+            let cst = Parser::new(&printed).parse();
+            assert_eq!(cst.errors().len(), 0, "{printed:?}");
+            let Some(Definition::ScalarTypeDefinition(def)) = cst.document().definitions().next()
+            else {
+                panic!("expected a scalar definition: {printed:?}");
+            };
+            let reparsed: String = def.description().unwrap().string_value().unwrap().into();
+            assert_eq!(reparsed, description, "{printed:?}");
+            let argument = def.directives().unwrap().directives().next().unwrap();
+            let argument = argument.arguments().unwrap().arguments().next().unwrap();
+            let Some(Value::StringValue(reparsed)) = argument.value() else {
+                panic!("expected a string value: {printed:?}");
+            };
+            assert_eq!(String::from(reparsed), value, "{printed:?}");
+        }
+        assert!(non_ascii > 100, "{non_ascii} non-ASCII characters");
+    }
 }
@@ -142,7 +142,9 @@ impl<'a> DocumentBuilder<'a> {
             return Ok(IndexMap::new());
         }
 
-        let num_directives = self.u.int_in_range(0..=(self.directive_defs.len() - 1))?;
+        let max_directives =
+            (self.directive_defs.len() - 1) as f64 * self.options.weights.directive_density;
+        let num_directives = self.u.int_in_range(0..=(max_directives.round() as usize))?;
         let directives = (0..num_directives)
             .map(|_| self.directive(directive_location))
             .collect::<ArbitraryResult<Vec<_>>>()?
@@ -184,9 +186,7 @@ impl<'a> DocumentBuilder<'a> {
     /// Create an arbitrary `DirectiveDef`
     pub fn directive_def(&mut self) -> ArbitraryResult<DirectiveDef> {
         let description = self
-            .u
-            .arbitrary()
-            .unwrap_or(false)
+            .flip_description()
             .then(|| self.description())
             .transpose()?;
         let name = self.type_name()?;
@@ -170,11 +170,9 @@ impl Hash for EnumValueDefinition {
 impl<'a> DocumentBuilder<'a> {
     /// Create an arbitrary `EnumTypeDef`
     pub fn enum_type_definition(&mut self) -> Result<EnumTypeDef> {
-        let extend = !self.enum_type_defs.is_empty() && self.u.arbitrary().unwrap_or(false);
+        let extend = !self.enum_type_defs.is_empty() && self.flip_extend();
         let description = self
-            .u
-            .arbitrary()
-            .unwrap_or(false)
+            .flip_description()
             .then(|| self.description())
             .transpose()?;
         let name = if extend {
@@ -220,9 +218,7 @@ impl<'a> DocumentBuilder<'a> {
         let mut enum_values_def = IndexSet::with_capacity(self.u.int_in_range(2..=10usize)?);
         for i in 0..self.u.int_in_range(2..=10usize)? {
             let description = self
-                .u
-                .arbitrary()
-                .unwrap_or(false)
+                .flip_description()
                 .then(|| self.description())
                 .transpose()?;
             let value = self.name_with_index(i)?;
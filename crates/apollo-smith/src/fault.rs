@@ -0,0 +1,307 @@
+use arbitrary::{Arbitrary, Result as ArbitraryResult};
+use indexmap::{IndexMap, IndexSet};
+use once_cell::sync::Lazy;
+
+use crate::{directive::Directive, enum_::EnumTypeDef, name::Name, ty::Ty, DocumentBuilder};
+
+/// Names of the directives every schema defines implicitly
+static BUILTIN_DIRECTIVE_NAMES: Lazy<Vec<String>> = Lazy::new(|| {
+    apollo_compiler::Schema::new()
+        .directive_definitions
+        .keys()
+        .map(|name| name.to_string())
+        .collect()
+});
+
+/// A single validation rule violation that can be injected into a valid type system document
+/// with [`DocumentBuilder::mutate_with_fault`].
+///
+/// Each fault makes validation report exactly one diagnostic, whose
+/// [`code`][apollo_compiler::validation::DiagnosticData::code] is [`Fault::code`].
+#[derive(Debug, Arbitrary, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Fault {
+    /// Remove from an object type a field required by one of its interfaces
+    MissingInterfaceField,
+    /// Define a value of an enum type a second time, in an extension
+    DuplicateEnumValue,
+    /// Apply `@deprecated` to an object type
+    UnsupportedDirectiveLocation,
+    /// Apply a directive that is not defined to an object type
+    UndefinedDirective,
+    /// Change the type of an object field to a type that is not defined
+    UndefinedFieldType,
+}
+
+impl Fault {
+    /// All the faults that can be injected
+    pub const ALL: &'static [Fault] = &[
+        Fault::MissingInterfaceField,
+        Fault::DuplicateEnumValue,
+        Fault::UnsupportedDirectiveLocation,
+        Fault::UndefinedDirective,
+        Fault::UndefinedFieldType,
+    ];
+
+    /// The code of the diagnostic reported for this fault, as returned by
+    /// [`DiagnosticData::code`][apollo_compiler::validation::DiagnosticData::code]
+    pub fn code(self) -> &'static str {
+        match self {
+            Fault::MissingInterfaceField => "MissingInterfaceField",
+            Fault::DuplicateEnumValue => "EnumValueNameCollision",
+            Fault::UnsupportedDirectiveLocation => "UnsupportedLocation",
+            Fault::UndefinedDirective => "UndefinedDirective",
+            Fault::UndefinedFieldType => "UndefinedDefinition",
+        }
+    }
+}
+
+impl<'a> DocumentBuilder<'a> {
+    /// Inject `fault` into the type system definitions of the document being built.
+    ///
+    /// The document must be valid beforehand, like the schema returned by
+    /// [`DocumentBuilder::schema_with_operations`], and must not contain executable
+    /// definitions. The mutated document then fails validation with exactly one diagnostic,
+    /// identified by [`Fault::code`].
+    ///
+    /// Returns `false`, leaving the document untouched, if it has nothing the fault applies to,
+    /// for example [`Fault::DuplicateEnumValue`] in a document without enum types.
+    ///
+    /// ```rust
+    /// use apollo_compiler::Schema;
+    /// use apollo_smith::{DocumentBuilder, Fault};
+    /// use arbitrary::Unstructured;
+    ///
+    /// let data: Vec<u8> = (0..4096_u32).map(|i| (i * 37 % 251) as u8).collect();
+    /// let mut u = Unstructured::new(&data);
+    /// let (schema, _) = DocumentBuilder::schema_with_operations(&mut u, 1).unwrap();
+    ///
+    /// let data: Vec<u8> = (0..1024_u32).map(|i| (i * 13 % 251) as u8).collect();
+    /// let mut u = Unstructured::new(&data);
+    /// let mut builder = DocumentBuilder::with_document(&mut u, schema).unwrap();
+    /// if builder.mutate_with_fault(Fault::UndefinedDirective).unwrap() {
+    ///     let schema = String::from(builder.finish());
+    ///     let errors = Schema::parse_and_validate(schema, "schema.graphql")
+    ///         .unwrap_err()
+    ///         .errors;
+    ///     let codes: Vec<_> = errors.iter().map(|e| e.error.code()).collect();
+    ///     assert_eq!(codes, [Fault::UndefinedDirective.code()]);
+    /// }
+    /// ```
+    pub fn mutate_with_fault(&mut self, fault: Fault) -> ArbitraryResult<bool> {
+        match fault {
+            Fault::MissingInterfaceField => self.remove_interface_field(),
+            Fault::DuplicateEnumValue => self.duplicate_enum_value(),
+            Fault::UnsupportedDirectiveLocation => self.deprecate_object_type(),
+            Fault::UndefinedDirective => self.apply_undefined_directive(),
+            Fault::UndefinedFieldType => self.use_undefined_field_type(),
+        }
+    }
+
+    fn remove_interface_field(&mut self) -> ArbitraryResult<bool> {
+        // Fields declared by exactly one interface of their object, so that removing them
+        // is reported once. The object must keep at least one field.
+        let mut candidates = Vec::new();
+        for (object_index, object) in self.object_type_defs.iter().enumerate() {
+            if object.fields_def.len() < 2 {
+                continue;
+            }
+            for (field_index, field) in object.fields_def.iter().enumerate() {
+                if self.count_interfaces_declaring(&object.name, &field.name) == 1 {
+                    candidates.push((object_index, field_index));
+                }
+            }
+        }
+        if candidates.is_empty() {
+            return Ok(false);
+        }
+
+        let (object_index, field_index) = *self.u.choose(&candidates)?;
+        self.object_type_defs[object_index]
+            .fields_def
+            .remove(field_index);
+        Ok(true)
+    }
+
+    fn duplicate_enum_value(&mut self) -> ArbitraryResult<bool> {
+        let values: Vec<_> = self
+            .enum_type_defs
+            .iter()
+            .flat_map(|enum_| {
+                enum_
+                    .enum_values_def
+                    .iter()
+                    .map(|value_def| (enum_.name.clone(), value_def))
+            })
+            .collect();
+        if values.is_empty() {
+            return Ok(false);
+        }
+
+        let (name, value_def) = self.u.choose(&values)?;
+        let mut value_def = (*value_def).clone();
+        value_def.description = None;
+        value_def.directives = IndexMap::new();
+        let extension = EnumTypeDef {
+            description: None,
+            name: name.clone(),
+            directives: IndexMap::new(),
+            enum_values_def: IndexSet::from([value_def]),
+            extend: true,
+        };
+        self.enum_type_defs.push(extension);
+        Ok(true)
+    }
+
+    fn deprecate_object_type(&mut self) -> ArbitraryResult<bool> {
+        // A user-defined `@deprecated` may be allowed on objects
+        let deprecated = Name::new(String::from("deprecated"));
+        if self.object_type_defs.is_empty()
+            || self.directive_defs.iter().any(|def| def.name == deprecated)
+        {
+            return Ok(false);
+        }
+
+        let object_index = self.u.choose_index(self.object_type_defs.len())?;
+        self.object_type_defs[object_index].directives.insert(
+            deprecated.clone(),
+            Directive {
+                name: deprecated,
+                arguments: Vec::new(),
+            },
+        );
+        Ok(true)
+    }
+
+    fn apply_undefined_directive(&mut self) -> ArbitraryResult<bool> {
+        if self.object_type_defs.is_empty() {
+            return Ok(false);
+        }
+
+        let mut name = self.type_name()?;
+        while BUILTIN_DIRECTIVE_NAMES.contains(&name.name) {
+            name = self.type_name()?;
+        }
+        let object_index = self.u.choose_index(self.object_type_defs.len())?;
+        self.object_type_defs[object_index].directives.insert(
+            name.clone(),
+            Directive {
+                name,
+                arguments: Vec::new(),
+            },
+        );
+        Ok(true)
+    }
+
+    fn use_undefined_field_type(&mut self) -> ArbitraryResult<bool> {
+        // Fields of interfaces would also be reported on their implementations
+        let candidates: Vec<_> = self
+            .object_type_defs
+            .iter()
+            .enumerate()
+            .flat_map(|(object_index, object)| {
+                object
+                    .fields_def
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, field)| {
+                        self.count_interfaces_declaring(&object.name, &field.name) == 0
+                    })
+                    .map(move |(field_index, _)| (object_index, field_index))
+            })
+            .collect();
+        if candidates.is_empty() {
+            return Ok(false);
+        }
+
+        let mut name = self.type_name()?;
+        while Ty::Named(name.clone()).is_builtin() {
+            name = self.type_name()?;
+        }
+        let (object_index, field_index) = *self.u.choose(&candidates)?;
+        let field = &mut self.object_type_defs[object_index].fields_def[field_index];
+        field.ty = rename_ty(&field.ty, name);
+        Ok(true)
+    }
+
+    /// Number of interfaces implemented by the object type `object` that declare `field`,
+    /// taking extensions into account
+    fn count_interfaces_declaring(&self, object: &Name, field: &Name) -> usize {
+        let interfaces: IndexSet<&Name> = self
+            .object_type_defs
+            .iter()
+            .filter(|def| &def.name == object)
+            .flat_map(|def| &def.implements_interfaces)
+            .collect();
+        interfaces
+            .into_iter()
+            .filter(|interface| {
+                self.interface_type_defs
+                    .iter()
+                    .filter(|def| &def.name == *interface)
+                    .any(|def| def.fields_def.iter().any(|f| &f.name == field))
+            })
+            .count()
+    }
+}
+
+/// Replace the named type of `ty`, keeping its list and non-null wrappers
+fn rename_ty(ty: &Ty, name: Name) -> Ty {
+    match ty {
+        Ty::Named(_) => Ty::Named(name),
+        Ty::List(inner) => Ty::List(Box::new(rename_ty(inner, name))),
+        Ty::NonNull(inner) => Ty::NonNull(Box::new(rename_ty(inner, name))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Fault;
+    use super::BUILTIN_DIRECTIVE_NAMES;
+    use crate::test_utils::{add_interfaces_and_objects, entropy, with_seeded_builder};
+    use crate::DocumentBuilder;
+    use apollo_compiler::Schema;
+    use arbitrary::Unstructured;
+
+    #[test]
+    fn builtin_directive_names_match_the_compiler() {
+        for name in ["skip", "include", "deprecated", "specifiedBy"] {
+            assert!(BUILTIN_DIRECTIVE_NAMES.iter().any(|n| n == name), "{name}");
+        }
+    }
+
+    #[test]
+    fn faults_are_reported_once() {
+        let mut injected = vec![0; Fault::ALL.len()];
+        for seed in 0..30 {
+            let data = entropy(seed, 65536);
+            let mut u = Unstructured::new(&data);
+            let (document, _) = DocumentBuilder::schema_with_operations(&mut u, 1).unwrap();
+            // More object types, so that some implement the generated interfaces
+            let document = add_interfaces_and_objects(document, seed, 0, 15);
+            let valid: String = document.clone().into();
+            Schema::parse_and_validate(&valid, "schema.graphql")
+                .unwrap_or_else(|invalid| panic!("seed {seed}: {}\n{valid}", invalid.errors));
+
+            for (index, &fault) in Fault::ALL.iter().enumerate() {
+                let mutated =
+                    with_seeded_builder(seed, 1024, Some(document.clone()), |mut builder| {
+                        let mutated = builder.mutate_with_fault(fault).unwrap();
+                        mutated.then(|| String::from(builder.finish()))
+                    });
+                let Some(schema) = mutated else {
+                    continue;
+                };
+                injected[index] += 1;
+                let errors = Schema::parse_and_validate(&schema, "schema.graphql")
+                    .expect_err("mutated schema should be invalid")
+                    .errors;
+                let codes: Vec<_> = errors.iter().map(|error| error.error.code()).collect();
+                assert_eq!(codes, [fault.code()], "{fault:?} seed {seed}: {errors}");
+            }
+        }
+        for (fault, injected) in Fault::ALL.iter().zip(injected) {
+            assert!(injected > 10, "{fault:?} injected {injected} times");
+        }
+    }
+}
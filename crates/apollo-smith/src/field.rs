@@ -3,7 +3,7 @@ use crate::{
     description::Description,
     directive::{Directive, DirectiveLocation},
     name::Name,
-    selection_set::SelectionSet,
+    selection_set::{SelectionSet, MAX_SELECTION_DEPTH},
     ty::Ty,
     DocumentBuilder,
 };
@@ -141,9 +141,7 @@ impl<'a> DocumentBuilder<'a> {
             .map(|field_name| {
                 Ok(FieldDef {
                     description: self
-                        .u
-                        .arbitrary()
-                        .unwrap_or(false)
+                        .flip_description()
                         .then(|| self.description())
                         .transpose()?,
                     name: field_name,
@@ -168,7 +166,20 @@ impl<'a> DocumentBuilder<'a> {
             .expect("an object type must be added on the stack")
             .fields_def();
 
-        let chosen_field_def = self.u.choose(fields_defs)?.clone();
+        // Stop nesting once the selection depth limit is reached, if the type allows it
+        let leaf_fields_defs: Vec<&FieldDef> = if self.selection_depth >= MAX_SELECTION_DEPTH {
+            fields_defs
+                .iter()
+                .filter(|field_def| self.is_leaf_type(&field_def.ty))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let chosen_field_def = if leaf_fields_defs.is_empty() {
+            self.u.choose(fields_defs)?.clone()
+        } else {
+            (*self.u.choose(&leaf_fields_defs)?).clone()
+        };
         let mut alias = self
             .u
             .arbitrary()
@@ -176,10 +176,13 @@ impl<'a> DocumentBuilder<'a> {
         &mut self,
         excludes: &mut Vec<Name>,
     ) -> ArbitraryResult<Option<FragmentSpread>> {
+        // Only spread fragments that apply to the current type so the spread is valid
+        let current_type = self.stack.last().map(|ty| ty.name());
         let available_fragment: Vec<&FragmentDef> = self
             .fragment_defs
             .iter()
-            .filter(|f| excludes.contains(&f.name))
+            .filter(|f| !excludes.contains(&f.name))
+            .filter(|f| current_type.is_none() || current_type == Some(&f.type_condition.name))
             .collect();
 
         let name = if available_fragment.is_empty() {
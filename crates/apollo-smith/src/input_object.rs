@@ -119,7 +119,7 @@ impl TryFrom<apollo_parser::cst::InputObjectTypeExtension> for InputObjectTypeDe
 impl<'a> DocumentBuilder<'a> {
     /// Create an arbitrary `InputObjectTypeDef`
     pub fn input_object_type_definition(&mut self) -> ArbitraryResult<InputObjectTypeDef> {
-        let extend = !self.input_object_type_defs.is_empty() && self.u.arbitrary().unwrap_or(false);
+        let extend = !self.input_object_type_defs.is_empty() && self.flip_extend();
         let name = if extend {
             let available_input_objects: Vec<&Name> = self
                 .input_object_type_defs
@@ -137,12 +137,23 @@ impl<'a> DocumentBuilder<'a> {
             self.type_name()?
         };
         let description = self
-            .u
-            .arbitrary()
-            .unwrap_or(false)
+            .flip_description()
             .then(|| self.description())
             .transpose()?;
-        let fields = self.input_values_def()?;
+        // Fields only reference input objects defined before this one, so there are no cycles
+        let mut input_types = self.list_existing_input_types();
+        if let Some(position) = self
+            .input_object_type_defs
+            .iter()
+            .position(|input_object| input_object.name == name)
+        {
+            let later: Vec<&Name> = self.input_object_type_defs[position..]
+                .iter()
+                .map(|input_object| &input_object.name)
+                .collect();
+            input_types.retain(|ty| !later.contains(&ty.name()));
+        }
+        let fields = self.input_values_def_with_types(&input_types)?;
 
         Ok(InputObjectTypeDef {
             description,
@@ -152,4 +163,62 @@ impl<'a> DocumentBuilder<'a> {
             fields,
         })
     }
+
+    /// Returns the fields of the input object named `name`,
+    /// from its definition and all of its extensions
+    pub(crate) fn input_object_fields(&self, name: &Name) -> Option<Vec<InputValueDef>> {
+        let mut defs = self
+            .input_object_type_defs
+            .iter()
+            .filter(|input_object| &input_object.name == name)
+            .peekable();
+        defs.peek()?;
+        Some(defs.flat_map(|def| def.fields.iter().cloned()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::with_seeded_builder;
+    use crate::Document;
+
+    #[test]
+    fn fields_only_reference_earlier_input_objects() {
+        let schema = "input A { x: Int } input B { a: A }";
+        let document: Document = apollo_parser::Parser::new(schema)
+            .parse()
+            .document()
+            .try_into()
+            .unwrap();
+        let mut references = 0;
+        for seed in 0..20 {
+            with_seeded_builder(seed, 4096, Some(document.clone()), |mut builder| {
+                for _ in 0..10 {
+                    let input_object = builder.input_object_type_definition().unwrap();
+                    builder.input_object_type_defs.push(input_object);
+                }
+                let defs = &builder.input_object_type_defs;
+                for (position, input_object) in defs.iter().enumerate() {
+                    let first = defs
+                        .iter()
+                        .position(|def| def.name == input_object.name)
+                        .unwrap();
+                    for field in &input_object.fields {
+                        let Some(target) = defs.iter().position(|def| &def.name == field.ty.name())
+                        else {
+                            continue;
+                        };
+                        assert!(
+                            target < first,
+                            "{} at {position} references {}",
+                            input_object.name.name,
+                            field.ty.name().name
+                        );
+                        references += 1;
+                    }
+                }
+            });
+        }
+        assert!(references > 0);
+    }
 }
@@ -177,7 +177,7 @@ impl<'a> DocumentBuilder<'a> {
             // Float
             1 => InputValue::Float(self.finite_f64()?),
             // String
-            2 => InputValue::String(self.limited_string(40)?),
+            2 => InputValue::String(self.string_value(40)?),
             // Boolean
             3 => InputValue::Boolean(self.u.arbitrary()?),
             // Null
@@ -215,90 +215,124 @@ impl<'a> DocumentBuilder<'a> {
         Ok(val)
     }
 
+    /// Create an arbitrary `InputValue` of the given type
+    ///
+    /// Fails with [`arbitrary::Error::IncorrectFormat`] if `ty` is not an input type.
     pub fn input_value_for_type(&mut self, ty: &Ty) -> ArbitraryResult<InputValue> {
-        let gen_val = |doc_builder: &mut DocumentBuilder<'_>| -> ArbitraryResult<InputValue> {
-            if ty.is_builtin() {
-                match ty.name().name.as_str() {
-                    "String" => Ok(InputValue::String(doc_builder.limited_string(1000)?)),
-                    "Int" => Ok(InputValue::Int(doc_builder.u.arbitrary()?)),
-                    "Float" => Ok(InputValue::Float(doc_builder.finite_f64()?)),
-                    "Boolean" => Ok(InputValue::Boolean(doc_builder.u.arbitrary()?)),
-                    "ID" => Ok(InputValue::Int(doc_builder.u.arbitrary()?)),
-                    other => {
-                        unreachable!("{} is not a builtin", other);
+        let val = match ty {
+            Ty::Named(name) => {
+                if ty.is_builtin() {
+                    match name.name.as_str() {
+                        "String" => InputValue::String(self.string_value(1000)?),
+                        "Int" => InputValue::Int(self.u.arbitrary()?),
+                        "Float" => InputValue::Float(self.finite_f64()?),
+                        "Boolean" => InputValue::Boolean(self.u.arbitrary()?),
+                        "ID" => InputValue::Int(self.u.arbitrary()?),
+                        other => {
+                            unreachable!("{} is not a builtin", other);
+                        }
+                    }
+                } else if let Some(enum_) = self
+                    .enum_type_defs
+                    .iter()
+                    .find(|e| &e.name == name)
+                    .cloned()
+                {
+                    InputValue::Enum(self.arbitrary_variant(&enum_)?.clone())
+                } else if let Some(input_fields) = self.input_object_fields(name) {
+                    let mut fields = Vec::with_capacity(input_fields.len());
+                    for field in &input_fields {
+                        // Optional fields may be omitted, they take their default value
+                        if matches!(field.ty, Ty::NonNull(_)) || self.u.arbitrary()? {
+                            fields
+                                .push((field.name.clone(), self.input_value_for_type(&field.ty)?));
+                        }
                     }
+                    InputValue::Object(fields)
+                } else if self.scalar_type_defs.iter().any(|s| &s.name == name) {
+                    // Custom scalars accept literals of any built-in scalar type
+                    match self.u.int_in_range(0..=3usize)? {
+                        0 => InputValue::String(self.string_value(1000)?),
+                        1 => InputValue::Int(self.u.arbitrary()?),
+                        2 => InputValue::Float(self.finite_f64()?),
+                        _ => InputValue::Boolean(self.u.arbitrary()?),
+                    }
+                } else {
+                    // Not an input type, so no value is valid for it
+                    return Err(arbitrary::Error::IncorrectFormat);
                 }
-            } else if let Some(enum_) = doc_builder
-                .enum_type_defs
-                .iter()
-                .find(|e| &e.name == ty.name())
-                .cloned()
-            {
-                Ok(InputValue::Enum(
-                    doc_builder.arbitrary_variant(&enum_)?.clone(),
-                ))
-            } else if let Some(object_ty) = doc_builder
-                .object_type_defs
-                .iter()
-                .find(|o| &o.name == ty.name())
-                .cloned()
-            {
-                Ok(InputValue::Object(
-                    object_ty
-                        .fields_def
-                        .iter()
-                        .map(|field_def| {
-                            Ok((
-                                field_def.name.clone(),
-                                doc_builder.input_value_for_type(&field_def.ty)?,
-                            ))
-                        })
-                        .collect::<ArbitraryResult<Vec<_>>>()?,
-                ))
-            } else {
-                todo!()
             }
-        };
-
-        let val = match ty {
-            Ty::Named(_) => gen_val(self)?,
-            Ty::List(_) => {
-                let nb_elt = self.u.int_in_range(1..=25usize)?;
+            Ty::List(item_ty) => {
+                // The number of values grows exponentially with nested lists, keep them short
+                let max_elt = match item_ty.as_ref() {
+                    Ty::List(_) => 3,
+                    Ty::NonNull(inner) if matches!(inner.as_ref(), Ty::List(_)) => 3,
+                    _ => 25,
+                };
+                let nb_elt = self.u.int_in_range(1..=max_elt)?;
                 InputValue::List(
                     (0..nb_elt)
-                        .map(|_| gen_val(self))
+                        .map(|_| self.input_value_for_type(item_ty))
                         .collect::<ArbitraryResult<Vec<InputValue>>>()?,
                 )
             }
-            Ty::NonNull(_) => gen_val(self)?,
+            Ty::NonNull(inner_ty) => self.input_value_for_type(inner_ty)?,
         };
 
         Ok(val)
     }
 
+    /// Create an arbitrary default value for an input value definition of the given type
+    ///
+    /// Default values match `ty`, unless
+    /// [`BuilderOptions::random_default_values`][crate::BuilderOptions::random_default_values]
+    /// is enabled.
+    pub(crate) fn default_value(&mut self, ty: &Ty) -> ArbitraryResult<Option<InputValue>> {
+        if !self.u.arbitrary().unwrap_or(false) {
+            return Ok(None);
+        }
+        if self.options.random_default_values {
+            return self.input_value(Constness::Const).map(Some);
+        }
+        if !self.is_input_type(ty) {
+            return Ok(None);
+        }
+        self.input_value_for_type(ty).map(Some)
+    }
+
+    /// Returns `true` if a value of this type can be written in a document
+    fn is_input_type(&self, ty: &Ty) -> bool {
+        let name = ty.name();
+        ty.is_builtin()
+            || self.enum_type_defs.iter().any(|e| &e.name == name)
+            || self.scalar_type_defs.iter().any(|s| &s.name == name)
+            || self.input_object_type_defs.iter().any(|o| &o.name == name)
+    }
+
     /// Create an arbitrary list of `InputValueDef`
     pub fn input_values_def(&mut self) -> ArbitraryResult<Vec<InputValueDef>> {
+        let input_types = self.list_existing_input_types();
+        self.input_values_def_with_types(&input_types)
+    }
+
+    /// Create an arbitrary list of `InputValueDef` whose types are chosen from `input_types`
+    pub(crate) fn input_values_def_with_types(
+        &mut self,
+        input_types: &[Ty],
+    ) -> ArbitraryResult<Vec<InputValueDef>> {
         let arbitrary_iv_num = self.u.int_in_range(2..=5usize)?;
         let mut input_values = Vec::with_capacity(arbitrary_iv_num - 1);
 
         for i in 0..arbitrary_iv_num {
             let description = self
-                .u
-                .arbitrary()
-                .unwrap_or(false)
+                .flip_description()
                 .then(|| self.description())
                 .transpose()?;
             let name = self.name_with_index(i)?;
-            let ty = self.choose_ty(&self.list_existing_types())?;
+            let ty = self.choose_ty(input_types)?;
             // TODO: incorrect because input_values_def is called from different locations
             let directives = self.directives(DirectiveLocation::InputFieldDefinition)?;
-            // TODO: FIXME: it's not correct I need to generate default value corresponding to the ty above
-            let default_value = self
-                .u
-                .arbitrary()
-                .unwrap_or(false)
-                .then(|| self.input_value(Constness::Const))
-                .transpose()?;
+            let default_value = self.default_value(&ty)?;
 
             input_values.push(InputValueDef {
                 description,
@@ -314,22 +348,14 @@ impl<'a> DocumentBuilder<'a> {
     /// Create an arbitrary `InputValueDef`
     pub fn input_value_def(&mut self) -> ArbitraryResult<InputValueDef> {
         let description = self
-            .u
-            .arbitrary()
-            .unwrap_or(false)
+            .flip_description()
             .then(|| self.description())
             .transpose()?;
         let name = self.name()?;
-        let ty = self.choose_ty(&self.list_existing_types())?;
+        let ty = self.choose_ty(&self.list_existing_input_types())?;
         // TODO: incorrect because input_values_def is called from different locations
         let directives = self.directives(DirectiveLocation::InputFieldDefinition)?;
-        // TODO: FIXME: it's not correct I need to generate default value corresponding to the ty above
-        let default_value = self
-            .u
-            .arbitrary()
-            .unwrap_or(false)
-            .then(|| self.input_value(Constness::Const))
-            .transpose()?;
+        let default_value = self.default_value(&ty)?;
 
         Ok(InputValueDef {
             description,
@@ -352,11 +378,11 @@ impl<'a> DocumentBuilder<'a> {
 
 #[cfg(test)]
 mod tests {
-    use indexmap::{IndexMap, IndexSet};
+    use indexmap::IndexMap;
 
     use arbitrary::Unstructured;
 
-    use crate::{field::FieldDef, ObjectTypeDef};
+    use crate::InputObjectTypeDef;
 
     use super::*;
 
@@ -379,59 +405,51 @@ mod tests {
             stack: Vec::new(),
             chosen_arguments: IndexMap::new(),
             chosen_aliases: IndexMap::new(),
+            selection_depth: 0,
+            options: crate::BuilderOptions::default(),
         };
-        let my_nested_type = ObjectTypeDef {
+        // Non-null fields are always present, so no input is used to choose them
+        let my_nested_type = InputObjectTypeDef {
             description: None,
             name: Name {
                 name: String::from("my_nested_object"),
             },
-            implements_interfaces: IndexSet::new(),
-            directives: IndexMap::new(),
-            fields_def: vec![FieldDef {
+            fields: vec![InputValueDef {
                 description: None,
                 name: Name {
                     name: String::from("value"),
                 },
-                arguments_definition: None,
-                ty: Ty::Named(Name {
+                ty: Ty::NonNull(Box::new(Ty::Named(Name {
                     name: String::from("String"),
-                }),
+                }))),
+                default_value: None,
                 directives: IndexMap::new(),
             }],
+            directives: IndexMap::new(),
             extend: false,
         };
 
-        let my_object_type = ObjectTypeDef {
+        let my_object_type = InputObjectTypeDef {
             description: None,
             name: Name {
                 name: String::from("my_object"),
             },
-            implements_interfaces: IndexSet::new(),
-            directives: IndexMap::new(),
-            fields_def: vec![FieldDef {
+            fields: vec![InputValueDef {
                 description: None,
                 name: Name {
                     name: String::from("first"),
                 },
-                arguments_definition: None,
-                ty: Ty::List(Box::new(Ty::Named(Name {
+                ty: Ty::NonNull(Box::new(Ty::List(Box::new(Ty::Named(Name {
                     name: String::from("my_nested_object"),
-                }))),
+                }))))),
+                default_value: None,
                 directives: IndexMap::new(),
             }],
+            directives: IndexMap::new(),
             extend: false,
         };
-        document_builder.object_type_defs.push(my_nested_type);
-        document_builder.object_type_defs.push(my_object_type);
-
-        let my_type_to_find = Ty::List(Box::new(Ty::Named(Name {
-            name: String::from("my_object"),
-        })));
-        document_builder.object_type_defs.iter().find(|o| {
-            let res = &o.name == my_type_to_find.name();
-
-            res
-        });
+        document_builder.input_object_type_defs.push(my_nested_type);
+        document_builder.input_object_type_defs.push(my_object_type);
 
         let input_val = document_builder
             .input_value_for_type(&Ty::List(Box::new(Ty::Named(Name {
@@ -450,3 +468,116 @@ mod tests {
         );
     }
 }
+
+#[cfg(test)]
+mod default_value_tests {
+    use super::InputValue;
+    use crate::test_utils::with_seeded_builder;
+    use crate::{name::Name, ty::Ty, Document, DocumentBuilder};
+
+    fn value_matches_type(builder: &DocumentBuilder<'_>, ty: &Ty, value: &InputValue) -> bool {
+        match (ty, value) {
+            (Ty::NonNull(_), InputValue::Null) => false,
+            (Ty::NonNull(inner), _) => value_matches_type(builder, inner, value),
+            (_, InputValue::Null) => true,
+            (Ty::List(inner), InputValue::List(items)) => items
+                .iter()
+                .all(|item| value_matches_type(builder, inner, item)),
+            (Ty::List(inner), _) => value_matches_type(builder, inner, value),
+            (Ty::Named(name), _) => match (name.name.as_str(), value) {
+                ("String", InputValue::String(_))
+                | ("Int" | "ID", InputValue::Int(_))
+                | ("Float", InputValue::Float(_) | InputValue::Int(_))
+                | ("Boolean", InputValue::Boolean(_)) => true,
+                ("String" | "Int" | "ID" | "Float" | "Boolean", _) => false,
+                _ => {
+                    if let Some(enum_) = builder.enum_type_defs.iter().find(|e| &e.name == name) {
+                        matches!(value, InputValue::Enum(variant)
+                            if enum_.enum_values_def.iter().any(|v| &v.value == variant))
+                    } else if let Some(fields) = builder.input_object_fields(name) {
+                        let InputValue::Object(values) = value else {
+                            return false;
+                        };
+                        let known_fields = values.iter().all(|(name, value)| {
+                            fields.iter().any(|field| {
+                                &field.name == name && value_matches_type(builder, &field.ty, value)
+                            })
+                        });
+                        let required_fields = fields
+                            .iter()
+                            .filter(|field| {
+                                matches!(field.ty, Ty::NonNull(_)) && field.default_value.is_none()
+                            })
+                            .all(|field| values.iter().any(|(name, _)| name == &field.name));
+                        known_fields && required_fields
+                    } else {
+                        builder.scalar_type_defs.iter().any(|s| &s.name == name)
+                    }
+                }
+            },
+        }
+    }
+
+    fn count_defaults(random_default_values: bool) -> (usize, usize) {
+        let (mut matching, mut mismatched) = (0, 0);
+        for seed in 0..16 {
+            with_seeded_builder(seed, 65536, None, |mut builder| {
+                builder.options.random_default_values = random_default_values;
+                for _ in 0..100 {
+                    let Ok(def) = builder.input_value_def() else {
+                        break;
+                    };
+                    if let Some(default_value) = &def.default_value {
+                        if value_matches_type(&builder, &def.ty, default_value) {
+                            matching += 1;
+                        } else {
+                            mismatched += 1;
+                        }
+                    }
+                }
+            });
+        }
+        (matching, mismatched)
+    }
+
+    #[test]
+    fn generated_default_values_match_their_type() {
+        let (matching, mismatched) = count_defaults(false);
+        assert!(matching > 0);
+        assert_eq!(mismatched, 0);
+
+        let (_, mismatched) = count_defaults(true);
+        assert!(mismatched > 0);
+    }
+
+    #[test]
+    fn default_values_of_input_objects_include_extension_fields() {
+        let schema = "input Point { x: Int!, y: Int } extend input Point { z: Int! }";
+        let document: Document = apollo_parser::Parser::new(schema)
+            .parse()
+            .document()
+            .try_into()
+            .unwrap();
+        let ty = Ty::Named(Name {
+            name: String::from("Point"),
+        });
+        let mut generated = 0;
+        for seed in 0..20 {
+            with_seeded_builder(seed, 1024, Some(document.clone()), |mut builder| {
+                let Ok(Some(default_value)) = builder.default_value(&ty) else {
+                    return;
+                };
+                assert!(
+                    value_matches_type(&builder, &ty, &default_value),
+                    "{default_value:?}"
+                );
+                let InputValue::Object(fields) = &default_value else {
+                    panic!("expected an object: {default_value:?}");
+                };
+                assert!(fields.iter().any(|(name, _)| name.name == "z"));
+                generated += 1;
+            });
+        }
+        assert!(generated > 0);
+    }
+}
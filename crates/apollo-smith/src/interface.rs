@@ -137,11 +137,9 @@ impl TryFrom<apollo_parser::cst::InterfaceTypeExtension> for InterfaceTypeDef {
 impl<'a> DocumentBuilder<'a> {
     /// Create an arbitrary `InterfaceTypeDef`
     pub fn interface_type_definition(&mut self) -> ArbitraryResult<InterfaceTypeDef> {
-        let extend = !self.interface_type_defs.is_empty() && self.u.arbitrary().unwrap_or(false);
+        let extend = !self.interface_type_defs.is_empty() && self.flip_extend();
         let description = self
-            .u
-            .arbitrary()
-            .unwrap_or(false)
+            .flip_description()
             .then(|| self.description())
             .transpose()?;
         let name = if extend {
@@ -154,9 +152,53 @@ impl<'a> DocumentBuilder<'a> {
         } else {
             self.type_name()?
         };
-        let fields_def = self.fields_definition(&[])?;
+        let mut interfaces = self.implements_interfaces()?;
+        // An extension may pick the interface it extends or interfaces implementing it,
+        // which would be a cycle, or interfaces it already implements
+        interfaces.retain(|itf| {
+            itf != &name
+                && !self
+                    .transitive_interfaces(&IndexSet::from([itf.clone()]))
+                    .contains(&name)
+        });
+        for itf in self
+            .interface_type_defs
+            .iter()
+            .filter(|itf| itf.name == name)
+        {
+            interfaces.retain(|other| !itf.interfaces.contains(other));
+        }
+        // Fields of the interface being extended must agree with the new interfaces
+        let extended_fields: Vec<FieldDef> = self
+            .interface_type_defs
+            .iter()
+            .filter(|itf| itf.name == name)
+            .flat_map(|itf| itf.fields_def.iter().cloned())
+            .collect();
+        interfaces.retain(|itf| {
+            let implemented = self.transitive_interfaces(&IndexSet::from([itf.clone()]));
+            self.interface_fields_agree(&implemented, &extended_fields)
+        });
+        let existing_fields = self.interface_field_names(&name);
+        let implements_fields: Vec<FieldDef> = self
+            .interface_fields(&interfaces)
+            .into_iter()
+            .filter(|field| !existing_fields.contains(&field.name))
+            .collect();
+
+        let mut fields_def = self.fields_definition(
+            &implements_fields
+                .iter()
+                .map(|f| &f.name)
+                .chain(&existing_fields)
+                .collect::<Vec<&Name>>(),
+        )?;
+        // Add fields coming from interfaces
+        fields_def.extend(implements_fields);
+        if extend {
+            self.add_to_implementers(&name, &fields_def, &interfaces);
+        }
         let directives = self.directives(DirectiveLocation::Interface)?;
-        let interfaces = self.implements_interfaces()?;
 
         Ok(InterfaceTypeDef {
             description,
@@ -168,6 +210,80 @@ impl<'a> DocumentBuilder<'a> {
         })
     }
 
+    /// Returns the fields that types implementing the given interfaces must define,
+    /// from the interface definitions and their extensions
+    pub(crate) fn interface_fields(&self, interfaces: &IndexSet<Name>) -> Vec<FieldDef> {
+        let mut fields = IndexMap::new();
+        for itf in &self.interface_type_defs {
+            if interfaces.contains(&itf.name) {
+                for field in &itf.fields_def {
+                    fields
+                        .entry(field.name.clone())
+                        .or_insert_with(|| field.clone());
+                }
+            }
+        }
+        fields.into_values().collect()
+    }
+
+    /// Returns the names of the fields already defined by an interface and its extensions
+    fn interface_field_names(&self, name: &Name) -> Vec<Name> {
+        self.interface_type_defs
+            .iter()
+            .filter(|itf| &itf.name == name)
+            .flat_map(|itf| itf.fields_def.iter().map(|field| field.name.clone()))
+            .collect()
+    }
+
+    /// Add the fields and implemented interfaces of an interface extension to the types
+    /// that already implement the interface, and to the types implementing those in turn
+    fn add_to_implementers(
+        &mut self,
+        interface: &Name,
+        fields: &[FieldDef],
+        implemented: &IndexSet<Name>,
+    ) {
+        let mut queue = vec![interface.clone()];
+        let mut seen = IndexSet::new();
+        while let Some(interface) = queue.pop() {
+            if !seen.insert(interface.clone()) {
+                continue;
+            }
+            let objects: IndexSet<Name> = self
+                .object_type_defs
+                .iter()
+                .filter(|object| object.implements_interfaces.contains(&interface))
+                .map(|object| object.name.clone())
+                .collect();
+            for name in objects {
+                let parts = self.object_type_defs.iter_mut().filter(|o| o.name == name);
+                let (fields_defs, implements): (Vec<_>, Vec<_>) = parts
+                    .map(|object| (&mut object.fields_def, &mut object.implements_interfaces))
+                    .unzip();
+                add_missing_fields(fields_defs.into_iter(), fields);
+                add_missing_interfaces(implements, implemented);
+            }
+            let interfaces: IndexSet<Name> = self
+                .interface_type_defs
+                .iter()
+                .filter(|itf| itf.name != interface && itf.interfaces.contains(&interface))
+                .map(|itf| itf.name.clone())
+                .collect();
+            for name in interfaces {
+                let parts = self
+                    .interface_type_defs
+                    .iter_mut()
+                    .filter(|i| i.name == name);
+                let (fields_defs, implements): (Vec<_>, Vec<_>) = parts
+                    .map(|itf| (&mut itf.fields_def, &mut itf.interfaces))
+                    .unzip();
+                add_missing_fields(fields_defs.into_iter(), fields);
+                add_missing_interfaces(implements, implemented);
+                queue.push(name);
+            }
+        }
+    }
+
     /// Create an arbitrary `IndexSet` of implemented interfaces
     pub fn implements_interfaces(&mut self) -> ArbitraryResult<IndexSet<Name>> {
         if self.interface_type_defs.is_empty() {
@@ -180,11 +296,83 @@ impl<'a> DocumentBuilder<'a> {
         let mut interface_impls = IndexSet::with_capacity(num_itf);
 
         for _ in 0..num_itf {
-            interface_impls.insert(self.u.choose(&self.interface_type_defs)?.name.clone());
+            let mut candidate = interface_impls.clone();
+            candidate.insert(self.u.choose(&self.interface_type_defs)?.name.clone());
+            // Implementing an interface requires implementing the interfaces it implements
+            let candidate = self.transitive_interfaces(&candidate);
+            if self.interface_fields_agree(&candidate, &[]) {
+                interface_impls = candidate;
+            }
         }
 
         Ok(interface_impls)
     }
+
+    /// Returns whether fields with the same name have the same type and arguments
+    /// in all of the given interfaces and in `fields`,
+    /// so that a type defining `fields` can implement them all
+    pub(crate) fn interface_fields_agree(
+        &self,
+        interfaces: &IndexSet<Name>,
+        fields: &[FieldDef],
+    ) -> bool {
+        let mut fields: IndexMap<&Name, &FieldDef> =
+            fields.iter().map(|field| (&field.name, field)).collect();
+        self.interface_type_defs
+            .iter()
+            .filter(|itf| interfaces.contains(&itf.name))
+            .flat_map(|itf| &itf.fields_def)
+            .all(|field| {
+                let first = *fields.entry(&field.name).or_insert(field);
+                first.ty == field.ty && first.arguments_definition == field.arguments_definition
+            })
+    }
+
+    /// Returns the given interfaces and all the interfaces they implement, directly or not
+    pub(crate) fn transitive_interfaces(&self, interfaces: &IndexSet<Name>) -> IndexSet<Name> {
+        let mut all = interfaces.clone();
+        let mut index = 0;
+        while let Some(name) = all.get_index(index).cloned() {
+            for itf in self
+                .interface_type_defs
+                .iter()
+                .filter(|itf| itf.name == name)
+            {
+                all.extend(itf.interfaces.iter().cloned());
+            }
+            index += 1;
+        }
+        all
+    }
+}
+
+/// Add the `interfaces` that are not implemented by any part of a type to its first part
+fn add_missing_interfaces(mut parts: Vec<&mut IndexSet<Name>>, interfaces: &IndexSet<Name>) {
+    let missing: Vec<Name> = interfaces
+        .iter()
+        .filter(|itf| !parts.iter().any(|part| part.contains(*itf)))
+        .cloned()
+        .collect();
+    if let Some(first) = parts.first_mut() {
+        first.extend(missing);
+    }
+}
+
+/// Add the `fields` that are not defined by any part of a type to its first part
+fn add_missing_fields<'a>(parts: impl Iterator<Item = &'a mut Vec<FieldDef>>, fields: &[FieldDef]) {
+    let mut parts: Vec<_> = parts.collect();
+    let missing: Vec<FieldDef> = fields
+        .iter()
+        .filter(|field| {
+            !parts
+                .iter()
+                .any(|part| part.iter().any(|f| f.name == field.name))
+        })
+        .cloned()
+        .collect();
+    if let Some(first) = parts.first_mut() {
+        first.extend(missing);
+    }
 }
 
 impl StackedEntity for InterfaceTypeDef {
@@ -196,3 +384,63 @@ impl StackedEntity for InterfaceTypeDef {
         &self.fields_def
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{add_interfaces_and_objects, entropy};
+    use crate::DocumentBuilder;
+    use apollo_compiler::schema::ExtendedType;
+    use apollo_compiler::Schema;
+    use arbitrary::Unstructured;
+
+    #[test]
+    fn implements_only_interfaces() {
+        let mut implementations = 0;
+        for seed in 0..20 {
+            let data = entropy(seed, 4096);
+            let mut u = Unstructured::new(&data);
+            let document = DocumentBuilder::new(&mut u).unwrap().finish();
+            let document = add_interfaces_and_objects(document, seed, 10, 20);
+
+            let interfaces: Vec<_> = document
+                .interface_type_definitions
+                .iter()
+                .map(|itf| &itf.name)
+                .collect();
+            let implemented = document
+                .object_type_definitions
+                .iter()
+                .flat_map(|object| &object.implements_interfaces)
+                .chain(
+                    document
+                        .interface_type_definitions
+                        .iter()
+                        .flat_map(|itf| &itf.interfaces),
+                );
+            for name in implemented {
+                implementations += 1;
+                assert!(interfaces.contains(&name), "{name:?} is not an interface");
+            }
+
+            // Names are not shared with other kinds of types either
+            let document: String = document.into();
+            let schema = Schema::parse(&document, "schema.graphql")
+                .unwrap_or_else(|with_errors| with_errors.partial);
+            for ty in schema.types.values() {
+                let implements_interfaces = match ty {
+                    ExtendedType::Object(def) => &def.implements_interfaces,
+                    ExtendedType::Interface(def) => &def.implements_interfaces,
+                    _ => continue,
+                };
+                for name in implements_interfaces {
+                    assert!(
+                        schema.get_interface(name).is_some(),
+                        "`{}` implements `{name}`, which is not an interface",
+                        ty.name()
+                    );
+                }
+            }
+        }
+        assert!(implementations > 0);
+    }
+}
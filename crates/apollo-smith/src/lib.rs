@@ -5,6 +5,7 @@ pub(crate) mod description;
 pub(crate) mod directive;
 pub(crate) mod document;
 pub(crate) mod enum_;
+pub(crate) mod fault;
 pub(crate) mod field;
 pub(crate) mod fragment;
 pub(crate) mod input_object;
@@ -13,14 +14,19 @@ pub(crate) mod interface;
 pub(crate) mod name;
 pub(crate) mod object;
 pub(crate) mod operation;
+pub(crate) mod options;
 pub(crate) mod scalar;
 pub(crate) mod schema;
 pub(crate) mod selection_set;
 #[cfg(test)]
 pub(crate) mod snapshot_tests;
+pub(crate) mod stats;
+#[cfg(test)]
+pub(crate) mod test_utils;
 pub(crate) mod ty;
 pub(crate) mod union;
 pub(crate) mod variable;
+pub(crate) mod weights;
 
 use indexmap::IndexMap;
 use std::fmt::Debug;
@@ -44,6 +50,7 @@ use argument::Argument;
 pub use directive::DirectiveDef;
 pub use document::Document;
 pub use enum_::EnumTypeDef;
+pub use fault::Fault;
 use field::FieldDef;
 pub use fragment::FragmentDef;
 pub use input_object::InputObjectTypeDef;
@@ -51,10 +58,13 @@ pub use interface::InterfaceTypeDef;
 use name::Name;
 pub use object::ObjectTypeDef;
 pub use operation::OperationDef;
+pub use options::BuilderOptions;
 pub use scalar::ScalarTypeDef;
 pub use schema::SchemaDef;
+pub use stats::DocumentStats;
 use ty::Ty;
 pub use union::UnionTypeDef;
+pub use weights::Weights;
 
 /// DocumentBuilder is a struct to build an arbitrary valid GraphQL document
 ///
@@ -93,6 +103,9 @@ pub struct DocumentBuilder<'a> {
     pub(crate) chosen_arguments: IndexMap<Name, Vec<Argument>>,
     // Useful to keep the same aliases for a specific field name
     pub(crate) chosen_aliases: IndexMap<Name, Name>,
+    // Number of selection sets currently being generated, to bound nesting
+    pub(crate) selection_depth: usize,
+    pub(crate) options: BuilderOptions,
 }
 
 impl<'a> Debug for DocumentBuilder<'a> {
@@ -115,7 +128,17 @@ impl<'a> Debug for DocumentBuilder<'a> {
 impl<'a> DocumentBuilder<'a> {
     /// Create an instance of `DocumentBuilder`
     pub fn new(u: &'a mut Unstructured<'a>) -> Result<Self> {
-        let mut builder = Self {
+        Self::with_options(u, BuilderOptions::default())
+    }
+
+    /// Create an instance of `DocumentBuilder` that generates a document with the given
+    /// [`BuilderOptions`].
+    pub fn with_options(u: &'a mut Unstructured<'a>, options: BuilderOptions) -> Result<Self> {
+        Self::empty(u, options).generate()
+    }
+
+    fn empty(u: &'a mut Unstructured<'a>, options: BuilderOptions) -> Self {
+        Self {
             u,
             object_type_defs: Vec::new(),
             interface_type_defs: Vec::new(),
@@ -130,60 +153,130 @@ impl<'a> DocumentBuilder<'a> {
             stack: Vec::new(),
             chosen_arguments: IndexMap::new(),
             chosen_aliases: IndexMap::new(),
+            selection_depth: 0,
+            options,
+        }
+    }
+
+    /// Create a schema and `operations` operation definitions that are valid against it,
+    /// returned as a type system document and an executable document.
+    ///
+    /// Unlike [`DocumentBuilder::new`], this does not generate fragment definitions,
+    /// and names every operation when there are several of them.
+    /// Operations only select fields of the generated types.
+    /// The schema passes [`apollo_compiler::Schema::parse_and_validate`],
+    /// and the operations pass [`apollo_compiler::ExecutableDocument::parse_and_validate`]
+    /// against it.
+    ///
+    /// ```rust
+    /// use apollo_compiler::{ast, ExecutableDocument, Schema};
+    /// use apollo_smith::DocumentBuilder;
+    /// use arbitrary::Unstructured;
+    ///
+    /// let data: Vec<u8> = (0..4096_u32).map(|i| (i * 37 % 251) as u8).collect();
+    /// let mut u = Unstructured::new(&data);
+    /// let (schema, operations) = DocumentBuilder::schema_with_operations(&mut u, 3).unwrap();
+    ///
+    /// let schema = Schema::parse_and_validate(String::from(schema), "schema.graphql").unwrap();
+    /// let operations = ast::Document::from(operations);
+    /// assert_eq!(operations.definitions.len(), 3);
+    /// operations
+    ///     .to_executable_validate(&schema)
+    ///     .unwrap();
+    /// ```
+    pub fn schema_with_operations(
+        u: &'a mut Unstructured<'a>,
+        operations: usize,
+    ) -> Result<(Document, Document)> {
+        let mut builder = Self::empty(u, BuilderOptions::default());
+        builder.generate_types()?;
+        builder.generate_directives()?;
+        let schema_def = builder.schema_definition()?;
+        builder.schema_def = Some(schema_def);
+
+        for _ in 0..operations {
+            // Never None: the schema definition was just generated
+            if let Some(mut operation_def) = builder.operation_definition()? {
+                if operation_def.name.is_none() && operations > 1 {
+                    operation_def.name = Some(builder.type_name()?);
+                }
+                builder.operation_defs.push(operation_def);
+            }
+        }
+
+        let mut schema = builder.finish();
+        let executable = Document {
+            operation_definitions: std::mem::take(&mut schema.operation_definitions),
+            ..Document::default()
         };
+        Ok((schema, executable))
+    }
 
-        for _ in 0..builder.u.int_in_range(1..=50)? {
-            let scalar_type_def = builder.scalar_type_definition()?;
-            builder.scalar_type_defs.push(scalar_type_def);
+    fn generate(mut self) -> Result<Self> {
+        self.generate_types()?;
+
+        for _ in 0..self.u.int_in_range(1..=50)? {
+            let fragment_def = self.fragment_definition()?;
+            self.fragment_defs.push(fragment_def);
         }
 
-        for _ in 0..builder.u.int_in_range(1..=50)? {
-            let enum_type_def = builder.enum_type_definition()?;
-            builder.enum_type_defs.push(enum_type_def);
+        self.generate_directives()?;
+
+        let schema_def = self.schema_definition()?;
+        self.schema_def = Some(schema_def);
+
+        for _ in 0..self.u.int_in_range(1..=50)? {
+            let operation_def = self.operation_definition()?;
+            // Could be None if there is no schema definition (in this case it never happens)
+            if let Some(operation_def) = operation_def {
+                self.operation_defs.push(operation_def);
+            }
         }
 
-        for _ in 0..builder.u.int_in_range(1..=50)? {
-            let interface_type_def = builder.interface_type_definition()?;
-            builder.interface_type_defs.push(interface_type_def);
+        Ok(self)
+    }
+
+    fn generate_types(&mut self) -> Result<()> {
+        for _ in 0..self.u.int_in_range(1..=50)? {
+            let scalar_type_def = self.scalar_type_definition()?;
+            self.scalar_type_defs.push(scalar_type_def);
         }
 
-        for _ in 0..builder.u.int_in_range(1..=50)? {
-            let object_type_def = builder.object_type_definition()?;
-            builder.object_type_defs.push(object_type_def);
+        for _ in 0..self.u.int_in_range(1..=50)? {
+            let enum_type_def = self.enum_type_definition()?;
+            self.enum_type_defs.push(enum_type_def);
         }
 
-        for _ in 0..builder.u.int_in_range(1..=50)? {
-            let union_type_def = builder.union_type_definition()?;
-            builder.union_type_defs.push(union_type_def);
+        for _ in 0..self.u.int_in_range(1..=50)? {
+            let interface_type_def = self.interface_type_definition()?;
+            self.interface_type_defs.push(interface_type_def);
         }
 
-        for _ in 0..builder.u.int_in_range(1..=50)? {
-            let input_object_type_def = builder.input_object_type_definition()?;
-            builder.input_object_type_defs.push(input_object_type_def);
+        for _ in 0..self.u.int_in_range(1..=50)? {
+            let object_type_def = self.object_type_definition()?;
+            self.object_type_defs.push(object_type_def);
         }
 
-        for _ in 0..builder.u.int_in_range(1..=50)? {
-            let fragment_def = builder.fragment_definition()?;
-            builder.fragment_defs.push(fragment_def);
+        for _ in 0..self.u.int_in_range(1..=50)? {
+            let union_type_def = self.union_type_definition()?;
+            self.union_type_defs.push(union_type_def);
         }
 
-        for _ in 0..builder.u.int_in_range(1..=50)? {
-            let directive_def = builder.directive_def()?;
-            builder.directive_defs.push(directive_def);
+        for _ in 0..self.u.int_in_range(1..=50)? {
+            let input_object_type_def = self.input_object_type_definition()?;
+            self.input_object_type_defs.push(input_object_type_def);
         }
 
-        let schema_def = builder.schema_definition()?;
-        builder.schema_def = Some(schema_def);
+        Ok(())
+    }
 
-        for _ in 0..builder.u.int_in_range(1..=50)? {
-            let operation_def = builder.operation_definition()?;
-            // Could be None if there is no schema definition (in this case it never happens)
-            if let Some(operation_def) = operation_def {
-                builder.operation_defs.push(operation_def);
-            }
+    fn generate_directives(&mut self) -> Result<()> {
+        for _ in 0..self.u.int_in_range(1..=50)? {
+            let directive_def = self.directive_def()?;
+            self.directive_defs.push(directive_def);
         }
 
-        Ok(builder)
+        Ok(())
     }
 
     /// Create an instance of `DocumentBuilder` given a `Document` to be able to call
@@ -204,6 +297,8 @@ impl<'a> DocumentBuilder<'a> {
             stack: Vec::new(),
             chosen_arguments: IndexMap::new(),
             chosen_aliases: IndexMap::new(),
+            selection_depth: 0,
+            options: BuilderOptions::default(),
         };
 
         Ok(builder)
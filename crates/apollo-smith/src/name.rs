@@ -76,30 +76,42 @@ impl Name {
 impl<'a> DocumentBuilder<'a> {
     /// Create an arbitrary `Name`
     pub fn name(&mut self) -> ArbitraryResult<Name> {
-        Ok(Name::new(self.limited_string(30)?))
+        Ok(Name::new(self.name_string()?))
     }
 
     /// Create an arbitrary type `Name`
     pub fn type_name(&mut self) -> ArbitraryResult<Name> {
-        let mut new_name = self.limited_string(30)?;
-        if self.list_existing_type_names().any(|n| n.name == new_name) {
-            let _ = write!(
-                new_name,
-                "{}",
-                self.object_type_defs.len() + self.enum_type_defs.len() + self.directive_defs.len()
-            );
+        let base_name = self.name_string()?;
+        let mut new_name = base_name.clone();
+        let mut index =
+            self.object_type_defs.len() + self.enum_type_defs.len() + self.directive_defs.len();
+        // The suffixed name may be taken as well
+        while self.list_existing_type_names().any(|n| n.name == new_name) {
+            new_name = format!("{base_name}{index}");
+            index += 1;
         }
         Ok(Name::new(new_name))
     }
 
     /// Create an arbitrary `Name` with an index included in the name (to avoid name conflict)
     pub fn name_with_index(&mut self, index: usize) -> ArbitraryResult<Name> {
-        let mut name = self.limited_string(30)?;
+        let mut name = self.name_string()?;
         let _ = write!(name, "{index}");
 
         Ok(Name::new(name))
     }
 
+    /// Never starts with `__`, unless enabled by
+    /// [`BuilderOptions::reserved_names`][crate::BuilderOptions::reserved_names]
+    fn name_string(&mut self) -> ArbitraryResult<String> {
+        let name = self.limited_string(30)?;
+        if self.options.reserved_names && self.u.arbitrary()? {
+            Ok(format!("__{name}"))
+        } else {
+            Ok(name)
+        }
+    }
+
     // Mirror what happens in `Arbitrary for String`, but do so with a clamped size.
     pub(crate) fn limited_string(&mut self, max_size: usize) -> ArbitraryResult<String> {
         loop {
@@ -112,7 +124,8 @@ impl<'a> DocumentBuilder<'a> {
 
                         // Cannot start with a number
                         let ch = if curr_idx == 0 {
-                            // len - 1 to not have a _ at the begining
+                            // len - 1 to not have a _ at the begining,
+                            // which also avoids names reserved for introspection (`__`)
                             CHARSET_LETTERS[idx % (CHARSET_LETTERS.len() - 1)]
                         } else {
                             let idx = idx % (CHARSET_LETTERS.len() + CHARSET_NUMBERS.len());
@@ -150,3 +163,52 @@ impl<'a> DocumentBuilder<'a> {
             .chain(self.operation_defs.iter().filter_map(|op| op.name.as_ref()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::entropy;
+    use crate::Document;
+    use crate::DocumentBuilder;
+    use apollo_parser::cst::CstNode;
+    use apollo_parser::{Parser, SyntaxKind};
+    use arbitrary::Unstructured;
+
+    #[test]
+    fn generated_names_are_not_reserved() {
+        for seed in 0..16 {
+            let data = entropy(seed, 4096);
+            let mut u = Unstructured::new(&data);
+            let document: String = DocumentBuilder::new(&mut u).unwrap().finish().into();
+
+            let cst = Parser::new(&document).parse();
+            let names: Vec<_> = cst
+                .document()
+                .syntax()
+                .descendants_with_tokens()
+                .filter_map(|element| element.into_token())
+                .filter(|token| token.kind() == SyntaxKind::IDENT)
+                .collect();
+            assert!(!names.is_empty());
+            for name in names {
+                // Names starting with `__` are reserved for introspection
+                assert!(!name.text().starts_with("__"), "{}", name.text());
+            }
+        }
+    }
+
+    #[test]
+    fn reserved_names_are_opt_in() {
+        let data = entropy(808, 4096);
+        for reserved_names in [false, true] {
+            let mut u = Unstructured::new(&data);
+            let mut builder = DocumentBuilder::with_document(&mut u, Document::default()).unwrap();
+            builder.options.reserved_names = reserved_names;
+            let mut reserved = 0;
+            for _ in 0..50 {
+                let name = builder.name().unwrap();
+                reserved += usize::from(name.name.starts_with("__"));
+            }
+            assert_eq!(reserved > 0, reserved_names);
+        }
+    }
+}
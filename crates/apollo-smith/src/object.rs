@@ -142,11 +142,9 @@ impl TryFrom<apollo_parser::cst::ObjectTypeExtension> for ObjectTypeDef {
 impl<'a> DocumentBuilder<'a> {
     /// Create an arbitrary `ObjectTypeDef`
     pub fn object_type_definition(&mut self) -> ArbitraryResult<ObjectTypeDef> {
-        let extend = !self.object_type_defs.is_empty() && self.u.arbitrary().unwrap_or(false);
+        let extend = !self.object_type_defs.is_empty() && self.flip_extend();
         let description = self
-            .u
-            .arbitrary()
-            .unwrap_or(false)
+            .flip_description()
             .then(|| self.description())
             .transpose()?;
         let name = if extend {
@@ -167,23 +165,40 @@ impl<'a> DocumentBuilder<'a> {
         };
 
         // ---- Interface
-        let interface_impls = self.implements_interfaces()?;
-        let implements_fields: Vec<FieldDef> = interface_impls
+        let mut interface_impls = self.implements_interfaces()?;
+        // Interfaces implemented by the type being extended cannot be implemented again
+        for object in self.object_type_defs.iter().filter(|o| o.name == name) {
+            interface_impls.retain(|itf| !object.implements_interfaces.contains(itf));
+        }
+        // Fields of the type being extended must agree with the new interfaces
+        let extended_fields: Vec<FieldDef> = self
+            .object_type_defs
             .iter()
-            .flat_map(|itf_name| {
-                self.interface_type_defs
-                    .iter()
-                    .find(|itf| &itf.name == itf_name)
-                    .expect("cannot find the corresponding interface")
-                    .fields_def
-                    .clone()
-            })
+            .filter(|object| object.name == name)
+            .flat_map(|object| object.fields_def.iter().cloned())
+            .collect();
+        interface_impls.retain(|itf| {
+            let implemented = self.transitive_interfaces(&IndexSet::from([itf.clone()]));
+            self.interface_fields_agree(&implemented, &extended_fields)
+        });
+        // Fields defined by the type being extended also satisfy the interfaces
+        let existing_fields: Vec<Name> = self
+            .object_type_defs
+            .iter()
+            .filter(|object| object.name == name)
+            .flat_map(|object| object.fields_def.iter().map(|field| field.name.clone()))
+            .collect();
+        let implements_fields: Vec<FieldDef> = self
+            .interface_fields(&interface_impls)
+            .into_iter()
+            .filter(|field| !existing_fields.contains(&field.name))
             .collect();
 
         let mut fields_def = self.fields_definition(
             &implements_fields
                 .iter()
                 .map(|f| &f.name)
+                .chain(&existing_fields)
                 .collect::<Vec<&Name>>(),
         )?;
         // Add fields coming from interfaces
@@ -209,3 +224,43 @@ impl StackedEntity for ObjectTypeDef {
         &self.fields_def
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{add_interfaces_and_objects, entropy};
+    use crate::DocumentBuilder;
+    use apollo_compiler::Schema;
+    use arbitrary::Unstructured;
+
+    #[test]
+    fn implementations_define_all_interface_fields() {
+        let mut implementations = 0;
+        for seed in 0..10 {
+            let data = entropy(seed, 4096);
+            let mut u = Unstructured::new(&data);
+            let mut document = DocumentBuilder::new(&mut u).unwrap().finish();
+            document.operation_definitions.clear();
+            document.fragment_definitions.clear();
+            let document = add_interfaces_and_objects(document, seed, 10, 20);
+            implementations += document
+                .object_type_definitions
+                .iter()
+                .map(|object| object.implements_interfaces.len())
+                .sum::<usize>();
+
+            let document: String = document.into();
+            let errors = match Schema::parse_and_validate(&document, "schema.graphql") {
+                Ok(_) => continue,
+                Err(with_errors) => with_errors.errors.to_string(),
+            };
+            let missing_fields: Vec<&str> = errors
+                .lines()
+                .filter(|line| {
+                    line.contains("does not satisfy interface") && line.contains("missing field")
+                })
+                .collect();
+            assert!(missing_fields.is_empty(), "{missing_fields:#?}");
+        }
+        assert!(implementations > 0);
+    }
+}
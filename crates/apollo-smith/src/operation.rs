@@ -173,3 +173,31 @@ impl<'a> DocumentBuilder<'a> {
         }))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::entropy;
+    use crate::DocumentBuilder;
+    use apollo_compiler::{ExecutableDocument, Schema};
+    use arbitrary::Unstructured;
+
+    #[test]
+    fn schema_with_operations_is_valid() {
+        for seed in 0..200 {
+            let len = [256, 1024, 4096, 16384, 65536][seed as usize % 5];
+            let data = entropy(seed, len);
+            let mut u = Unstructured::new(&data);
+            let operations = 1 + seed as usize % 4;
+            let (schema, executable) =
+                DocumentBuilder::schema_with_operations(&mut u, operations).unwrap();
+            assert_eq!(executable.operation_definitions.len(), operations);
+
+            let schema: String = schema.into();
+            let executable: String = executable.into();
+            let schema = Schema::parse_and_validate(&schema, "schema.graphql")
+                .unwrap_or_else(|invalid| panic!("seed {seed}: {}\n{schema}", invalid.errors));
+            ExecutableDocument::parse_and_validate(&schema, &executable, "query.graphql")
+                .unwrap_or_else(|invalid| panic!("seed {seed}: {}\n{executable}", invalid.errors));
+        }
+    }
+}
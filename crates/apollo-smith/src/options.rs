@@ -0,0 +1,44 @@
+use crate::Weights;
+
+/// Configuration for [`DocumentBuilder::with_options`][crate::DocumentBuilder::with_options].
+///
+/// The default options generate the same documents as
+/// [`DocumentBuilder::new`][crate::DocumentBuilder::new] for the same input.
+///
+/// ```
+/// use apollo_smith::{BuilderOptions, DocumentBuilder};
+/// use arbitrary::Unstructured;
+///
+/// let mut options = BuilderOptions::default();
+/// options.unicode_stress = true;
+/// options.weights.description = 1.0;
+///
+/// let data: Vec<u8> = (0..4096_u32).map(|i| (i * 37 % 251) as u8).collect();
+/// let mut u = Unstructured::new(&data);
+/// let document = DocumentBuilder::with_options(&mut u, options).unwrap().finish();
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[non_exhaustive]
+pub struct BuilderOptions {
+    /// Generate default values of any type for input value definitions.
+    ///
+    /// By default, generated default values match the type of the input value they
+    /// are attached to. Documents generated with this option are useful to fuzz validation.
+    pub random_default_values: bool,
+    /// Probabilities biasing generation choices.
+    ///
+    /// Useful to target specific code paths, for example by generating more type extensions.
+    pub weights: Weights,
+    /// Draw the characters of descriptions and string values from a wide Unicode range,
+    /// to stress lexers.
+    ///
+    /// This includes multi-byte characters, bidirectional text markers, line and paragraph
+    /// separators, byte order marks, and every character the GraphQL lexer treats as
+    /// whitespace. Names always stay within the charset allowed by the spec.
+    pub unicode_stress: bool,
+    /// Allow generated names to start with `__`, which is reserved for introspection.
+    ///
+    /// Documents generated with this option are usually invalid,
+    /// which is useful to test validation of reserved names.
+    pub reserved_names: bool,
+}
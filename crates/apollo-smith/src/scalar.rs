@@ -1,6 +1,8 @@
 use crate::{
+    argument::Argument,
     description::Description,
     directive::{Directive, DirectiveLocation},
+    input_value::InputValue,
     name::Name,
     DocumentBuilder,
 };
@@ -82,7 +84,14 @@ impl TryFrom<apollo_parser::cst::ScalarTypeExtension> for ScalarTypeDef {
 impl<'a> DocumentBuilder<'a> {
     /// Create an arbitrary `ScalarTypeDef`
     pub fn scalar_type_definition(&mut self) -> ArbitraryResult<ScalarTypeDef> {
-        let extend = !self.scalar_type_defs.is_empty() && self.u.arbitrary().unwrap_or(false);
+        let description = self
+            .flip_description()
+            .then(|| self.description())
+            .transpose()?;
+        let mut directives = self.directives(DirectiveLocation::Scalar)?;
+        // Extended scalar must have directive
+        let extend =
+            !self.scalar_type_defs.is_empty() && !directives.is_empty() && self.flip_extend();
         let name = if extend {
             let available_scalars: Vec<&Name> = self
                 .scalar_type_defs
@@ -99,15 +108,11 @@ impl<'a> DocumentBuilder<'a> {
         } else {
             self.type_name()?
         };
-        let description = self
-            .u
-            .arbitrary()
-            .unwrap_or(false)
-            .then(|| self.description())
-            .transpose()?;
-        let directives = self.directives(DirectiveLocation::Scalar)?;
-        // Extended scalar must have directive
-        let extend = !directives.is_empty() && self.u.arbitrary().unwrap_or(false);
+        // `@specifiedBy` is not repeatable, so only add it to definitions
+        if !extend && self.u.arbitrary().unwrap_or(false) {
+            let specified_by = self.specified_by()?;
+            directives.insert(specified_by.name.clone(), specified_by);
+        }
 
         Ok(ScalarTypeDef {
             name,
@@ -116,4 +121,65 @@ impl<'a> DocumentBuilder<'a> {
             extend,
         })
     }
+
+    /// Create a built-in `@specifiedBy` directive with an arbitrary URL
+    fn specified_by(&mut self) -> ArbitraryResult<Directive> {
+        let scheme = *self.u.choose(&["https", "http"])?;
+        let host = self.limited_string(16)?.to_lowercase().replace('_', "-");
+        let path = self.limited_string(30)?;
+        Ok(Directive {
+            name: Name::new(String::from("specifiedBy")),
+            arguments: vec![Argument {
+                name: Name::new(String::from("url")),
+                value: InputValue::String(format!("{scheme}://{host}.example.com/{path}")),
+            }],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::with_seeded_builder;
+    use apollo_compiler::ast::Document;
+
+    #[test]
+    fn scalars_can_be_specified_by_url() {
+        let mut specified = 0;
+        for seed in 0..50 {
+            specified += with_seeded_builder(seed, 1024, None, |mut builder| {
+                let Ok(scalar) = builder.scalar_type_definition() else {
+                    return 0;
+                };
+                let Some(directive) = scalar
+                    .directives
+                    .values()
+                    .find(|directive| directive.name.name == "specifiedBy")
+                else {
+                    return 0;
+                };
+                assert!(!scalar.extend);
+                let [argument] = &directive.arguments[..] else {
+                    panic!("expected a single argument");
+                };
+                assert_eq!(argument.name.name, "url");
+
+                builder.scalar_type_defs.push(scalar.clone());
+                let sdl = String::from(builder.finish());
+                let document = Document::parse(&sdl, "scalar.graphql").unwrap();
+                let url = document
+                    .definitions
+                    .iter()
+                    .filter_map(|def| def.as_scalar_type_definition())
+                    .find_map(|def| def.directives.get("specifiedBy"))
+                    .and_then(|directive| directive.argument_by_name("url"))
+                    .and_then(|value| value.as_str())
+                    .unwrap()
+                    .to_owned();
+                assert!(url.starts_with("http"), "{url}");
+                assert!(url.contains(".example.com/"), "{url}");
+                1
+            });
+        }
+        assert!(specified > 0);
+    }
 }
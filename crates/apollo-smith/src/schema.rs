@@ -127,9 +127,7 @@ impl<'a> DocumentBuilder<'a> {
     /// Create an arbitrary `SchemaDef`
     pub fn schema_definition(&mut self) -> ArbitraryResult<SchemaDef> {
         let description = self
-            .u
-            .arbitrary()
-            .unwrap_or(false)
+            .flip_description()
             .then(|| self.description())
             .transpose()?;
         let directives = self.directives(DirectiveLocation::Schema)?;
@@ -170,7 +168,7 @@ impl<'a> DocumentBuilder<'a> {
             query,
             mutation,
             subscription,
-            extend: self.u.arbitrary().unwrap_or(false),
+            extend: self.flip_extend(),
         })
     }
 }
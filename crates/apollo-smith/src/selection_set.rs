@@ -19,6 +19,18 @@ pub struct SelectionSet {
     selections: Vec<Selection>,
 }
 
+impl SelectionSet {
+    /// Nesting depth of this selection set, counting itself as 1
+    pub(crate) fn depth(&self) -> usize {
+        let nested = self.selections.iter().map(|selection| match selection {
+            Selection::Field(field) => field.selection_set.as_ref().map_or(0, Self::depth),
+            Selection::FragmentSpread(_) => 0,
+            Selection::InlineFragment(inline) => inline.selection_set.depth(),
+        });
+        1 + nested.max().unwrap_or(0)
+    }
+}
+
 impl From<SelectionSet> for Vec<ast::Selection> {
     fn from(sel_set: SelectionSet) -> Self {
         sel_set.selections.into_iter().map(Into::into).collect()
@@ -83,9 +95,20 @@ impl TryFrom<apollo_parser::cst::Selection> for Selection {
     }
 }
 
+/// Maximum nesting of generated selection sets. Past this depth, selections
+/// only contain fields, preferably leaf fields.
+pub(crate) const MAX_SELECTION_DEPTH: usize = 5;
+
 impl<'a> DocumentBuilder<'a> {
     /// Create an arbitrary `SelectionSet`
     pub fn selection_set(&mut self) -> ArbitraryResult<SelectionSet> {
+        self.selection_depth += 1;
+        let selection_set = self.selections();
+        self.selection_depth -= 1;
+        selection_set
+    }
+
+    fn selections(&mut self) -> ArbitraryResult<SelectionSet> {
         let mut exclude_names = Vec::new();
         let selection_nb = std::cmp::max(
             self.stack.last().map(|o| o.fields_def().len()).unwrap_or(7),
@@ -103,6 +126,9 @@ impl<'a> DocumentBuilder<'a> {
         index: usize,
         excludes: &mut Vec<Name>,
     ) -> ArbitraryResult<Selection> {
+        if self.selection_depth >= MAX_SELECTION_DEPTH {
+            return Ok(Selection::Field(self.field(index)?));
+        }
         let selection = match self.u.int_in_range(0..=2usize)? {
             0 => Selection::Field(self.field(index)?),
             1 => match self.fragment_spread(excludes)? {
@@ -116,3 +142,71 @@ impl<'a> DocumentBuilder<'a> {
         Ok(selection)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::MAX_SELECTION_DEPTH;
+    use crate::test_utils::with_seeded_builder;
+    use apollo_compiler::ast;
+
+    #[derive(Default)]
+    struct Counts {
+        aliases: usize,
+        fragment_spreads: usize,
+        inline_fragments: usize,
+    }
+
+    fn walk(selections: &[ast::Selection], depth: usize, counts: &mut Counts) {
+        for selection in selections {
+            match selection {
+                ast::Selection::Field(field) => {
+                    counts.aliases += usize::from(field.alias.is_some());
+                    walk(&field.selection_set, depth + 1, counts);
+                }
+                ast::Selection::FragmentSpread(_) => {
+                    assert!(depth <= MAX_SELECTION_DEPTH, "spread nested too deep");
+                    counts.fragment_spreads += 1;
+                }
+                ast::Selection::InlineFragment(inline) => {
+                    assert!(depth <= MAX_SELECTION_DEPTH, "fragment nested too deep");
+                    counts.inline_fragments += usize::from(inline.type_condition.is_some());
+                    walk(&inline.selection_set, depth + 1, counts);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn executable_documents_use_aliases_and_fragments() {
+        let mut counts = Counts::default();
+        for seed in 0..50 {
+            let document = with_seeded_builder(seed, 4096, None, |mut builder| {
+                for _ in 0..5 {
+                    let Ok(fragment_def) = builder.fragment_definition() else {
+                        break;
+                    };
+                    builder.fragment_defs.push(fragment_def);
+                    let Ok(Some(operation_def)) = builder.operation_definition() else {
+                        break;
+                    };
+                    builder.operation_defs.push(operation_def);
+                }
+                ast::Document::from(builder.finish())
+            });
+            for definition in &document.definitions {
+                match definition {
+                    ast::Definition::OperationDefinition(operation) => {
+                        walk(&operation.selection_set, 1, &mut counts)
+                    }
+                    ast::Definition::FragmentDefinition(fragment) => {
+                        walk(&fragment.selection_set, 1, &mut counts)
+                    }
+                    _ => {}
+                }
+            }
+        }
+        assert!(counts.aliases > 0);
+        assert!(counts.fragment_spreads > 0);
+        assert!(counts.inline_fragments > 0);
+    }
+}
@@ -18,20 +18,20 @@ fn snapshot_tests() {
           A0
         }
 
-        fragment A2 on A1 {
+        fragment A5 on A2 {
           A0
           A0
         }
 
         schema {
-          query: A1
-          mutation: A1
-          subscription: A1
+          query: A2
+          mutation: A2
+          subscription: A2
         }
 
         scalar A
 
-        type A1 {
+        type A2 {
           A0: A0
           A1: A0
         }
@@ -41,19 +41,19 @@ fn snapshot_tests() {
           A1: A0
         }
 
-        union A2 = A1
+        union A3 = A2
 
         enum A0 {
           A0
           A1
         }
 
-        input A2 {
-          A0: A1
-          A1: A1
+        input A4 {
+          A0: A
+          A1: A
         }
 
-        directive @A2 on QUERY
+        directive @A6 on QUERY
     "#]]
     .assert_eq(&gen(0));
     expect![[r#"
@@ -62,20 +62,68 @@ fn snapshot_tests() {
           A0
         }
 
-        fragment A2 on A1 {
+        fragment A5 on A2 {
+          A0
+          A0
+        }
+
+        schema {
+          query: A2
+          mutation: A2
+          subscription: A2
+        }
+
+        """
+        d\"""
+        """
+        scalar A
+
+        type A2 {
+          A0: A0
+          A1: A0
+        }
+
+        interface A1 {
+          A0: A0
+          A1: A0
+        }
+
+        union A3 = A2
+
+        enum A0 {
+          A0
+          A1
+        }
+
+        input A4 {
+          A0: A
+          A1: A
+        }
+
+        directive @A6 on QUERY
+    "#]]
+    .assert_eq(&gen(10));
+    expect![[r#"
+        {
+          A0
+          A0
+        }
+
+        fragment A5 on A2 {
           A0
           A0
         }
 
         schema {
-          query: A1
-          mutation: A1
-          subscription: A1
+          query: A2
+          mutation: A2
+          subscription: A2
         }
 
-        scalar CA
+        """jR"""
+        scalar uECA86420zAAAAAAAAAAAA
 
-        type A1 {
+        type A2 {
           A0: A
           A1: A
         }
@@ -85,55 +133,62 @@ fn snapshot_tests() {
           A1: A
         }
 
-        union A2 = A1
+        union A3 = A2
 
         enum A {
           A0
           A1
         }
 
-        input A2 {
-          A0: A1
-          A1: A1
+        input A4 {
+          A0: uECA86420zAAAAAAAAAAAA
+          A1: uECA86420zAAAAAAAAAAAA
         }
 
-        directive @A2 on QUERY
+        directive @A6 on QUERY
     "#]]
-    .assert_eq(&gen(10));
+    .assert_eq(&gen(100));
     expect![[r#"
         {
           A0
           A0
         }
 
-        fragment A21 on A20 {
+        fragment A54 on A51 {
           A0
           A0
         }
 
         schema {
-          query: A20
-          mutation: A20
-          subscription: A20
+          query: A51
+          mutation: A51
+          subscription: A51
         }
 
-        scalar CJ
+        """jR"""
+        scalar uECA86420zxvtrpnljhfdb
 
-        type A20 {
-          A0: uECA86420zAAAAAAAAAAAA
-          A1: uECA86420zAAAAAAAAAAAA
+        type A51 {
+          A0: aWUSQO2LJHFDB97531_ywu
+          A1: aWUSQO2LJHFDB97531_ywu
         }
 
-        interface A20 {
-          A0: uECA86420zAAAAAAAAAAAA
-          A1: uECA86420zAAAAAAAAAAAA
+        interface A50 {
+          A0: aWUSQO2LJHFDB97531_ywu
+          A1: aWUSQO2LJHFDB97531_ywu
         }
 
-        union A21 = A20
+        union A52 = A51
 
-        enum uECA86420zAAAAAAAAAAAA {
-          A0
-          A1
+        enum aWUSQO2LJHFDB97531_ywu {
+          upnljh0
+          A7531_ywusrpnljhfdbZXVTRPN1
+          iomkigecaY2
+          wz3
+          uPNgKIGECA86420zxvtr4
+          QHFDB97531_yAAAA5
+          A6
+          A7
         }
 
         enum A {
@@ -231,153 +286,162 @@ fn snapshot_tests() {
           A1
         }
 
-        input A21 {
-          A0: A20
-          A1: A20
+        enum A20 {
+          A0
+          A1
         }
 
-        directive @A21 on QUERY
-    "#]]
-    .assert_eq(&gen(100));
-    expect![[r#"
-        {
+        enum A21 {
           A0
+          A1
+        }
+
+        enum A22 {
           A0
+          A1
         }
 
-        fragment A21 on A20 {
+        enum A23 {
           A0
+          A1
+        }
+
+        enum A24 {
           A0
+          A1
         }
 
-        schema {
-          query: A20
-          mutation: A20
-          subscription: A20
+        enum A25 {
+          A0
+          A1
         }
 
-        scalar CJ
+        enum A26 {
+          A0
+          A1
+        }
 
-        type A20 {
-          A0: uECA86420zxvtrpnljhfdb
-          A1: uECA86420zxvtrpnljhfdb
+        enum A27 {
+          A0
+          A1
         }
 
-        interface A20 {
-          A0: uECA86420zxvtrpnljhfdb
-          A1: uECA86420zxvtrpnljhfdb
+        enum A28 {
+          A0
+          A1
         }
 
-        union A21 = A20
+        enum A29 {
+          A0
+          A1
+        }
 
-        enum uECA86420zxvtrpnljhfdb {
-          aWUSQO2LJHFDB97531_ywu0
-          AKIG1
-          ChfdbZXV2
+        enum A30 {
+          A0
+          A1
         }
 
-        "S_LdpAi3b-U\rNvGo9h2a.T"
-        enum mkigecaYWUSQO2LJHFDB97531 {
-          Ovtrpnljhfdb0
-          g1_yAAAAAAAAAAAAAAAA1
+        enum A31 {
+          A0
+          A1
         }
 
-        enum A {
+        enum A32 {
           A0
           A1
         }
 
-        enum A3 {
+        enum A33 {
           A0
           A1
         }
 
-        enum A4 {
+        enum A34 {
           A0
           A1
         }
 
-        enum A5 {
+        enum A35 {
           A0
           A1
         }
 
-        enum A6 {
+        enum A36 {
           A0
           A1
         }
 
-        enum A7 {
+        enum A37 {
           A0
           A1
         }
 
-        enum A8 {
+        enum A38 {
           A0
           A1
         }
 
-        enum A9 {
+        enum A39 {
           A0
           A1
         }
 
-        enum A10 {
+        enum A40 {
           A0
           A1
         }
 
-        enum A11 {
+        enum A41 {
           A0
           A1
         }
 
-        enum A12 {
+        enum A42 {
           A0
           A1
         }
 
-        enum A13 {
+        enum A43 {
           A0
           A1
         }
 
-        enum A14 {
+        enum A44 {
           A0
           A1
         }
 
-        enum A15 {
+        enum A45 {
           A0
           A1
         }
 
-        enum A16 {
+        enum A46 {
           A0
           A1
         }
 
-        enum A17 {
+        enum A47 {
           A0
           A1
         }
 
-        enum A18 {
+        enum A48 {
           A0
           A1
         }
 
-        enum A19 {
+        enum A49 {
           A0
           A1
         }
 
-        input A21 {
-          A0: A20
-          A1: A20
+        input A53 {
+          A0: uECA86420zxvtrpnljhfdb
+          A1: uECA86420zxvtrpnljhfdb
         }
 
-        directive @A21 on QUERY
+        directive @A55 on QUERY
     "#]]
     .assert_eq(&gen(1000));
 }
@@ -0,0 +1,95 @@
+use crate::DocumentBuilder;
+
+/// Counts describing the definitions generated so far by a [`DocumentBuilder`].
+///
+/// Useful to check that size and depth limits take effect when tuning a fuzzing campaign.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DocumentStats {
+    /// Number of type definitions and extensions: scalars, objects, interfaces,
+    /// unions, enums, and input objects
+    pub types: usize,
+    /// Number of field definitions in objects, interfaces, and input objects
+    pub fields: usize,
+    /// Number of directive definitions
+    pub directives: usize,
+    /// Number of operation definitions
+    pub operations: usize,
+    /// Number of fragment definitions
+    pub fragments: usize,
+    /// Deepest selection set nesting in operations and fragments,
+    /// where a top-level selection set has depth 1
+    pub max_selection_depth: usize,
+}
+
+impl<'a> DocumentBuilder<'a> {
+    /// Returns counts of the definitions generated so far
+    pub fn stats(&self) -> DocumentStats {
+        let types = self.scalar_type_defs.len()
+            + self.object_type_defs.len()
+            + self.interface_type_defs.len()
+            + self.union_type_defs.len()
+            + self.enum_type_defs.len()
+            + self.input_object_type_defs.len();
+        let fields = self
+            .object_type_defs
+            .iter()
+            .map(|def| def.fields_def.len())
+            .chain(
+                self.interface_type_defs
+                    .iter()
+                    .map(|def| def.fields_def.len()),
+            )
+            .chain(
+                self.input_object_type_defs
+                    .iter()
+                    .map(|def| def.fields.len()),
+            )
+            .sum();
+        let max_selection_depth = self
+            .operation_defs
+            .iter()
+            .map(|def| def.selection_set.depth())
+            .chain(
+                self.fragment_defs
+                    .iter()
+                    .map(|def| def.selection_set.depth()),
+            )
+            .max()
+            .unwrap_or(0);
+
+        DocumentStats {
+            types,
+            fields,
+            directives: self.directive_defs.len(),
+            operations: self.operation_defs.len(),
+            fragments: self.fragment_defs.len(),
+            max_selection_depth,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::with_seeded_builder;
+    use crate::DocumentBuilder;
+    use arbitrary::Unstructured;
+
+    #[test]
+    fn stats_count_generated_definitions() {
+        let (stats, document) =
+            with_seeded_builder(0, 4096, None, |builder| (builder.stats(), builder.finish()));
+
+        assert_eq!(stats.operations, document.operation_definitions.len());
+        assert_eq!(stats.fragments, document.fragment_definitions.len());
+        assert_eq!(stats.directives, document.directive_definitions.len());
+        assert!(stats.types >= document.object_type_definitions.len());
+        assert!(stats.fields > 0);
+        assert!(stats.max_selection_depth >= 1);
+
+        let empty = DocumentBuilder::with_document(&mut Unstructured::new(&[]), document)
+            .unwrap()
+            .stats();
+        assert_eq!(empty, stats);
+    }
+}
@@ -0,0 +1,64 @@
+use crate::Document;
+use crate::DocumentBuilder;
+use arbitrary::Unstructured;
+
+/// Deterministic pseudo-random bytes, so that tests can try many different documents.
+pub(crate) fn entropy(seed: u64, len: usize) -> Vec<u8> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 56) as u8
+        })
+        .collect()
+}
+
+/// Run `f` with a builder for `document`, or for a document generated from `seed`
+/// if it is `None`.
+///
+/// The builder uses fresh entropy of `len` bytes, so that generating the document
+/// does not use up the input that `f` consumes.
+pub(crate) fn with_seeded_builder<R>(
+    seed: u64,
+    len: usize,
+    document: Option<Document>,
+    f: impl FnOnce(DocumentBuilder<'_>) -> R,
+) -> R {
+    let document = document.unwrap_or_else(|| {
+        let data = entropy(seed, len);
+        let mut u = Unstructured::new(&data);
+        DocumentBuilder::new(&mut u).unwrap().finish()
+    });
+    let data = entropy(seed + 1_000_000, len);
+    let mut u = Unstructured::new(&data);
+    f(DocumentBuilder::with_document(&mut u, document).unwrap())
+}
+
+/// Add `interfaces` interface types, then `objects` object types to `document`.
+///
+/// Each definition uses fresh entropy, so that most of them are generated
+/// even when generating a previous one used up its input.
+/// Object types can implement the interfaces that were added first.
+pub(crate) fn add_interfaces_and_objects(
+    mut document: Document,
+    seed: u64,
+    interfaces: u64,
+    objects: u64,
+) -> Document {
+    for step in 0..interfaces + objects {
+        let data = entropy(seed * 1000 + step, 1024);
+        let mut u = Unstructured::new(&data);
+        let mut builder = DocumentBuilder::with_document(&mut u, document).unwrap();
+        if step < interfaces {
+            let def = builder.interface_type_definition().unwrap();
+            builder.interface_type_defs.push(def);
+        } else {
+            let def = builder.object_type_definition().unwrap();
+            builder.object_type_defs.push(def);
+        }
+        document = builder.finish();
+    }
+    document
+}
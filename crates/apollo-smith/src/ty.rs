@@ -176,6 +176,27 @@ impl<'a> DocumentBuilder<'a> {
             .collect()
     }
 
+    /// List all existing (already created) `Ty` that can be used as input types
+    pub(crate) fn list_existing_input_types(&self) -> Vec<Ty> {
+        self.scalar_type_defs
+            .iter()
+            .filter(|s| !s.extend)
+            .map(|s| Ty::Named(s.name.clone()))
+            .chain(
+                self.enum_type_defs
+                    .iter()
+                    .filter(|e| !e.extend)
+                    .map(|e| Ty::Named(e.name.clone())),
+            )
+            .chain(
+                self.input_object_type_defs
+                    .iter()
+                    .filter(|o| !o.extend)
+                    .map(|o| Ty::Named(o.name.clone())),
+            )
+            .collect()
+    }
+
     /// List all existing object (already created) `Ty`
     pub(crate) fn list_existing_object_types(&self) -> Vec<Ty> {
         self.object_type_defs
@@ -184,6 +205,13 @@ impl<'a> DocumentBuilder<'a> {
             .collect()
     }
 
+    /// Returns whether a field of this `Ty` is selected without a selection set
+    pub(crate) fn is_leaf_type(&self, ty: &Ty) -> bool {
+        let name = ty.name();
+        !self.object_type_defs.iter().any(|o| &o.name == name)
+            && !self.interface_type_defs.iter().any(|i| &i.name == name)
+    }
+
     #[allow(dead_code)]
     pub(crate) fn generate_value_for_type(&mut self, _ty: &Ty) -> InputValue {
         todo!()
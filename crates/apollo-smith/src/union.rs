@@ -111,7 +111,7 @@ impl TryFrom<apollo_parser::cst::UnionTypeExtension> for UnionTypeDef {
 impl<'a> DocumentBuilder<'a> {
     /// Create an arbitrary `UnionTypeDef`
     pub fn union_type_definition(&mut self) -> ArbitraryResult<UnionTypeDef> {
-        let extend = !self.union_type_defs.is_empty() && self.u.arbitrary().unwrap_or(false);
+        let extend = !self.union_type_defs.is_empty() && self.flip_extend();
         let name = if extend {
             let available_unions: Vec<&Name> = self
                 .union_type_defs
@@ -129,13 +129,11 @@ impl<'a> DocumentBuilder<'a> {
             self.type_name()?
         };
         let description = self
-            .u
-            .arbitrary()
-            .unwrap_or(false)
+            .flip_description()
             .then(|| self.description())
             .transpose()?;
         let directives = self.directives(DirectiveLocation::Union)?;
-        let extend = self.u.arbitrary().unwrap_or(false);
+        let extend = self.flip_extend();
         let mut existing_types = self.list_existing_object_types();
         existing_types.extend(
             self.union_type_defs
@@ -1,4 +1,3 @@
-use crate::input_value::Constness;
 use crate::{
     directive::{Directive, DirectiveLocation},
     input_value::InputValue,
@@ -47,13 +46,8 @@ impl<'a> DocumentBuilder<'a> {
     /// Create an arbitrary `VariableDef`
     pub fn variable_definition(&mut self) -> ArbitraryResult<VariableDef> {
         let name = self.type_name()?;
-        let ty = self.choose_ty(&self.list_existing_types())?;
-        let default_value = self
-            .u
-            .arbitrary()
-            .unwrap_or(false)
-            .then(|| self.input_value(Constness::Const))
-            .transpose()?;
+        let ty = self.choose_ty(&self.list_existing_input_types())?;
+        let default_value = self.default_value(&ty)?;
         let directives = self.directives(DirectiveLocation::VariableDefinition)?;
 
         Ok(VariableDef {
@@ -0,0 +1,113 @@
+use crate::DocumentBuilder;
+
+/// Probabilities biasing the choices a [`DocumentBuilder`] makes while generating a document.
+///
+/// Probabilities are between `0.0` (never) and `1.0` (always). The default weights generate
+/// the same documents as [`DocumentBuilder::new`] for the same input.
+///
+/// ```
+/// use apollo_smith::Weights;
+///
+/// let mut weights = Weights::default();
+/// // Extend existing types whenever possible, and never attach descriptions
+/// weights.extend = 1.0;
+/// weights.description = 0.0;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct Weights {
+    /// Probability that a type or schema definition is generated as an extension
+    /// of an existing definition
+    pub extend: f64,
+    /// Probability that a description is attached to a definition
+    pub description: f64,
+    /// Fraction of the available directive definitions that may be applied at once
+    /// to a single location
+    pub directive_density: f64,
+}
+
+impl Default for Weights {
+    fn default() -> Self {
+        Self {
+            extend: 0.5,
+            description: 0.5,
+            directive_density: 1.0,
+        }
+    }
+}
+
+impl<'a> DocumentBuilder<'a> {
+    /// Returns `true` with the given probability, consuming one byte of input.
+    ///
+    /// With a probability of `0.5`, this reads the input the same way as `bool::arbitrary`.
+    pub(crate) fn flip(&mut self, probability: f64) -> bool {
+        let byte: u8 = self.u.arbitrary().unwrap_or(0);
+        // Map the lowest bit to the highest one, so that `bool::arbitrary`'s `true` values
+        // (odd bytes) are the values below 128.
+        let value = byte.rotate_right(1) ^ 0x80;
+        f64::from(value) < probability * 256.0
+    }
+
+    /// Returns `true` with the probability of generating an extension
+    pub(crate) fn flip_extend(&mut self) -> bool {
+        self.flip(self.options.weights.extend)
+    }
+
+    /// Returns `true` with the probability of attaching a description
+    pub(crate) fn flip_description(&mut self) -> bool {
+        self.flip(self.options.weights.description)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::entropy;
+    use crate::BuilderOptions;
+    use crate::DocumentBuilder;
+    use crate::Weights;
+    use arbitrary::Unstructured;
+
+    #[test]
+    fn default_weights_reproduce_default_documents() {
+        let data = entropy(863, 4096);
+        let mut u = Unstructured::new(&data);
+        let default = DocumentBuilder::new(&mut u).unwrap().finish();
+        let mut u = Unstructured::new(&data);
+        let weighted = DocumentBuilder::with_options(&mut u, BuilderOptions::default())
+            .unwrap()
+            .finish();
+        assert_eq!(String::from(default), String::from(weighted));
+    }
+
+    #[test]
+    fn weights_bias_generation() {
+        let data = entropy(863, 4096);
+        let options = BuilderOptions {
+            weights: Weights {
+                extend: 0.0,
+                description: 0.0,
+                directive_density: 0.0,
+            },
+            ..BuilderOptions::default()
+        };
+        let mut u = Unstructured::new(&data);
+        let document = DocumentBuilder::with_options(&mut u, options)
+            .unwrap()
+            .finish();
+        assert!(!document.object_type_definitions.is_empty());
+        for object in &document.object_type_definitions {
+            assert!(!object.extend);
+            assert!(object.description.is_none());
+            assert!(object.directives.is_empty());
+        }
+        for interface in &document.interface_type_definitions {
+            assert!(!interface.extend);
+            assert!(interface.description.is_none());
+            assert!(interface.directives.is_empty());
+        }
+        for enum_ in &document.enum_type_definitions {
+            assert!(!enum_.extend);
+            assert!(enum_.description.is_none());
+        }
+    }
+}